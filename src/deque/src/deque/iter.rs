@@ -0,0 +1,147 @@
+use std::iter::FusedIterator;
+use std::slice;
+
+use super::Deque;
+
+/// An iterator over references to a range of elements of a [`Deque`], from front to back.
+///
+/// Created by [`Deque::iter`] and [`Deque::range`].
+///
+/// # Fields
+///
+/// * `front` - The elements before the end of the buffer.
+/// * `back` - The elements wrapped around to the start of the buffer.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Creates an iterator over the elements of two slices, the first one first.
+    pub(super) fn new(front: &'a [T], back: &'a [T]) -> Self {
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// An iterator over mutable references to a range of elements of a [`Deque`], from front to
+/// back.
+///
+/// Created by [`Deque::iter_mut`] and [`Deque::range_mut`].
+///
+/// # Fields
+///
+/// * `front` - The elements before the end of the buffer.
+/// * `back` - The elements wrapped around to the start of the buffer.
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Creates an iterator over the elements of two slices, the first one first.
+    pub(super) fn new(front: &'a mut [T], back: &'a mut [T]) -> Self {
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// An iterator consuming a [`Deque`] and yielding its elements, from front to back.
+///
+/// # Fields
+///
+/// * `deque` - The elements not yielded yet.
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    deque: Deque<T>,
+}
+
+impl<T> IntoIter<T> {
+    /// Creates an iterator consuming a deque.
+    pub(super) fn new(deque: Deque<T>) -> Self {
+        IntoIter { deque }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.deque.try_remove_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.deque.size();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.try_remove_rear()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
@@ -1,8 +1,20 @@
-use std::fmt::Debug;
+pub mod iter;
 
-use vec::vec::LinkedVec;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
 
-/// A generic deque (double-ended queue) data structure implemented using a vector.
+use vec::array_vec::ring_buf::RingBuf;
+use vec::error::CollectionError;
+
+use iter::{IntoIter, Iter, IterMut};
+
+/// A generic deque (double-ended queue) data structure implemented using a circular buffer.
+///
+/// The elements live in a [`RingBuf`], wrapping around to the start of its buffer when they
+/// reach its end, so that adding and removing elements at either end is O(1), as is reading
+/// any element by its position from the front. The buffer is only allocated once an element is
+/// added, and doubles whenever it is full: a fixed deque stops growing at the capacity it was
+/// created with, while a growable deque has no limit.
 ///
 /// # Generic Parameters
 ///
@@ -10,8 +22,7 @@ use vec::vec::LinkedVec;
 ///
 /// # Fields
 ///
-/// * `cap` - Maximum capacity of the deque.
-/// * `data` - Internal vector storing the elements of the deque.
+/// * `buf` - The circular buffer storing the elements of the deque.
 ///
 /// # Examples
 ///
@@ -23,21 +34,60 @@ use vec::vec::LinkedVec;
 /// // Add elements to the front and rear of the deque.
 /// deque.add_front(1).unwrap();
 /// deque.add_rear(2).unwrap();
+/// assert_eq!((deque.front(), deque.back()), (Ok(&1), Ok(&2)));
 ///
 /// // Remove elements from the front and rear of the deque.
 /// let removed_front = deque.remove_front();
 /// let removed_rear = deque.remove_rear();
 ///
-/// assert_eq!(removed_front, Some(1));
-/// assert_eq!(removed_rear, Some(2));
+/// assert_eq!(removed_front, Ok(1));
+/// assert_eq!(removed_rear, Ok(2));
+/// ```
+///
+/// Telling a full deque from an empty one:
+///
+/// ```
+/// use deque::deque::Deque;
+/// use vec::error::CollectionError;
+///
+/// let mut deque = Deque::new(1);
+/// assert_eq!(deque.remove_rear(), Err(CollectionError::Empty));
+/// assert_eq!(deque.back(), Err(CollectionError::Empty));
+///
+/// deque.add_front('a').unwrap();
+/// assert_eq!(deque.add_rear('b'), Err(CollectionError::Full { capacity: 1 }));
+///
+/// assert_eq!(deque.try_remove_front(), Some('a'));
+/// assert_eq!(deque.try_remove_rear(), None);
+/// ```
+///
+/// Wrapping around the end of the buffer, and growing:
+///
+/// ```
+/// use deque::deque::Deque;
+///
+/// let mut deque = Deque::new(4);
+/// deque.add_rear(2).unwrap();
+/// deque.add_rear(3).unwrap();
+/// deque.add_front(1).unwrap();
+/// deque.add_front(0).unwrap();
+/// assert!(deque.is_full());
+/// assert_eq!(deque.iter().collect::<Vec<_>>(), [&0, &1, &2, &3]);
+/// assert_eq!((deque.get(1), deque.get(4)), (Some(&1), None));
+///
+/// let mut growable = Deque::growable();
+/// for item in 0..50 {
+///     growable.add_rear(item).unwrap();
+///     growable.add_front(-item - 1).unwrap();
+/// }
+/// assert!(!growable.is_full());
+/// assert_eq!(growable.into_iter().collect::<Vec<_>>(), (-50..50).collect::<Vec<_>>());
 /// ```
-#[derive(Debug)]
 pub struct Deque<T> {
-    cap: usize,
-    data: LinkedVec<T>,
+    buf: RingBuf<T>,
 }
 
-impl<T: Debug + Clone + PartialEq> Deque<T> {
+impl<T> Deque<T> {
     /// Creates a new empty deque with the given maximum capacity.
     ///
     /// # Arguments
@@ -46,11 +96,22 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     ///
     /// # Returns
     ///
-    /// A new empty deque with the specified capacity.
+    /// A new empty deque with the specified capacity, which doesn't allocate until an element
+    /// is added. Its buffer then doubles as needed up to the capacity.
     pub fn new(size: usize) -> Self {
         Deque {
-            cap: size,
-            data: LinkedVec::new(),
+            buf: RingBuf::new(size),
+        }
+    }
+
+    /// Creates a new empty deque without maximum capacity, which grows as elements are added.
+    ///
+    /// # Returns
+    ///
+    /// A new empty growable deque, which doesn't allocate until an element is added.
+    pub fn growable() -> Self {
+        Deque {
+            buf: RingBuf::growable(),
         }
     }
 
@@ -62,15 +123,13 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     ///
     /// # Returns
     ///
-    /// `Ok(())` if successful, otherwise returns an error if the deque is full.
-    pub fn add_front(&mut self, item: T) -> Result<(), String> {
-        if self.data.len() == self.cap {
-            return Err("Max size!".into());
-        }
-
-        self.data.push(item);
-
-        Ok(())
+    /// `Ok(())` if successful, otherwise [`CollectionError::Full`] if the deque is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing the buffer would make it larger than `isize::MAX` bytes.
+    pub fn add_front(&mut self, item: T) -> Result<(), CollectionError> {
+        self.buf.push_front(item)
     }
 
     /// Adds an element to the rear of the deque.
@@ -81,15 +140,22 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     ///
     /// # Returns
     ///
-    /// `Ok(())` if successful, otherwise returns an error if the deque is full.
-    pub fn add_rear(&mut self, item: T) -> Result<(), String> {
-        if self.data.len() == self.cap {
-            return Err("Max size!".into());
-        }
-
-        self.data.insert(0, item);
+    /// `Ok(())` if successful, otherwise [`CollectionError::Full`] if the deque is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing the buffer would make it larger than `isize::MAX` bytes.
+    pub fn add_rear(&mut self, item: T) -> Result<(), CollectionError> {
+        self.buf.push_back(item)
+    }
 
-        Ok(())
+    /// Removes an element from the front of the deque.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or [`CollectionError::Empty`] if the deque is empty.
+    pub fn remove_front(&mut self) -> Result<T, CollectionError> {
+        self.try_remove_front().ok_or(CollectionError::Empty)
     }
 
     /// Removes an element from the front of the deque.
@@ -97,8 +163,17 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     /// # Returns
     ///
     /// An optional value containing the removed element, or `None` if the deque is empty.
-    pub fn remove_front(&mut self) -> Option<T> {
-        self.data.pop()
+    pub fn try_remove_front(&mut self) -> Option<T> {
+        self.buf.pop_front()
+    }
+
+    /// Removes an element from the rear of the deque.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or [`CollectionError::Empty`] if the deque is empty.
+    pub fn remove_rear(&mut self) -> Result<T, CollectionError> {
+        self.try_remove_rear().ok_or(CollectionError::Empty)
     }
 
     /// Removes an element from the rear of the deque.
@@ -106,11 +181,224 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     /// # Returns
     ///
     /// An optional value containing the removed element, or `None` if the deque is empty.
-    pub fn remove_rear(&mut self) -> Option<T> {
-        match self.data.peek() {
-            Some(_) => self.data.remove(0),
-            None => None,
-        }
+    pub fn try_remove_rear(&mut self) -> Option<T> {
+        self.buf.pop_back()
+    }
+
+    /// Returns a reference to the front element of the deque.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the front element, or [`CollectionError::Empty`] if the deque is empty.
+    pub fn front(&self) -> Result<&T, CollectionError> {
+        self.try_front().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the front element of the deque.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the front element, or `None` if the deque is empty.
+    pub fn try_front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element of the deque.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the back element, or [`CollectionError::Empty`] if the deque is empty.
+    pub fn back(&self) -> Result<&T, CollectionError> {
+        self.try_back().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the back element of the deque.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the back element, or `None` if the deque is empty.
+    pub fn try_back(&self) -> Option<&T> {
+        self.get(self.buf.len().checked_sub(1)?)
+    }
+
+    /// Returns a reference to the element at a position from the front of the deque.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the element, the front one being at 0.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the element, or `None` if the position is out of bounds.
+    pub fn get(&self, position: usize) -> Option<&T> {
+        self.buf.get(position)
+    }
+
+    /// Returns a mutable reference to the element at a position from the front of the deque.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the element, the front one being at 0.
+    ///
+    /// # Returns
+    ///
+    /// An optional mutable reference to the element, or `None` if the position is out of
+    /// bounds.
+    pub fn get_mut(&mut self, position: usize) -> Option<&mut T> {
+        self.buf.get_mut(position)
+    }
+
+    /// Rotates the deque `count` positions to the left, so that the element at position
+    /// `count` becomes the front one.
+    ///
+    /// Moves `min(count, size - count)` elements, one at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of elements moving from the front to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the size of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::deque::Deque;
+    ///
+    /// let mut deque = Deque::growable();
+    /// for item in 0..5 {
+    ///     deque.add_rear(item).unwrap();
+    /// }
+    ///
+    /// deque.rotate_left(2);
+    /// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [2, 3, 4, 0, 1]);
+    /// deque.rotate_right(4);
+    /// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [3, 4, 0, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, count: usize) {
+        self.buf.rotate_left(count);
+    }
+
+    /// Rotates the deque `count` positions to the right, so that the element at position
+    /// `size - count` becomes the front one.
+    ///
+    /// Moves `min(count, size - count)` elements, one at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of elements moving from the back to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the size of the deque.
+    pub fn rotate_right(&mut self, count: usize) {
+        self.buf.rotate_right(count);
+    }
+
+    /// Moves the elements so that they no longer wrap around the end of the buffer.
+    ///
+    /// Takes O(capacity) when the elements wrap around, and O(1) otherwise.
+    ///
+    /// # Returns
+    ///
+    /// A mutable slice of the elements, from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::deque::Deque;
+    ///
+    /// let mut deque = Deque::new(3);
+    /// deque.add_rear('b').unwrap();
+    /// deque.add_rear('c').unwrap();
+    /// deque.add_front('a').unwrap();
+    /// assert_eq!(deque.as_slices(), (&['a'][..], &['b', 'c'][..]));
+    ///
+    /// deque.make_contiguous().sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque.as_slices(), (&['c', 'b', 'a'][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.buf.make_contiguous()
+    }
+
+    /// Returns the elements of the deque as two slices.
+    ///
+    /// # Returns
+    ///
+    /// The elements from the front up to the end of the buffer, then the elements wrapped
+    /// around to its start, which is empty when the elements are contiguous.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.buf.as_slices()
+    }
+
+    /// Returns the elements of the deque as two mutable slices.
+    ///
+    /// # Returns
+    ///
+    /// The elements from the front up to the end of the buffer, then the elements wrapped
+    /// around to its start, which is empty when the elements are contiguous.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.buf.as_mut_slices()
+    }
+
+    /// Returns an iterator over a range of positions of the deque.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The positions of the elements, the front one being at 0.
+    ///
+    /// # Returns
+    ///
+    /// A double-ended iterator yielding references to the elements in the range, from front to
+    /// back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the size of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::deque::Deque;
+    ///
+    /// let mut deque = Deque::new(6);
+    /// for item in 0..6 {
+    ///     deque.add_front(item).unwrap();
+    /// }
+    ///
+    /// assert_eq!(deque.range(1..4).collect::<Vec<_>>(), [&4, &3, &2]);
+    /// assert_eq!(deque.range(3..).rev().collect::<Vec<_>>(), [&0, &1, &2]);
+    ///
+    /// deque.range_mut(..=1).for_each(|item| *item *= 10);
+    /// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [50, 40, 3, 2, 1, 0]);
+    /// ```
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+        let (start, end) = self.bounds(range);
+        let (front, back) = self.as_slices();
+        let (front, back) = Self::slice_range(front, back, start, end);
+        Iter::new(front, back)
+    }
+
+    /// Returns a mutable iterator over a range of positions of the deque.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The positions of the elements, the front one being at 0.
+    ///
+    /// # Returns
+    ///
+    /// A double-ended iterator yielding mutable references to the elements in the range, from
+    /// front to back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the size of the deque.
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+        let (start, end) = self.bounds(range);
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = Self::slice_range(front, back, start, end);
+        IterMut::new(front, back)
     }
 
     /// Checks if the deque is empty.
@@ -119,16 +407,32 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     ///
     /// `true` if the deque is empty, `false` otherwise.
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.buf.is_empty()
     }
 
     /// Checks if the deque is full.
     ///
     /// # Returns
     ///
-    /// `true` if the deque is full, `false` otherwise.
+    /// `true` if the deque is fixed and holds as many elements as its capacity, `false`
+    /// otherwise. A growable deque is never full.
     pub fn is_full(&self) -> bool {
-        self.data.len() == self.cap
+        self.buf.is_full()
+    }
+
+    /// Checks if the deque grows as elements are added.
+    pub fn is_growable(&self) -> bool {
+        self.buf.is_growable()
+    }
+
+    /// Returns the capacity of the deque.
+    ///
+    /// # Returns
+    ///
+    /// The maximum capacity of a fixed deque, or the number of elements a growable deque can
+    /// hold before it grows.
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
     }
 
     /// Returns the current size of the deque.
@@ -137,38 +441,129 @@ impl<T: Debug + Clone + PartialEq> Deque<T> {
     ///
     /// The number of elements currently in the deque.
     pub fn size(&self) -> usize {
-        self.data.len()
+        self.buf.len()
     }
 
     /// Clears the deque, removing all elements.
     pub fn clear(&mut self) {
-        self.data = LinkedVec::new();
+        self.buf.clear();
     }
 
     /// Returns an iterator over the elements of the deque.
     ///
     /// # Returns
     ///
-    /// An iterator yielding references to the elements of the deque.
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data.iter()
+    /// A double-ended iterator yielding references to the elements of the deque, from front to
+    /// back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.range(..)
     }
 
     /// Returns a mutable iterator over the elements of the deque.
     ///
     /// # Returns
     ///
-    /// A mutable iterator yielding references to the elements of the deque.
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.data.iter_mut()
+    /// A double-ended iterator yielding mutable references to the elements of the deque, from
+    /// front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.range_mut(..)
     }
 
-    /// Consumes the deque and returns an iterator over its elements.
-    ///
-    /// # Returns
-    ///
-    /// An iterator consuming the deque and yielding its elements.
-    pub fn into_iter(self) -> impl Iterator<Item = T> {
-        self.data.into_iter()
+    /// Returns the start and end positions of a range, checking them against the size.
+    fn bounds<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflows"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflows"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.size(),
+        };
+        assert!(
+            start <= end,
+            "range starts at {} but ends at {}",
+            start,
+            end
+        );
+        assert!(
+            end <= self.size(),
+            "range ends at {} but the size is {}",
+            end,
+            self.size()
+        );
+        (start, end)
+    }
+
+    /// Narrows the two slices of the elements down to the positions from `start` to `end`.
+    fn slice_range<S: SliceRange>(front: S, back: S, start: usize, end: usize) -> (S, S) {
+        let split = front.len();
+        if end <= split {
+            (front.sub(start, end), back.sub(0, 0))
+        } else if start >= split {
+            (back.sub(start - split, end - split), front.sub(0, 0))
+        } else {
+            (front.sub(start, split), back.sub(0, end - split))
+        }
+    }
+}
+
+/// A shared or mutable slice that [`Deque::range`] and [`Deque::range_mut`] narrow down.
+trait SliceRange {
+    fn len(&self) -> usize;
+    fn sub(self, start: usize, end: usize) -> Self;
+}
+
+impl<T> SliceRange for &[T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn sub(self, start: usize, end: usize) -> Self {
+        &self[start..end]
+    }
+}
+
+impl<T> SliceRange for &mut [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn sub(self, start: usize, end: usize) -> Self {
+        &mut self[start..end]
+    }
+}
+
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Deque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Deque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Deque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
     }
 }
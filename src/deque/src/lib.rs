@@ -1,3 +1,6 @@
 // --- region: modules
 pub mod deque;
+pub mod palindrome_checker;
+pub mod sliding_window;
+pub mod work_stealing;
 // --- endregion: modules
\ No newline at end of file
@@ -1,13 +1,24 @@
 // --- region: imports
-use crate::{
-    deque::Deque, 
-    palindrome_checker::palindrome_checker
+use crate::deque::Deque;
+use ::deque::palindrome_checker::{
+    manacher::{longest_palindrome, Palindromes},
+    palindrome_checker,
+    partition::{min_palindrome_partition, palindrome_partitions},
+    PalindromeChecker,
 };
+use ::deque::sliding_window::{
+    aggregate::AggregateWindow,
+    monoid::{Gcd, Sum},
+    SlidingWindow, Span,
+};
+use ::deque::work_stealing::{thread_pool::ThreadPool, Steal, Worker};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 // --- endregion: imports
 
 // --- region: modules
 mod deque;
-mod palindrome_checker;
 // --- endregion: modules
 
 fn main() {
@@ -21,6 +32,18 @@ fn main() {
     for item in deque.iter() {
         print!("{} ", item);
     }
+    println!("\nFront: {:?}, back: {:?}, second: {:?}", deque.front(), deque.back(), deque.get(1));
+    deque.rotate_left(1);
+    println!("Rotated left by one: {:?}", deque);
+    println!("As slices: {:?}", deque.as_slices());
+    println!("Made contiguous: {:?}", deque.make_contiguous());
+    println!("Positions 1 to 2: {:?}", deque.range(1..3).collect::<Vec<_>>());
+
+    let mut growable = Deque::growable();
+    for item in 0..10 {
+        growable.add_rear(item).unwrap();
+    }
+    println!("Growable deque of {} with capacity {}: {:?}", growable.size(), growable.capacity(), growable);
 
     // PALINDROME CHECKER
     println!("\n\n\n***PALINDROME CHECKER***");
@@ -30,4 +53,69 @@ fn main() {
     println!("Is '{}' a palindrome? {}", palindrome1, palindrome_checker(palindrome1));
     println!("Is '{}' a palindrome? {}", palindrome2, palindrome_checker(palindrome2));
     println!("Is '{}' a palindrome? {}", non_palindrome, palindrome_checker(non_palindrome));
+
+    let sentence = "A man, a plan, a canal: Panama";
+    println!("Is '{}' a palindrome? {}, ignoring case, punctuation and spaces? {}",
+        sentence, palindrome_checker(sentence), PalindromeChecker::lenient().check(sentence));
+    let flags = "🇺🇸🇸🇺";
+    println!("Is '{}' a palindrome by characters? {}, by grapheme clusters? {}",
+        flags, palindrome_checker(flags), PalindromeChecker::new().with_graphemes().check(flags));
+
+    let text = "forgeeksskeegfor";
+    let chars: Vec<char> = text.chars().collect();
+    let palindromes = Palindromes::new(&chars);
+    println!("Longest palindrome of '{}': '{}', {} palindromes in all",
+        text, longest_palindrome(text), palindromes.count());
+    println!("Fewest palindromes making up 'racecarannakayak': {:?}", min_palindrome_partition("racecarannakayak"));
+    println!("Every palindrome partition of 'aabb': {:?}", palindrome_partitions("aabb"));
+
+    // SLIDING WINDOW
+    println!("\n\n\n***SLIDING WINDOW***");
+    let readings = [12, 18, 30, 7, 14, 21, 42, 35];
+    let mut extremes = SlidingWindow::new(Span::Count(3));
+    let mut sums = AggregateWindow::new(Span::Count(3), Sum);
+    let mut gcds = AggregateWindow::new(Span::Count(3), Gcd);
+    for reading in readings {
+        extremes.push(reading);
+        sums.push(reading);
+        gcds.push(reading);
+        println!(
+            "Pushed {:>2}: window {:?}, max {:?}, min {:?}, sum {}, gcd {}",
+            reading, extremes.iter().collect::<Vec<_>>(), extremes.max(), extremes.min(), sums.aggregate(), gcds.aggregate()
+        );
+    }
+
+    let start = Instant::now();
+    let mut last_minute = SlidingWindow::new(Span::Time(Duration::from_secs(60)));
+    for (second, latency) in [(0, 120), (20, 80), (45, 200), (70, 95)] {
+        last_minute.push_at(latency, start + Duration::from_secs(second));
+        println!("At {:>2}s: {} readings in the last minute, max latency {:?}", second, last_minute.size(), last_minute.max());
+    }
+    let evicted = last_minute.evict_at(start + Duration::from_secs(120));
+    println!("At 120s: evicted {}, {} left, span {:?}", evicted, last_minute.size(), last_minute.span());
+
+    // WORK-STEALING DEQUE
+    println!("\n\n\n***WORK-STEALING DEQUE***");
+    let mut worker = Worker::new();
+    let stealer = worker.stealer();
+    for task in 1..=5 {
+        worker.push(task);
+    }
+    println!("Worker pops {:?}, stealer steals {:?}", worker.pop(), stealer.steal());
+    println!("{} tasks left", stealer.size());
+    while let Steal::Success(task) = stealer.steal() {
+        print!("stole {} ", task);
+    }
+
+    let pool = ThreadPool::new(4);
+    let finished = Arc::new(AtomicUsize::new(0));
+    for job in 0..1_000u64 {
+        let finished = finished.clone();
+        pool.execute(move || {
+            let _ = (0..job).sum::<u64>();
+            finished.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+    pool.join();
+    println!("\nThread pool of {} threads ran {} jobs, {} panicked", pool.size(), finished.load(Ordering::Relaxed), pool.panicked());
 }
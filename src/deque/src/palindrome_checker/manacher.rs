@@ -0,0 +1,149 @@
+use std::ops::Range;
+
+/// The palindromes of a sequence, found by Manacher's algorithm in O(n).
+///
+/// The algorithm looks at the 2n + 1 centers of the sequence, on each element and between
+/// each two elements, and finds the longest palindrome around each of them. It goes from left
+/// to right, remembering the palindrome reaching furthest to the right: a center inside it
+/// mirrors a center already seen, whose palindrome it shares as far as that palindrome goes,
+/// so that each element is only compared again when a palindrome grows past the furthest one.
+///
+/// # Fields
+///
+/// * `lengths` - The length of the longest palindrome around each center, from left to right:
+///   the center before the element `i` is at `2 * i`, and the center on it at `2 * i + 1`.
+///
+/// # Examples
+///
+/// ```
+/// use deque::palindrome_checker::manacher::Palindromes;
+///
+/// let text: Vec<char> = "abacaba".chars().collect();
+/// let palindromes = Palindromes::new(&text);
+///
+/// assert_eq!(palindromes.longest(), 0..7);
+/// assert!(palindromes.is_palindrome(2..5));
+/// assert!(!palindromes.is_palindrome(0..2));
+/// // a, b, a, c, a, b, a, aba, aca, aba, bacab, abacaba.
+/// assert_eq!(palindromes.count(), 12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palindromes {
+    lengths: Vec<usize>,
+}
+
+impl Palindromes {
+    /// Finds the palindromes of a sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The sequence.
+    ///
+    /// # Returns
+    ///
+    /// The longest palindrome around each center of the sequence.
+    pub fn new<T: PartialEq>(items: &[T]) -> Self {
+        let centers = 2 * items.len() + 1;
+        // Two positions mirroring each other around a center are either both between elements,
+        // which always match, or both on elements.
+        let matches = |left: usize, right: usize| {
+            left.is_multiple_of(2) || items[left / 2] == items[right / 2]
+        };
+
+        let mut lengths = vec![0; centers];
+        let (mut center, mut right) = (0, 0);
+        for position in 0..centers {
+            let mut length = if position < right {
+                lengths[2 * center - position].min(right - position)
+            } else {
+                0
+            };
+            while length < position
+                && position + length + 1 < centers
+                && matches(position - length - 1, position + length + 1)
+            {
+                length += 1;
+            }
+            lengths[position] = length;
+            if position + length > right {
+                center = position;
+                right = position + length;
+            }
+        }
+        Palindromes { lengths }
+    }
+
+    /// Checks if a range of the sequence is a palindrome, in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of elements, which may be empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the elements of the range read the same forward and backward, `false`
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the sequence.
+    pub fn is_palindrome(&self, range: Range<usize>) -> bool {
+        assert!(
+            range.start <= range.end && 2 * range.end < self.lengths.len(),
+            "range out of bounds"
+        );
+        self.lengths[range.start + range.end] >= range.len()
+    }
+
+    /// Returns the longest palindrome of the sequence.
+    ///
+    /// # Returns
+    ///
+    /// The range of the first longest palindrome, which is empty for an empty sequence.
+    pub fn longest(&self) -> Range<usize> {
+        let (position, &length) = self
+            .lengths
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, length)| length)
+            .unwrap_or((0, &0));
+        (position - length) / 2..(position + length) / 2
+    }
+
+    /// Returns the number of non-empty palindromes of the sequence, counting each position
+    /// they are at.
+    pub fn count(&self) -> usize {
+        self.lengths.iter().map(|length| length.div_ceil(2)).sum()
+    }
+}
+
+/// # Longest palindromic substring
+///
+/// Finds the longest substring of a string reading the same forward and backward, comparing
+/// characters, with Manacher's algorithm in O(n).
+///
+/// # Arguments
+///
+/// * `text` - The string to search.
+///
+/// # Returns
+///
+/// The first longest palindromic substring, which is empty only if the string is.
+///
+/// # Examples
+///
+/// ```
+/// use deque::palindrome_checker::manacher::longest_palindrome;
+///
+/// assert_eq!(longest_palindrome("babad"), "bab");
+/// assert_eq!(longest_palindrome("forgeeksskeegfor"), "geeksskeeg");
+/// assert_eq!(longest_palindrome("été à Noël, léon"), "été");
+/// assert_eq!(longest_palindrome(""), "");
+/// ```
+pub fn longest_palindrome(text: &str) -> &str {
+    let (offsets, chars): (Vec<usize>, Vec<char>) = text.char_indices().unzip();
+    let longest = Palindromes::new(&chars).longest();
+    let offset = |index: usize| offsets.get(index).copied().unwrap_or(text.len());
+    &text[offset(longest.start)..offset(longest.end)]
+}
@@ -1,9 +1,17 @@
+pub mod manacher;
+pub mod partition;
+
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::deque::Deque;
 
 /// # Palindrome Checker
 ///
 /// Checks if a given string is a palindrome using a deque. A palindrome is a word, phrase, number, or other sequence of characters that reads the same forward and backward.
 ///
+/// The characters are compared as they are; see [`PalindromeChecker`] to ignore case,
+/// punctuation or whitespace.
+///
 /// # Arguments
 ///
 /// * `palindrome` - The string to check for palindrome property.
@@ -24,25 +32,171 @@ use crate::deque::Deque;
 /// assert_eq!(palindrome_checker(palindrome1), true);
 /// assert_eq!(palindrome_checker(palindrome2), true);
 /// assert_eq!(palindrome_checker(non_palindrome), false);
+/// assert_eq!(palindrome_checker("Radar"), false);
 /// ```
 pub fn palindrome_checker(palindrome: &str) -> bool {
-    // Create a deque to hold the characters of the palindrome.
-    let mut deque = Deque::new(palindrome.len());
+    PalindromeChecker::new().check(palindrome)
+}
 
-    // Add each character of the palindrome to the front of the deque.
-    for c in palindrome.chars() {
-        let _ = deque.add_front(c);
+/// A palindrome checker, configured with how the characters of a string are compared.
+///
+/// The string is cut into units, either characters or grapheme clusters (what a reader sees as
+/// a single character, such as a letter with its accents or a flag), which go through a deque
+/// and are compared from both ends.
+///
+/// # Fields
+///
+/// * `fold_case` - Whether units are compared regardless of case.
+/// * `skip_punctuation` - Whether units other than letters, digits and whitespace are ignored.
+/// * `skip_whitespace` - Whether whitespace units are ignored.
+/// * `graphemes` - Whether units are grapheme clusters rather than characters.
+///
+/// # Examples
+///
+/// ```
+/// use deque::palindrome_checker::PalindromeChecker;
+///
+/// let sentence = "A man, a plan, a canal: Panama";
+/// assert!(!PalindromeChecker::new().check(sentence));
+/// assert!(PalindromeChecker::lenient().check(sentence));
+///
+/// let checker = PalindromeChecker::new().without_whitespace();
+/// assert!(checker.check("never odd or even"));
+/// assert!(!checker.check("Never odd or even"));
+/// assert!(checker.with_case_folding().check("Never odd or even"));
+/// ```
+///
+/// Comparing grapheme clusters, so that accents stay on their letter and flags aren't split:
+///
+/// ```
+/// use deque::palindrome_checker::PalindromeChecker;
+///
+/// // "é" written as "e" followed by a combining acute accent.
+/// let accented = "e\u{301}te\u{301}";
+/// assert!(!PalindromeChecker::new().check(accented));
+/// assert!(PalindromeChecker::new().with_graphemes().check(accented));
+///
+/// // The flags of the United States and of the Soviet Union, made of the regional indicators
+/// // U and S.
+/// let flags = "🇺🇸🇸🇺";
+/// assert!(PalindromeChecker::new().check(flags));
+/// assert!(!PalindromeChecker::new().with_graphemes().check(flags));
+///
+/// // Case folding matches "ß" with "ss".
+/// assert!(PalindromeChecker::lenient().check("Saß, ssas"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PalindromeChecker {
+    fold_case: bool,
+    skip_punctuation: bool,
+    skip_whitespace: bool,
+    graphemes: bool,
+}
+
+impl PalindromeChecker {
+    /// Creates a new checker comparing the characters as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new checker for sentences, comparing grapheme clusters regardless of case and
+    /// ignoring punctuation and whitespace.
+    pub fn lenient() -> Self {
+        PalindromeChecker::new()
+            .with_case_folding()
+            .without_punctuation()
+            .without_whitespace()
+            .with_graphemes()
+    }
+
+    /// Compares units regardless of case, by full case folding: a unit matches another one if
+    /// they have the same lowercase once uppercased, so that "ß" matches "ss" and "ς" matches
+    /// "σ". A character folding to several characters counts as several units.
+    pub fn with_case_folding(mut self) -> Self {
+        self.fold_case = true;
+        self
+    }
+
+    /// Ignores the units without any letter, digit or whitespace, such as punctuation marks and
+    /// symbols.
+    ///
+    /// Comparing characters, this also ignores combining marks such as accents; comparing
+    /// grapheme clusters keeps them on their letter.
+    pub fn without_punctuation(mut self) -> Self {
+        self.skip_punctuation = true;
+        self
+    }
+
+    /// Ignores the whitespace units.
+    pub fn without_whitespace(mut self) -> Self {
+        self.skip_whitespace = true;
+        self
+    }
+
+    /// Compares extended grapheme clusters rather than characters.
+    pub fn with_graphemes(mut self) -> Self {
+        self.graphemes = true;
+        self
     }
 
-    // Check if the input string is a palindrome.
-    let mut is_palindrome = true;
-    while deque.size() > 1 && is_palindrome {
-        let front = deque.remove_front().unwrap();
-        let rear = deque.remove_rear().unwrap();
-        if front != rear {
-            is_palindrome = false;
+    /// Checks if a string is a palindrome.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the units of the string, once ignored and folded as configured, read the same
+    /// forward and backward, `false` otherwise.
+    pub fn check(&self, text: &str) -> bool {
+        let units: Vec<&str> = if self.graphemes {
+            text.graphemes(true).collect()
+        } else {
+            let chars = text.char_indices();
+            chars.map(|(i, c)| &text[i..i + c.len_utf8()]).collect()
+        };
+
+        let mut deque = Deque::growable();
+        for unit in units.into_iter().filter(|unit| self.keeps(unit)) {
+            if self.fold_case {
+                // A unit may fold to several ones, such as "ß" to "ss".
+                for folded in self.split(&fold(unit)) {
+                    let _ = deque.add_rear(folded);
+                }
+            } else {
+                let _ = deque.add_rear(unit.to_string());
+            }
+        }
+
+        while deque.size() > 1 {
+            if deque.try_remove_front() != deque.try_remove_rear() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Checks if a unit is compared rather than ignored.
+    fn keeps(&self, unit: &str) -> bool {
+        let whitespace = unit.chars().all(char::is_whitespace);
+        if whitespace {
+            return !self.skip_whitespace;
         }
+        !self.skip_punctuation || unit.chars().any(char::is_alphanumeric)
     }
 
-    is_palindrome
+    /// Cuts a string into units.
+    fn split(&self, text: &str) -> Vec<String> {
+        if self.graphemes {
+            text.graphemes(true).map(str::to_string).collect()
+        } else {
+            text.chars().map(String::from).collect()
+        }
+    }
+}
+
+/// Folds the case of a string, as the lowercase of its uppercase.
+fn fold(text: &str) -> String {
+    text.to_uppercase().to_lowercase()
 }
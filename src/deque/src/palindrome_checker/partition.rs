@@ -0,0 +1,132 @@
+use std::ops::Range;
+
+use super::manacher::Palindromes;
+
+/// # Minimum palindrome partition
+///
+/// Cuts a string into as few palindromic substrings as possible, comparing characters.
+///
+/// The fewest parts of each prefix come from the fewest parts of a shorter prefix followed by
+/// a palindrome, which [`Palindromes`] checks in O(1), so that the partition takes O(n²).
+///
+/// # Arguments
+///
+/// * `text` - The string to cut.
+///
+/// # Returns
+///
+/// The parts, in order, which is empty only if the string is.
+///
+/// # Examples
+///
+/// ```
+/// use deque::palindrome_checker::partition::min_palindrome_partition;
+///
+/// assert_eq!(min_palindrome_partition("aab"), ["aa", "b"]);
+/// assert_eq!(min_palindrome_partition("racecarannakayak"), ["racecar", "anna", "kayak"]);
+/// assert_eq!(min_palindrome_partition("abc").len(), 3);
+/// assert!(min_palindrome_partition("").is_empty());
+/// ```
+pub fn min_palindrome_partition(text: &str) -> Vec<&str> {
+    let (offsets, palindromes) = prepare(text);
+    let len = offsets.len() - 1;
+
+    // `parts[end]` holds the fewest parts of the first `end` characters, and `starts[end]` the
+    // start of the last of them.
+    let mut parts = vec![0; len + 1];
+    let mut starts = vec![0; len + 1];
+    for end in 1..=len {
+        let (count, start) = (0..end)
+            .filter(|&start| palindromes.is_palindrome(start..end))
+            .map(|start| (parts[start] + 1, start))
+            .min()
+            .unwrap_or((end, 0));
+        parts[end] = count;
+        starts[end] = start;
+    }
+
+    let mut partition = Vec::with_capacity(parts[len]);
+    let mut end = len;
+    while end > 0 {
+        partition.push(&text[offsets[starts[end]]..offsets[end]]);
+        end = starts[end];
+    }
+    partition.reverse();
+    partition
+}
+
+/// # Palindrome partitions
+///
+/// Finds every way to cut a string into palindromic substrings, comparing characters.
+///
+/// A string of n characters has up to 2^(n - 1) partitions, as many as "aaaa…" has, so this is
+/// only meant for short strings.
+///
+/// # Arguments
+///
+/// * `text` - The string to cut.
+///
+/// # Returns
+///
+/// The partitions, each made of its parts in order, and sorted by their first part, shortest
+/// first, then by their next parts. An empty string has a single partition with no part.
+///
+/// # Examples
+///
+/// ```
+/// use deque::palindrome_checker::partition::palindrome_partitions;
+///
+/// assert_eq!(
+///     palindrome_partitions("aab"),
+///     [vec!["a", "a", "b"], vec!["aa", "b"]]
+/// );
+/// assert_eq!(palindrome_partitions("noon").len(), 3);
+/// assert_eq!(palindrome_partitions(""), [Vec::<&str>::new()]);
+/// ```
+pub fn palindrome_partitions(text: &str) -> Vec<Vec<&str>> {
+    let (offsets, palindromes) = prepare(text);
+    let mut partitions = Vec::new();
+    let mut parts = Vec::new();
+    extend(0, &offsets, &palindromes, &mut parts, &mut partitions);
+
+    partitions
+        .into_iter()
+        .map(|parts| {
+            parts
+                .into_iter()
+                .map(|range| &text[offsets[range.start]..offsets[range.end]])
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the byte offsets of the characters of a string, followed by its length, and its
+/// palindromes.
+fn prepare(text: &str) -> (Vec<usize>, Palindromes) {
+    let (mut offsets, chars): (Vec<usize>, Vec<char>) = text.char_indices().unzip();
+    offsets.push(text.len());
+    (offsets, Palindromes::new(&chars))
+}
+
+/// Adds to `partitions` every partition starting with `parts` and cutting the characters from
+/// `start` on.
+fn extend(
+    start: usize,
+    offsets: &[usize],
+    palindromes: &Palindromes,
+    parts: &mut Vec<Range<usize>>,
+    partitions: &mut Vec<Vec<Range<usize>>>,
+) {
+    let len = offsets.len() - 1;
+    if start == len {
+        partitions.push(parts.clone());
+        return;
+    }
+    for end in start + 1..=len {
+        if palindromes.is_palindrome(start..end) {
+            parts.push(start..end);
+            extend(end, offsets, palindromes, parts, partitions);
+            parts.pop();
+        }
+    }
+}
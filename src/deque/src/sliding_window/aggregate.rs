@@ -0,0 +1,263 @@
+use std::time::Instant;
+
+use super::monoid::Monoid;
+use super::Span;
+
+/// An element on the front stack of an [`AggregateWindow`].
+///
+/// # Fields
+///
+/// * `pushed_at` - When the element was pushed.
+/// * `value` - The element.
+/// * `aggregate` - The aggregate of the element and every newer element on the front stack.
+#[derive(Debug)]
+struct Entry<T> {
+    pushed_at: Instant,
+    value: T,
+    aggregate: T,
+}
+
+/// A sliding window over a stream of elements, keeping track of their aggregate under any
+/// associative operation, such as a sum or a greatest common divisor.
+///
+/// The window is a queue made of two stacks. Elements are pushed onto the back stack, whose
+/// aggregate is kept as they come. Elements are evicted from the front stack, each of which
+/// holds the aggregate of itself and the newer elements below it; when the front stack runs
+/// out, the back stack is moved onto it, newest first, computing those aggregates on the way.
+/// The aggregate of the window combines the top of the front stack with the back stack, so
+/// that no operation needs an inverse: pushing and evicting are O(1) amortized, and reading
+/// the aggregate is O(1).
+///
+/// Elements are expected to be pushed in time order.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements in the window.
+/// * `M` - The [`Monoid`] aggregating the elements.
+///
+/// # Fields
+///
+/// * `span` - How much of the stream the window covers.
+/// * `monoid` - The operation aggregating the elements.
+/// * `front` - The oldest elements, the oldest one on top.
+/// * `back` - The newest elements, the newest one on top, with when they were pushed.
+/// * `back_aggregate` - The aggregate of the elements on the back stack.
+///
+/// # Examples
+///
+/// ```
+/// use deque::sliding_window::aggregate::AggregateWindow;
+/// use deque::sliding_window::monoid::{Gcd, Sum};
+/// use deque::sliding_window::Span;
+///
+/// let mut sums = AggregateWindow::new(Span::Count(3), Sum);
+/// let mut gcds = AggregateWindow::new(Span::Count(3), Gcd);
+/// let mut rolling = Vec::new();
+/// for value in [12u32, 18, 30, 7, 14, 21] {
+///     sums.push(value);
+///     gcds.push(value);
+///     rolling.push((sums.aggregate(), gcds.aggregate()));
+/// }
+/// assert_eq!(rolling, [(12, 12), (30, 6), (60, 6), (55, 1), (51, 1), (42, 7)]);
+/// ```
+///
+/// Evicting by time with a custom monoid, which need not be commutative:
+///
+/// ```
+/// use deque::sliding_window::aggregate::AggregateWindow;
+/// use deque::sliding_window::monoid::FnMonoid;
+/// use deque::sliding_window::Span;
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// let at = |seconds| start + Duration::from_secs(seconds);
+/// let concat = FnMonoid::new(String::new(), |a: &String, b: &String| a.clone() + b);
+///
+/// let mut window = AggregateWindow::new(Span::Time(Duration::from_secs(60)), concat);
+/// window.push_at("a".to_string(), at(0));
+/// window.push_at("b".to_string(), at(30));
+/// window.push_at("c".to_string(), at(70));
+/// assert_eq!(window.aggregate(), "bc");
+///
+/// assert_eq!(window.evict_at(at(95)), 1);
+/// assert_eq!(window.aggregate(), "c");
+/// ```
+///
+/// Comparing with the aggregate of every window, computed from scratch:
+///
+/// ```
+/// use deque::sliding_window::aggregate::AggregateWindow;
+/// use deque::sliding_window::monoid::Sum;
+/// use deque::sliding_window::Span;
+///
+/// let values: Vec<i64> = (0..500).map(|i| (i * 7919 % 211) - 100).collect();
+/// for width in [1, 2, 5, 64] {
+///     let mut window = AggregateWindow::new(Span::Count(width), Sum);
+///     for (end, &value) in values.iter().enumerate() {
+///         window.push(value);
+///         let start = (end + 1).saturating_sub(width);
+///         assert_eq!(window.aggregate(), values[start..=end].iter().sum::<i64>());
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AggregateWindow<T, M> {
+    span: Span,
+    monoid: M,
+    front: Vec<Entry<T>>,
+    back: Vec<(Instant, T)>,
+    back_aggregate: T,
+}
+
+impl<T, M: Monoid<T>> AggregateWindow<T, M> {
+    /// Creates a new empty aggregate window.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - How much of the stream the window covers.
+    /// * `monoid` - The operation aggregating the elements.
+    ///
+    /// # Returns
+    ///
+    /// A new empty window, whose aggregate is the identity of the monoid.
+    pub fn new(span: Span, monoid: M) -> Self {
+        AggregateWindow {
+            span,
+            back_aggregate: monoid.identity(),
+            monoid,
+            front: Vec::new(),
+            back: Vec::new(),
+        }
+    }
+
+    /// Pushes an element into the window at the current time, evicting the elements falling
+    /// out of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The newest element of the stream.
+    pub fn push(&mut self, value: T) {
+        self.push_at(value, Instant::now());
+    }
+
+    /// Pushes an element into the window at a given time, evicting the elements falling out
+    /// of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The newest element of the stream.
+    /// * `at` - When the element is pushed, no earlier than the elements before it.
+    pub fn push_at(&mut self, value: T, at: Instant) {
+        self.back_aggregate = self.monoid.combine(&self.back_aggregate, &value);
+        self.back.push((at, value));
+
+        self.evict_at(at);
+    }
+
+    /// Evicts the elements falling out of the window at the current time.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements evicted.
+    pub fn evict(&mut self) -> usize {
+        self.evict_at(Instant::now())
+    }
+
+    /// Evicts the elements falling out of the window at a given time.
+    ///
+    /// A window spanning a number of elements only evicts as elements are pushed.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements evicted.
+    pub fn evict_at(&mut self, now: Instant) -> usize {
+        let mut evicted = 0;
+        while let Some(pushed_at) = self.oldest() {
+            if !self.span.expires(self.size(), pushed_at, now) {
+                break;
+            }
+            if self.front.is_empty() {
+                self.flip();
+            }
+            self.front.pop();
+            evicted += 1;
+        }
+        evicted
+    }
+
+    /// Returns the aggregate of the elements in the window.
+    ///
+    /// # Returns
+    ///
+    /// The elements combined oldest first, or the identity of the monoid if the window is
+    /// empty.
+    pub fn aggregate(&self) -> T {
+        match self.front.last() {
+            Some(oldest) => self.monoid.combine(&oldest.aggregate, &self.back_aggregate),
+            None => self
+                .monoid
+                .combine(&self.monoid.identity(), &self.back_aggregate),
+        }
+    }
+
+    /// Returns how much of the stream the window covers.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Checks if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn size(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Clears the window, removing all elements.
+    pub fn clear(&mut self) {
+        self.front.clear();
+        self.back.clear();
+        self.back_aggregate = self.monoid.identity();
+    }
+
+    /// Returns an iterator over the elements in the window.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let front = self.front.iter().rev().map(|entry| &entry.value);
+        front.chain(self.back.iter().map(|(_, value)| value))
+    }
+
+    /// Returns when the oldest element in the window was pushed, if any.
+    fn oldest(&self) -> Option<Instant> {
+        match self.front.last() {
+            Some(entry) => Some(entry.pushed_at),
+            None => self.back.first().map(|&(pushed_at, _)| pushed_at),
+        }
+    }
+
+    /// Moves the back stack onto the empty front stack, newest first, so that the oldest
+    /// element ends on top.
+    fn flip(&mut self) {
+        while let Some((pushed_at, value)) = self.back.pop() {
+            let aggregate = match self.front.last() {
+                Some(newer) => self.monoid.combine(&value, &newer.aggregate),
+                None => self.monoid.combine(&value, &self.monoid.identity()),
+            };
+            self.front.push(Entry {
+                pushed_at,
+                value,
+                aggregate,
+            });
+        }
+        self.back_aggregate = self.monoid.identity();
+    }
+}
@@ -0,0 +1,261 @@
+pub mod aggregate;
+pub mod monoid;
+
+use std::time::{Duration, Instant};
+
+use crate::deque::Deque;
+
+/// How much of a stream a sliding window covers.
+///
+/// # Variants
+///
+/// * `Count` - The last given number of elements.
+/// * `Time` - The elements pushed less than the given duration ago.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Span {
+    Count(usize),
+    Time(Duration),
+}
+
+impl Span {
+    /// Checks if the oldest element of a window falls out of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of elements in the window.
+    /// * `pushed_at` - When the oldest element was pushed.
+    /// * `now` - The current time.
+    pub(crate) fn expires(&self, size: usize, pushed_at: Instant, now: Instant) -> bool {
+        match *self {
+            Span::Count(count) => size > count,
+            Span::Time(duration) => now.saturating_duration_since(pushed_at) >= duration,
+        }
+    }
+}
+
+/// A sliding window over a stream of elements, keeping track of their maximum and minimum.
+///
+/// Besides the elements, the window keeps two monotonic deques of their sequence numbers: the
+/// candidates for the maximum, from the greatest down, and the candidates for the minimum, from
+/// the least up. A pushed element removes from the back the candidates it beats, since they
+/// leave the window before it, so that the front candidate is always the answer. Each element
+/// enters and leaves each deque once, which makes pushing and evicting O(1) amortized, while
+/// reading the maximum or the minimum is O(1).
+///
+/// Elements are expected to be pushed in time order.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements in the window.
+///
+/// # Fields
+///
+/// * `span` - How much of the stream the window covers.
+/// * `entries` - The elements in the window, oldest first, with when they were pushed.
+/// * `start` - The sequence number of the oldest element in the window.
+/// * `maxima` - The sequence numbers of the candidates for the maximum, greatest first.
+/// * `minima` - The sequence numbers of the candidates for the minimum, least first.
+///
+/// # Examples
+///
+/// ```
+/// use deque::sliding_window::{SlidingWindow, Span};
+///
+/// let mut window = SlidingWindow::new(Span::Count(3));
+/// let mut maxima = Vec::new();
+/// for value in [1, 3, -1, -3, 5, 3, 6, 7] {
+///     window.push(value);
+///     maxima.push(*window.max().unwrap());
+/// }
+/// assert_eq!(maxima, [1, 3, 3, 3, 5, 5, 6, 7]);
+/// assert_eq!(window.min(), Some(&3));
+/// assert_eq!(window.iter().collect::<Vec<_>>(), [&3, &6, &7]);
+/// ```
+///
+/// Evicting by time:
+///
+/// ```
+/// use deque::sliding_window::{SlidingWindow, Span};
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// let at = |seconds| start + Duration::from_secs(seconds);
+///
+/// let mut window = SlidingWindow::new(Span::Time(Duration::from_secs(10)));
+/// window.push_at(40, at(0));
+/// window.push_at(25, at(4));
+/// window.push_at(30, at(8));
+/// assert_eq!((window.min(), window.max()), (Some(&25), Some(&40)));
+///
+/// assert_eq!(window.evict_at(at(10)), 1);
+/// assert_eq!((window.min(), window.max()), (Some(&25), Some(&30)));
+/// assert_eq!(window.evict_at(at(30)), 2);
+/// assert_eq!(window.max(), None);
+/// ```
+#[derive(Debug)]
+pub struct SlidingWindow<T> {
+    span: Span,
+    entries: Deque<(Instant, T)>,
+    start: u64,
+    maxima: Deque<u64>,
+    minima: Deque<u64>,
+}
+
+impl<T: Ord> SlidingWindow<T> {
+    /// Creates a new empty sliding window.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - How much of the stream the window covers.
+    ///
+    /// # Returns
+    ///
+    /// A new empty window.
+    pub fn new(span: Span) -> Self {
+        SlidingWindow {
+            span,
+            entries: Deque::growable(),
+            start: 0,
+            maxima: Deque::growable(),
+            minima: Deque::growable(),
+        }
+    }
+
+    /// Pushes an element into the window at the current time, evicting the elements falling
+    /// out of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The newest element of the stream.
+    pub fn push(&mut self, value: T) {
+        self.push_at(value, Instant::now());
+    }
+
+    /// Pushes an element into the window at a given time, evicting the elements falling out
+    /// of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The newest element of the stream.
+    /// * `at` - When the element is pushed, no earlier than the elements before it.
+    pub fn push_at(&mut self, value: T, at: Instant) {
+        let sequence = self.start + self.entries.size() as u64;
+        while self
+            .back_value(&self.maxima)
+            .is_some_and(|back| *back <= value)
+        {
+            let _ = self.maxima.try_remove_rear();
+        }
+        while self
+            .back_value(&self.minima)
+            .is_some_and(|back| *back >= value)
+        {
+            let _ = self.minima.try_remove_rear();
+        }
+        let _ = self.maxima.add_rear(sequence);
+        let _ = self.minima.add_rear(sequence);
+        let _ = self.entries.add_rear((at, value));
+
+        self.evict_at(at);
+    }
+
+    /// Evicts the elements falling out of the window at the current time.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements evicted.
+    pub fn evict(&mut self) -> usize {
+        self.evict_at(Instant::now())
+    }
+
+    /// Evicts the elements falling out of the window at a given time.
+    ///
+    /// A window spanning a number of elements only evicts as elements are pushed.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements evicted.
+    pub fn evict_at(&mut self, now: Instant) -> usize {
+        let mut evicted = 0;
+        while let Some(&(pushed_at, _)) = self.entries.try_front() {
+            if !self.span.expires(self.entries.size(), pushed_at, now) {
+                break;
+            }
+            let _ = self.entries.try_remove_front();
+            if self.maxima.try_front() == Some(&self.start) {
+                let _ = self.maxima.try_remove_front();
+            }
+            if self.minima.try_front() == Some(&self.start) {
+                let _ = self.minima.try_remove_front();
+            }
+            self.start += 1;
+            evicted += 1;
+        }
+        evicted
+    }
+
+    /// Returns the greatest element in the window, the newest one among equal elements.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the maximum, or `None` if the window is empty.
+    pub fn max(&self) -> Option<&T> {
+        self.value(*self.maxima.try_front()?)
+    }
+
+    /// Returns the least element in the window, the newest one among equal elements.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the minimum, or `None` if the window is empty.
+    pub fn min(&self) -> Option<&T> {
+        self.value(*self.minima.try_front()?)
+    }
+
+    /// Returns how much of the stream the window covers.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Checks if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn size(&self) -> usize {
+        self.entries.size()
+    }
+
+    /// Clears the window, removing all elements.
+    pub fn clear(&mut self) {
+        self.start += self.entries.size() as u64;
+        self.entries.clear();
+        self.maxima.clear();
+        self.minima.clear();
+    }
+
+    /// Returns an iterator over the elements in the window.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Returns the element with a sequence number, which must be in the window.
+    fn value(&self, sequence: u64) -> Option<&T> {
+        let (_, value) = self.entries.get((sequence - self.start) as usize)?;
+        Some(value)
+    }
+
+    /// Returns the element of the back candidate of a monotonic deque.
+    fn back_value(&self, candidates: &Deque<u64>) -> Option<&T> {
+        self.value(*candidates.try_back()?)
+    }
+}
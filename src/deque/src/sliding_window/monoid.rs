@@ -0,0 +1,103 @@
+use std::ops::{Add, Rem};
+
+/// An associative operation with an identity element, which an
+/// [`AggregateWindow`](super::aggregate::AggregateWindow) folds its elements with.
+///
+/// `combine` must be associative, `combine(a, combine(b, c)) == combine(combine(a, b), c)`,
+/// and `identity` must leave any element unchanged. `combine` need not be commutative: the
+/// elements are always combined oldest first.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements combined.
+pub trait Monoid<T> {
+    /// Returns the identity element, the aggregate of no element.
+    fn identity(&self) -> T;
+
+    /// Combines two elements, the older one first.
+    fn combine(&self, older: &T, newer: &T) -> T;
+}
+
+/// The sum of numbers, starting from the default value, zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sum;
+
+impl<T: Default + Clone + Add<Output = T>> Monoid<T> for Sum {
+    fn identity(&self) -> T {
+        T::default()
+    }
+
+    fn combine(&self, older: &T, newer: &T) -> T {
+        older.clone() + newer.clone()
+    }
+}
+
+/// The greatest common divisor of non-negative integers, starting from the default value,
+/// zero, whose divisors are every integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Gcd;
+
+impl<T: Default + Copy + PartialEq + Rem<Output = T>> Monoid<T> for Gcd {
+    fn identity(&self) -> T {
+        T::default()
+    }
+
+    fn combine(&self, older: &T, newer: &T) -> T {
+        let (mut a, mut b) = (*older, *newer);
+        while b != T::default() {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+}
+
+/// A monoid made of an identity element and a combining function.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements combined.
+/// * `F` - Type of the combining function.
+///
+/// # Fields
+///
+/// * `identity` - The identity element.
+/// * `combine` - The combining function, taking the older element first.
+///
+/// # Examples
+///
+/// ```
+/// use deque::sliding_window::monoid::{FnMonoid, Monoid};
+///
+/// let concat = FnMonoid::new(String::new(), |a: &String, b: &String| a.clone() + b);
+/// let word = ["sli", "ding"].iter().fold(concat.identity(), |acc, part| {
+///     concat.combine(&acc, &part.to_string())
+/// });
+/// assert_eq!(word, "sliding");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FnMonoid<T, F> {
+    identity: T,
+    combine: F,
+}
+
+impl<T, F: Fn(&T, &T) -> T> FnMonoid<T, F> {
+    /// Creates a monoid from an identity element and a combining function.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity` - The identity element.
+    /// * `combine` - The associative combining function, taking the older element first.
+    pub fn new(identity: T, combine: F) -> Self {
+        FnMonoid { identity, combine }
+    }
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> Monoid<T> for FnMonoid<T, F> {
+    fn identity(&self) -> T {
+        self.identity.clone()
+    }
+
+    fn combine(&self, older: &T, newer: &T) -> T {
+        (self.combine)(older, newer)
+    }
+}
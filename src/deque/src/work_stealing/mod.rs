@@ -0,0 +1,479 @@
+pub mod thread_pool;
+
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{self, AtomicIsize, AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// The smallest capacity of the buffer of a work-stealing deque.
+const MIN_CAPACITY: usize = 16;
+
+/// A circular buffer of a work-stealing deque, whose capacity is a power of two.
+///
+/// Elements are at positions counted since the deque was created, and the slot of a position
+/// is the position modulo the capacity.
+///
+/// # Fields
+///
+/// * `slots` - The elements, initialized from `top` up to `bottom`.
+struct Buffer<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+}
+
+impl<T> Buffer<T> {
+    /// Allocates a new buffer, whose slots are uninitialized.
+    fn new(capacity: usize) -> Box<Self> {
+        Box::new(Buffer {
+            slots: (0..capacity)
+                .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+                .collect(),
+        })
+    }
+
+    /// Returns the capacity of the buffer.
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the slot of a position.
+    fn slot(&self, position: isize) -> *mut MaybeUninit<T> {
+        self.slots[position as usize & (self.slots.len() - 1)].get()
+    }
+}
+
+/// The deque shared by a [`Worker`] and its [`Stealer`]s.
+///
+/// This is the deque of Chase and Lev, with the memory orderings of Lê, Pop, Cohen and
+/// Zappa Nardelli ("Correct and Efficient Work-Stealing for Weak Memory Models", 2013). The
+/// worker pushes and pops at the bottom, while stealers take from the top, racing each other
+/// and the worker for the last element with a compare-and-swap on `top`.
+///
+/// # Fields
+///
+/// * `top` - The position of the next element to steal, only moving forward.
+/// * `bottom` - The position of the next element to push, only written by the worker.
+/// * `buffer` - The current buffer, only replaced by the worker when it is full.
+/// * `retired` - The buffers replaced by a larger one, which a stealer may still be reading,
+///   freed with the deque.
+struct Inner<T> {
+    top: AtomicIsize,
+    bottom: AtomicIsize,
+    buffer: AtomicPtr<Buffer<T>>,
+    retired: Mutex<Vec<*mut Buffer<T>>>,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: elements are moved between threads, but never shared: each one is taken once, either
+// by the worker or by the stealer winning the compare-and-swap on `top`.
+unsafe impl<T: Send> Send for Inner<T> {}
+// SAFETY: see above; every field shared between threads is atomic or locked.
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+impl<T> Inner<T> {
+    /// Returns the number of elements in the deque, which other threads may be changing.
+    fn size(&self) -> usize {
+        let bottom = self.bottom.load(Ordering::Acquire);
+        let top = self.top.load(Ordering::Acquire);
+        bottom.wrapping_sub(top).max(0) as usize
+    }
+}
+
+/// Custom drop implementation for dropping the elements left in the deque.
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        let buffer = *self.buffer.get_mut();
+        let (mut top, bottom) = (*self.top.get_mut(), *self.bottom.get_mut());
+        // SAFETY: every handle is gone, so the buffer, allocated by `Box`, and the elements
+        // from `top` up to `bottom` are only reachable from here.
+        unsafe {
+            while top != bottom {
+                (*(*buffer).slot(top)).assume_init_drop();
+                top = top.wrapping_add(1);
+            }
+            drop(Box::from_raw(buffer));
+        }
+        let retired = self
+            .retired
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        for buffer in retired.drain(..) {
+            // SAFETY: retired buffers were allocated by `Box`, and their elements were moved to
+            // the current buffer.
+            drop(unsafe { Box::from_raw(buffer) });
+        }
+    }
+}
+
+/// The outcome of stealing from a work-stealing deque.
+///
+/// # Variants
+///
+/// * `Empty` - The deque was empty.
+/// * `Success` - An element was stolen.
+/// * `Retry` - Another thread took the element first; stealing again may succeed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Steal<T> {
+    Empty,
+    Success(T),
+    Retry,
+}
+
+impl<T> Steal<T> {
+    /// Returns the stolen element, if any.
+    pub fn success(self) -> Option<T> {
+        match self {
+            Steal::Success(item) => Some(item),
+            Steal::Empty | Steal::Retry => None,
+        }
+    }
+
+    /// Checks if another thread took the element first.
+    pub fn is_retry(&self) -> bool {
+        matches!(self, Steal::Retry)
+    }
+}
+
+/// The owner of a work-stealing deque, pushing and popping elements at its bottom.
+///
+/// The worker uses the deque as a stack: it pops the element it pushed last, which is usually
+/// the one whose data is still in its cache, while [`Stealer`]s take the oldest elements from
+/// the other end, so that they rarely compete with the worker. Pushing and popping are O(1)
+/// amortized and only contend with stealers for the last element. The deque grows as needed,
+/// doubling its buffer.
+///
+/// The worker can be moved to another thread, but not shared: only one thread owns each end.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements in the deque.
+///
+/// # Fields
+///
+/// * `inner` - The deque shared with the stealers.
+///
+/// # Examples
+///
+/// ```
+/// use deque::work_stealing::{Steal, Worker};
+///
+/// let mut worker = Worker::new();
+/// let stealer = worker.stealer();
+/// for task in 1..=3 {
+///     worker.push(task);
+/// }
+///
+/// assert_eq!(stealer.steal(), Steal::Success(1));
+/// assert_eq!(worker.pop(), Some(3));
+/// assert_eq!(stealer.clone().steal(), Steal::Success(2));
+/// assert_eq!(worker.pop(), None);
+/// assert_eq!(stealer.steal(), Steal::Empty);
+/// ```
+///
+/// Stress test with the worker racing three stealers, checking every element is taken exactly
+/// once:
+///
+/// ```
+/// use deque::work_stealing::{Steal, Worker};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::thread;
+///
+/// const ITEMS: usize = 200_000;
+/// let mut worker = Worker::new();
+/// let done = AtomicBool::new(false);
+/// let mut taken = thread::scope(|scope| {
+///     let thieves: Vec<_> = (0..3)
+///         .map(|_| {
+///             let (stealer, done) = (worker.stealer(), &done);
+///             scope.spawn(move || {
+///                 let mut stolen = Vec::new();
+///                 loop {
+///                     match stealer.steal() {
+///                         Steal::Success(item) => stolen.push(item),
+///                         Steal::Retry => {}
+///                         Steal::Empty if done.load(Ordering::Acquire) => break stolen,
+///                         Steal::Empty => thread::yield_now(),
+///                     }
+///                 }
+///             })
+///         })
+///         .collect();
+///
+///     let mut popped = Vec::new();
+///     for item in 0..ITEMS {
+///         worker.push(item);
+///         // Pop now and then, so that the worker competes with the stealers.
+///         if item % 3 == 0 {
+///             popped.extend(worker.pop());
+///         }
+///         if item % 1024 == 0 {
+///             thread::yield_now();
+///         }
+///     }
+///     popped.extend(std::iter::from_fn(|| worker.pop()));
+///     done.store(true, Ordering::Release);
+///     for thief in thieves {
+///         popped.extend(thief.join().unwrap());
+///     }
+///     popped
+/// });
+///
+/// taken.sort_unstable();
+/// assert_eq!(taken, (0..ITEMS).collect::<Vec<_>>());
+/// ```
+///
+/// Stress test dropping the deque with elements left in it, while stealers are still alive:
+///
+/// ```
+/// use deque::work_stealing::Worker;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let token = Arc::new(());
+/// for _ in 0..50 {
+///     let mut worker = Worker::new();
+///     let stealer = worker.stealer();
+///     let thief = thread::spawn(move || {
+///         (0..500).filter(|_| stealer.steal().success().is_some()).count()
+///     });
+///     for _ in 0..1_000 {
+///         worker.push(token.clone());
+///     }
+///     drop(worker);
+///     assert!(thief.join().unwrap() <= 1_000);
+/// }
+/// assert_eq!(Arc::strong_count(&token), 1);
+/// ```
+pub struct Worker<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Worker<T> {
+    /// Creates a new empty work-stealing deque, owned by the returned worker.
+    pub fn new() -> Self {
+        let buffer = Box::into_raw(Buffer::new(MIN_CAPACITY));
+        Worker {
+            inner: Arc::new(Inner {
+                top: AtomicIsize::new(0),
+                bottom: AtomicIsize::new(0),
+                buffer: AtomicPtr::new(buffer),
+                retired: Mutex::new(Vec::new()),
+                _marker: PhantomData,
+            }),
+        }
+    }
+
+    /// Creates a new stealer of the deque, which can be cloned and shared between threads.
+    pub fn stealer(&self) -> Stealer<T> {
+        Stealer {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Pushes an element at the bottom of the deque.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to push.
+    pub fn push(&mut self, item: T) {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::Relaxed);
+        let top = inner.top.load(Ordering::Acquire);
+        let mut buffer = inner.buffer.load(Ordering::Relaxed);
+
+        // SAFETY: only the worker replaces the buffer, so it is still the current one.
+        if bottom.wrapping_sub(top) >= unsafe { (*buffer).capacity() } as isize {
+            buffer = self.grow(top, bottom);
+        }
+
+        // SAFETY: the deque isn't full, so no stealer reads the slot of `bottom` before it is
+        // published by the store to `bottom`, which the release fence orders after this write.
+        unsafe { (*(*buffer).slot(bottom)).write(item) };
+        atomic::fence(Ordering::Release);
+        inner
+            .bottom
+            .store(bottom.wrapping_add(1), Ordering::Relaxed);
+    }
+
+    /// Pops the element at the bottom of the deque, the one pushed last.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the element, or `None` if the deque is empty, including when a
+    /// stealer took the last element first.
+    pub fn pop(&mut self) -> Option<T> {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::Relaxed).wrapping_sub(1);
+        let buffer = inner.buffer.load(Ordering::Relaxed);
+        // Claim the bottom element before looking at `top`, so that a stealer either sees the
+        // claim or is seen by the worker.
+        inner.bottom.store(bottom, Ordering::Relaxed);
+        atomic::fence(Ordering::SeqCst);
+        let top = inner.top.load(Ordering::Relaxed);
+
+        let left = bottom.wrapping_sub(top);
+        if left < 0 {
+            inner
+                .bottom
+                .store(bottom.wrapping_add(1), Ordering::Relaxed);
+            return None;
+        }
+
+        // SAFETY: the slot of `bottom` holds an element, which stealers no longer reach unless
+        // it is the last one, in which case the compare-and-swap below decides who takes it.
+        let item = unsafe { ptr::read((*buffer).slot(bottom)) };
+        if left > 0 {
+            // SAFETY: other elements are left, so no stealer can take this one.
+            return Some(unsafe { item.assume_init() });
+        }
+
+        let won = inner
+            .top
+            .compare_exchange(
+                top,
+                top.wrapping_add(1),
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            )
+            .is_ok();
+        inner
+            .bottom
+            .store(bottom.wrapping_add(1), Ordering::Relaxed);
+        // SAFETY: winning the compare-and-swap makes the worker the only owner of the element,
+        // while losing it means a stealer took it, so the copy is forgotten.
+        won.then(|| unsafe { item.assume_init() })
+    }
+
+    /// Checks if the deque is empty, which stealers may be changing.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns the number of elements in the deque, which stealers may be lowering.
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// Replaces the full buffer by one twice as large, keeping the old one alive for the
+    /// stealers still reading it.
+    fn grow(&self, top: isize, bottom: isize) -> *mut Buffer<T> {
+        let inner = &*self.inner;
+        let old = inner.buffer.load(Ordering::Relaxed);
+        // SAFETY: only the worker replaces or frees the current buffer.
+        let larger = Buffer::new(unsafe { (*old).capacity() } * 2);
+        let mut position = top;
+        while position != bottom {
+            // SAFETY: the elements from `top` up to `bottom` are initialized; they are copied
+            // bitwise, and stealers reading the old buffer take each one at most once.
+            unsafe { ptr::copy_nonoverlapping((*old).slot(position), larger.slot(position), 1) };
+            position = position.wrapping_add(1);
+        }
+
+        let larger = Box::into_raw(larger);
+        inner.buffer.store(larger, Ordering::Release);
+        // The old buffer is no longer the current one, but is only freed with the deque, once
+        // no stealer can be reading it.
+        inner
+            .retired
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(old);
+        larger
+    }
+}
+
+impl<T> Default for Worker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Worker<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Worker")
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+/// A handle stealing elements from the top of a work-stealing deque, the ones pushed first.
+///
+/// Stealers are created by [`Worker::stealer`], and can be cloned and shared between threads;
+/// they keep the deque alive after the worker is dropped.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements in the deque.
+///
+/// # Fields
+///
+/// * `inner` - The deque shared with the worker and the other stealers.
+pub struct Stealer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Stealer<T> {
+    /// Steals the element at the top of the deque, the one pushed first.
+    ///
+    /// # Returns
+    ///
+    /// [`Steal::Success`] with the element, [`Steal::Empty`] if the deque is empty, or
+    /// [`Steal::Retry`] if the worker or another stealer took the element first.
+    pub fn steal(&self) -> Steal<T> {
+        let inner = &*self.inner;
+        let top = inner.top.load(Ordering::Acquire);
+        atomic::fence(Ordering::SeqCst);
+        let bottom = inner.bottom.load(Ordering::Acquire);
+        if bottom.wrapping_sub(top) <= 0 {
+            return Steal::Empty;
+        }
+
+        let buffer = inner.buffer.load(Ordering::Acquire);
+        // SAFETY: buffers are only freed with the deque, which this stealer keeps alive. The
+        // slot of `top` held an element when `bottom` was read; if it was taken or overwritten
+        // since, the compare-and-swap below fails and the copy is forgotten.
+        let item = unsafe { ptr::read((*buffer).slot(top)) };
+        if inner
+            .top
+            .compare_exchange(
+                top,
+                top.wrapping_add(1),
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            )
+            .is_err()
+        {
+            return Steal::Retry;
+        }
+        // SAFETY: winning the compare-and-swap makes this stealer the only owner of the element.
+        Steal::Success(unsafe { item.assume_init() })
+    }
+
+    /// Checks if the deque is empty, which other threads may be changing.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns the number of elements in the deque, which other threads may be changing.
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+}
+
+impl<T> Clone for Stealer<T> {
+    fn clone(&self) -> Self {
+        Stealer {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Stealer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stealer")
+            .field("size", &self.size())
+            .finish()
+    }
+}
@@ -0,0 +1,347 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::{Steal, Stealer, Worker};
+use crate::deque::Deque;
+
+/// A closure run by a [`ThreadPool`].
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// The most jobs a thread moves from the injector to its own deque at once.
+const BATCH: usize = 32;
+
+/// How long an idle thread sleeps before looking for jobs to steal again, in case no thread
+/// woke it up.
+const IDLE_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// The jobs submitted to a [`ThreadPool`] and its counters, guarded by its mutex.
+///
+/// # Fields
+///
+/// * `injected` - The jobs submitted but not yet taken by any thread.
+/// * `pending` - The number of jobs submitted but not yet finished.
+/// * `panicked` - The number of jobs that panicked.
+/// * `shutdown` - Whether the pool is being dropped.
+struct State {
+    injected: Deque<Job>,
+    pending: usize,
+    panicked: usize,
+    shutdown: bool,
+}
+
+/// The part of a [`ThreadPool`] shared with its threads.
+///
+/// # Fields
+///
+/// * `state` - The submitted jobs and the counters.
+/// * `work` - Wakes up the idle threads when jobs are submitted or can be stolen.
+/// * `idle` - Wakes up the threads joining the pool when every job is finished.
+/// * `stealers` - The stealers of the deque of each thread.
+struct Shared {
+    state: Mutex<State>,
+    work: Condvar,
+    idle: Condvar,
+    stealers: Vec<Stealer<Job>>,
+}
+
+impl Shared {
+    /// Locks the state, ignoring the poisoning of a thread that panicked while holding the
+    /// lock, as jobs never run while it is held.
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Takes a batch of submitted jobs, pushing all but the first onto the deque of a thread,
+    /// where idle threads can steal them.
+    fn take_injected(&self, worker: &mut Worker<Job>) -> Option<Job> {
+        let mut state = self.lock();
+        let job = state.injected.try_remove_front()?;
+        let batch = (state.injected.size() / 2).min(BATCH);
+        for _ in 0..batch {
+            if let Some(extra) = state.injected.try_remove_front() {
+                worker.push(extra);
+            }
+        }
+        let more = !state.injected.is_empty() || batch > 0;
+        drop(state);
+        if more {
+            self.work.notify_one();
+        }
+        Some(job)
+    }
+
+    /// Steals a job from the deque of another thread, starting with the next one.
+    fn steal(&self, index: usize) -> Option<Job> {
+        let count = self.stealers.len();
+        for offset in 1..count {
+            let stealer = &self.stealers[(index + offset) % count];
+            loop {
+                match stealer.steal() {
+                    Steal::Success(job) => return Some(job),
+                    Steal::Empty => break,
+                    Steal::Retry => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Waits for jobs to be submitted, or for a while in case jobs can be stolen.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the pool is being dropped and no job is left to take but the ones on the
+    /// deques of other threads, which those threads run, `true` otherwise.
+    fn wait(&self) -> bool {
+        let state = self.lock();
+        if !state.injected.is_empty() {
+            return true;
+        }
+        if state.shutdown {
+            return false;
+        }
+        let _ = self
+            .work
+            .wait_timeout(state, IDLE_TIMEOUT)
+            .unwrap_or_else(PoisonError::into_inner);
+        true
+    }
+
+    /// Records that a job finished, waking up the threads joining the pool if it was the last.
+    fn finish(&self, panicked: bool) {
+        let mut state = self.lock();
+        state.pending -= 1;
+        if panicked {
+            state.panicked += 1;
+        }
+        if state.pending == 0 {
+            self.idle.notify_all();
+        }
+    }
+}
+
+/// A fixed pool of threads running closures, balancing them with work-stealing deques.
+///
+/// Submitted closures go to a shared queue, from which each thread takes a batch onto its own
+/// [`Worker`] deque. A thread runs the jobs of its deque first, then takes another batch, and
+/// once there is none, steals jobs from the deques of the other threads through their
+/// [`Stealer`]s, so that a thread with a long batch doesn't keep jobs from idle threads.
+///
+/// A job that panics doesn't bring down its thread: the panic is caught and counted. Dropping
+/// the pool waits for every submitted job to finish.
+///
+/// # Fields
+///
+/// * `shared` - The submitted jobs and the stealers, shared with the threads.
+/// * `threads` - The threads of the pool.
+///
+/// # Examples
+///
+/// ```
+/// use deque::work_stealing::thread_pool::ThreadPool;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// let pool = ThreadPool::new(4);
+/// let sum = Arc::new(AtomicUsize::new(0));
+/// for value in 1..=100 {
+///     let sum = sum.clone();
+///     pool.execute(move || {
+///         sum.fetch_add(value, Ordering::Relaxed);
+///     });
+/// }
+///
+/// pool.join();
+/// assert_eq!(sum.load(Ordering::Relaxed), 5050);
+/// assert_eq!(pool.size(), 4);
+/// ```
+///
+/// Stress test submitting jobs from several threads, checking every job runs exactly once:
+///
+/// ```
+/// use deque::work_stealing::thread_pool::ThreadPool;
+/// use std::sync::atomic::{AtomicU8, Ordering};
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// const JOBS: usize = 100_000;
+/// let pool = ThreadPool::new(4);
+/// let runs: Arc<Vec<AtomicU8>> = Arc::new((0..JOBS).map(|_| AtomicU8::new(0)).collect());
+/// thread::scope(|scope| {
+///     for submitter in 0..4 {
+///         let (pool, runs) = (&pool, &runs);
+///         scope.spawn(move || {
+///             for job in (submitter..JOBS).step_by(4) {
+///                 let runs = runs.clone();
+///                 pool.execute(move || {
+///                     runs[job].fetch_add(1, Ordering::Relaxed);
+///                 });
+///             }
+///         });
+///     }
+/// });
+///
+/// pool.join();
+/// assert!(runs.iter().all(|count| count.load(Ordering::Relaxed) == 1));
+/// ```
+///
+/// Stress test with panicking and uneven jobs, and dropping the pool with jobs left:
+///
+/// ```
+/// use deque::work_stealing::thread_pool::ThreadPool;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let done = Arc::new(AtomicUsize::new(0));
+/// let pool = ThreadPool::new(3);
+/// for job in 0..2_000 {
+///     let done = done.clone();
+///     pool.execute(move || {
+///         if job % 500 == 0 {
+///             std::thread::sleep(Duration::from_millis(5));
+///         }
+///         if job % 100 == 99 {
+///             panic!("job {} failed", job);
+///         }
+///         done.fetch_add(1, Ordering::Relaxed);
+///     });
+/// }
+/// pool.join();
+/// assert_eq!(pool.panicked(), 20);
+/// assert_eq!(done.load(Ordering::Relaxed), 1_980);
+///
+/// for _ in 0..1_000 {
+///     let done = done.clone();
+///     pool.execute(move || {
+///         done.fetch_add(1, Ordering::Relaxed);
+///     });
+/// }
+/// drop(pool);
+/// assert_eq!(done.load(Ordering::Relaxed), 2_980);
+/// ```
+pub struct ThreadPool {
+    shared: Arc<Shared>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    /// Creates a new pool and starts its threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is zero, as no job could ever run, or if a thread can't be started.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "size must be greater than zero");
+        let workers: Vec<Worker<Job>> = (0..size).map(|_| Worker::new()).collect();
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                injected: Deque::growable(),
+                pending: 0,
+                panicked: 0,
+                shutdown: false,
+            }),
+            work: Condvar::new(),
+            idle: Condvar::new(),
+            stealers: workers.iter().map(Worker::stealer).collect(),
+        });
+
+        let threads = workers
+            .into_iter()
+            .enumerate()
+            .map(|(index, worker)| {
+                let shared = shared.clone();
+                thread::Builder::new()
+                    .name(format!("pool-{}", index))
+                    .spawn(move || run(index, worker, &shared))
+                    .expect("failed to start a thread")
+            })
+            .collect();
+        ThreadPool { shared, threads }
+    }
+
+    /// Submits a closure to run on a thread of the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `job` - The closure to run.
+    pub fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        let mut state = self.shared.lock();
+        state.pending += 1;
+        let _ = state.injected.add_rear(Box::new(job));
+        drop(state);
+        self.shared.work.notify_one();
+    }
+
+    /// Waits for every submitted job to finish, including the ones submitted while waiting.
+    pub fn join(&self) {
+        let mut state = self.shared.lock();
+        while state.pending > 0 {
+            state = self
+                .shared
+                .idle
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Returns the number of threads of the pool.
+    pub fn size(&self) -> usize {
+        self.threads.len()
+    }
+
+    /// Returns the number of submitted jobs not yet finished.
+    pub fn pending(&self) -> usize {
+        self.shared.lock().pending
+    }
+
+    /// Returns the number of jobs that panicked.
+    pub fn panicked(&self) -> usize {
+        self.shared.lock().panicked
+    }
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("size", &self.size())
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+/// Custom drop implementation for finishing every submitted job and stopping the threads.
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.shared.lock().shutdown = true;
+        self.shared.work.notify_all();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Runs the jobs of a thread of the pool until the pool is dropped.
+fn run(index: usize, mut worker: Worker<Job>, shared: &Shared) {
+    loop {
+        let job = worker
+            .pop()
+            .or_else(|| shared.take_injected(&mut worker))
+            .or_else(|| shared.steal(index));
+        match job {
+            Some(job) => {
+                let panicked = panic::catch_unwind(AssertUnwindSafe(job)).is_err();
+                shared.finish(panicked);
+            }
+            None if shared.wait() => {}
+            None => break,
+        }
+    }
+}
@@ -3,7 +3,7 @@ use queue::queue::Queue;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::ops::Add;
-use vec::linked_vec;
+use vec::storage::{Linked, Sequence, Storage};
 use vec::vec::LinkedVec;
 
 use crate::graph::{vertex::Vertex, Graph};
@@ -20,7 +20,8 @@ use crate::graph::{vertex::Vertex, Graph};
 ///
 /// # Returns
 ///
-/// A tuple containing the total distance between the two vertices and the path between them.
+/// A tuple containing the total distance between the two vertices and the path between them,
+/// from `start` to `end`.
 ///
 /// # Example
 ///
@@ -41,6 +42,7 @@ use crate::graph::{vertex::Vertex, Graph};
 /// println!("Total distance: {}", result.0);
 ///
 /// assert_eq!(result.0, 2);
+/// assert_eq!(result.1.to_vec(), vec!["A", "B", "C"]);
 /// ```
 pub fn breadth_first_search<
     T: Clone + Debug + Display + Eq + Hash,
@@ -50,15 +52,52 @@ pub fn breadth_first_search<
     start: Vertex<T, U>,
     end: Vertex<T, U>,
 ) -> (U, LinkedVec<T>) {
+    breadth_first_search_with_storage::<T, U, Linked>(graph, start, end)
+}
+
+/// Breadth-first search storing its bookkeeping and the path in the storage `S`.
+///
+/// Works like [`breadth_first_search`], whose results are backed by linked vectors.
+///
+/// # Example
+///
+/// ```
+/// use crate::graph::{graph::Graph, breadth_first_search::breadth_first_search_with_storage};
+/// use vec::storage::{Contiguous, Sequence};
+///
+/// let mut graph = Graph::new();
+///
+/// graph.add_edge(&"A", &"B", 1);
+/// graph.add_edge(&"B", &"C", 1);
+///
+/// let start_vertex = graph.get_vertex(&"A").unwrap().clone();
+/// let end_vertex = graph.get_vertex(&"C").unwrap().clone();
+///
+/// let (distance, path) =
+///     breadth_first_search_with_storage::<_, _, Contiguous>(&mut graph, start_vertex, end_vertex);
+///
+/// assert_eq!(distance, 2);
+/// assert_eq!(path.iter().collect::<Vec<_>>(), vec![&"A", &"B", &"C"]);
+/// ```
+pub fn breadth_first_search_with_storage<
+    T: Clone + Debug + Display + Eq + Hash,
+    U: Debug + Default + Clone + PartialEq + Add<Output = U>,
+    S: Storage,
+>(
+    graph: &mut Graph<T, U>,
+    start: Vertex<T, U>,
+    end: Vertex<T, U>,
+) -> (U, S::Vec<T>) {
     if !graph.contains(start.get_key()) {
-        return (U::default(), linked_vec![]);
+        return (U::default(), S::Vec::new());
     }
     if !graph.contains(end.get_key()) {
-        return (U::default(), linked_vec![]);
+        return (U::default(), S::Vec::new());
     }
 
-    let mut predecessors: HashMap<T, Option<T>> = HashMap::with_capacity(graph.vertices_count());
-    let mut distances: HashMap<T, U> = HashMap::with_capacity(graph.vertices_count());
+    let mut predecessors: HashMap<T, Option<T>, S> =
+        HashMap::with_storage(graph.vertices_count() + 1);
+    let mut distances: HashMap<T, U, S> = HashMap::with_storage(graph.vertices_count() + 1);
     distances.insert(start.get_key().clone(), U::default());
     predecessors.insert(start.get_key().clone(), None);
 
@@ -74,13 +113,17 @@ pub fn breadth_first_search<
             if vertex_neighbor.get_key() == start.get_key() {
                 continue;
             } else if vertex_neighbor.get_key() == end.get_key() {
-                let mut path = linked_vec![vertex_neighbor.get_key().clone()];
+                let mut keys = S::Vec::new();
+                keys.push(vertex_neighbor.get_key().clone());
                 let mut current_vertex = Some(vertex.get_key().clone());
                 while let Some(key) = current_vertex {
-                    path.push(key.clone());
-                    current_vertex = predecessors.get(&key.clone()).unwrap().clone();
+                    current_vertex = predecessors.get(&key).unwrap().clone();
+                    keys.push(key);
+                }
+                let mut path = S::Vec::new();
+                while let Some(key) = keys.pop() {
+                    path.push(key);
                 }
-                path.reverse();
 
                 return (
                     distances
@@ -107,5 +150,5 @@ pub fn breadth_first_search<
         }
     }
 
-    (U::default(), linked_vec![])
+    (U::default(), S::Vec::new())
 }
@@ -5,7 +5,7 @@ use std::ops::Add;
 use crate::graph::{vertex::Vertex, Graph};
 use hash_map::hash_map::HashMap;
 use stack::stack::Stack;
-use vec::linked_vec;
+use vec::storage::{Linked, Sequence, Storage};
 use vec::vec::LinkedVec;
 
 /// # Depth First Search
@@ -20,7 +20,8 @@ use vec::vec::LinkedVec;
 ///
 /// # Returns
 ///
-/// A tuple containing the total distance between the two vertices and the path between them.
+/// A tuple containing the total distance between the two vertices and the path between them,
+/// from `start` to `end`.
 ///
 /// # Example
 ///
@@ -41,6 +42,7 @@ use vec::vec::LinkedVec;
 /// println!("Total distance: {}", result.0);
 ///     
 /// assert_eq!(result.0, 2);
+/// assert_eq!(result.1.to_vec(), vec!["A", "B", "C"]);
 /// ```
 pub fn depth_first_search<
     T: Clone + Debug + Display + Eq + Hash,
@@ -50,25 +52,62 @@ pub fn depth_first_search<
     start: Vertex<T, U>,
     end: Vertex<T, U>,
 ) -> (U, LinkedVec<T>) {
+    depth_first_search_with_storage::<T, U, Linked>(graph, start, end)
+}
+
+/// Depth-first search storing its bookkeeping and the path in the storage `S`.
+///
+/// Works like [`depth_first_search`], whose results are backed by linked vectors.
+///
+/// # Example
+///
+/// ```
+/// use crate::graph::{graph::Graph, depth_first_search::depth_first_search_with_storage};
+/// use vec::storage::{Contiguous, Sequence};
+///
+/// let mut graph = Graph::new();
+///
+/// graph.add_edge(&"A", &"B", 1);
+/// graph.add_edge(&"B", &"C", 1);
+///
+/// let start_vertex = graph.get_vertex(&"A").unwrap().clone();
+/// let end_vertex = graph.get_vertex(&"C").unwrap().clone();
+///
+/// let (distance, path) =
+///     depth_first_search_with_storage::<_, _, Contiguous>(&mut graph, start_vertex, end_vertex);
+///
+/// assert_eq!(distance, 2);
+/// assert_eq!(path.iter().collect::<Vec<_>>(), vec![&"A", &"B", &"C"]);
+/// ```
+pub fn depth_first_search_with_storage<
+    T: Clone + Debug + Display + Eq + Hash,
+    U: Debug + Default + Clone + PartialEq + Add<Output = U>,
+    S: Storage,
+>(
+    graph: &mut Graph<T, U>,
+    start: Vertex<T, U>,
+    end: Vertex<T, U>,
+) -> (U, S::Vec<T>) {
     if !graph.contains(start.get_key()) {
-        return (U::default(), linked_vec![]);
+        return (U::default(), S::Vec::new());
     }
     if !graph.contains(end.get_key()) {
-        return (U::default(), linked_vec![]);
+        return (U::default(), S::Vec::new());
     }
 
-    let mut predecessors: HashMap<T, Option<T>> = HashMap::with_capacity(graph.vertices_count());
-    let mut distances = HashMap::with_capacity(graph.vertices_count());
+    let mut predecessors: HashMap<T, Option<T>, S> =
+        HashMap::with_storage(graph.vertices_count() + 1);
+    let mut distances: HashMap<T, U, S> = HashMap::with_storage(graph.vertices_count() + 1);
     distances.insert(start.get_key().clone(), U::default());
     predecessors.insert(start.get_key().clone(), None);
 
-    let mut stack = Stack::new();
+    let mut stack: Stack<_, S> = Stack::with_storage();
     stack.push(start.clone());
 
     while !stack.is_empty() {
         let vertex = stack.pop().unwrap();
 
-        let mut temp = LinkedVec::new();
+        let mut temp = S::Vec::new();
 
         for neighbor in vertex.get_neighbors() {
             let vertex_neighbor = graph.get_vertex_mut(&neighbor.0).unwrap();
@@ -76,14 +115,17 @@ pub fn depth_first_search<
             if vertex_neighbor.get_key() == start.get_key() {
                 continue;
             } else if vertex_neighbor.get_key() == end.get_key() {
-                let mut path = linked_vec![vertex_neighbor.get_key().clone()];
-
+                let mut keys = S::Vec::new();
+                keys.push(vertex_neighbor.get_key().clone());
                 let mut current_vertex = Some(vertex.get_key().clone());
                 while let Some(key) = current_vertex {
-                    path.push(key.clone());
-                    current_vertex = predecessors.get(&key.clone()).unwrap().clone();
+                    current_vertex = predecessors.get(&key).unwrap().clone();
+                    keys.push(key);
+                }
+                let mut path = S::Vec::new();
+                while let Some(key) = keys.pop() {
+                    path.push(key);
                 }
-                path.reverse();
 
                 let total_distance = distances
                     .get(vertex.get_key())
@@ -112,5 +154,5 @@ pub fn depth_first_search<
         }
     }
 
-    (U::default(), linked_vec![])
+    (U::default(), S::Vec::new())
 }
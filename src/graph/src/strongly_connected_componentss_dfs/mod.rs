@@ -104,8 +104,8 @@ pub fn strongly_connected_components_dfs<
         for (neighbor, _) in vertex.get_neighbors() {
             if !visited.get(&neighbor.clone()).unwrap() {
                 let neighbor = graph.get_vertex(&neighbor).unwrap().clone();
-                let sub_component = get_component(graph, visited, neighbor);
-                component.extend(sub_component);
+                let mut sub_component = get_component(graph, visited, neighbor);
+                component.append(&mut sub_component);
             }
         }
 
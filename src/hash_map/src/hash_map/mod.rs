@@ -2,11 +2,16 @@ use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 
-use vec::linked_vec;
-use vec::vec::LinkedVec;
+use vec::storage::{Linked, Sequence, Storage};
 
 /// A simple hash map implementation using a linked vector for buckets.
 ///
+/// # Generic Parameters
+///
+/// * `K` - Type of the keys.
+/// * `V` - Type of the values.
+/// * `S` - The [`Storage`] holding the bucket table and each bucket, [`Linked`] by default.
+///
 /// # Examples
 ///
 /// ```
@@ -20,9 +25,21 @@ use vec::vec::LinkedVec;
 /// assert_eq!(map.get(&"key2"), Some(&"value2"));
 /// assert_eq!(map.get(&"key3"), None);
 /// ```
+///
+/// Using contiguous storage, which makes finding a bucket O(1):
+///
+/// ```
+/// use crate::hash_map::hash_map::HashMap;
+/// use vec::storage::Contiguous;
+///
+/// let mut map: HashMap<&str, i32, Contiguous> = HashMap::with_storage(16);
+/// map.insert("key1", 1);
+///
+/// assert_eq!(map.get(&"key1"), Some(&1));
+/// ```
 #[derive(Debug, Clone, PartialEq)]
-pub struct HashMap<K, V> {
-    buckets: LinkedVec<LinkedVec<(K, V)>>,
+pub struct HashMap<K, V, S: Storage = Linked> {
+    buckets: S::Vec<S::Vec<(K, V)>>,
     size: usize,
 }
 
@@ -31,7 +48,7 @@ where
     K: Hash + Eq + Clone + Debug,
     V: Clone + PartialEq + Debug,
 {
-    /// Creates a new hash map with the specified capacity.
+    /// Creates a new hash map, backed by linked vectors, with the specified capacity.
     ///
     /// # Arguments
     ///
@@ -45,11 +62,10 @@ where
     /// let map: HashMap<&str, &str> = HashMap::with_capacity(10);
     /// ```
     pub fn with_capacity(capacity: usize) -> Self {
-        let buckets = linked_vec![LinkedVec::new(); capacity + 1];
-        HashMap { buckets, size: 0 }
+        Self::with_storage(capacity + 1)
     }
 
-    /// Creates a new hash map with an initial capacity of 16.
+    /// Creates a new hash map, backed by linked vectors, with an initial capacity of 16.
     ///
     /// # Examples
     ///
@@ -59,9 +75,45 @@ where
     /// let map: HashMap<&str, &str> = HashMap::new();
     /// ```
     pub fn new() -> Self {
-        let initial_capacity = 16;
-        let buckets = linked_vec![LinkedVec::new(); initial_capacity];
-        HashMap { buckets, size: 0 }
+        Self::with_storage(16)
+    }
+}
+
+impl<K, V, S: Storage> Default for HashMap<K, V, S>
+where
+    K: Hash + Eq + Clone + Debug,
+    V: Clone + PartialEq + Debug,
+{
+    fn default() -> Self {
+        Self::with_storage(16)
+    }
+}
+
+impl<K, V, S: Storage> HashMap<K, V, S>
+where
+    K: Hash + Eq + Clone + Debug,
+    V: Clone + PartialEq + Debug,
+{
+    /// Creates a new hash map, backed by the storage `S`, with the specified number of buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The initial number of buckets (at least one is always allocated).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::hash_map::hash_map::HashMap;
+    /// use vec::storage::Contiguous;
+    ///
+    /// let map: HashMap<&str, &str, Contiguous> = HashMap::with_storage(10);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn with_storage(capacity: usize) -> Self {
+        HashMap {
+            buckets: Self::empty_buckets(capacity.max(1)),
+            size: 0,
+        }
     }
 
     /// Inserts a key-value pair into the hash map.
@@ -91,7 +143,7 @@ where
         }
 
         let bucket_index = self.hash(&key);
-        let bucket = &mut self.buckets[bucket_index];
+        let bucket = self.buckets.find_mut(bucket_index).unwrap();
 
         for &mut (ref existing_key, ref mut existing_value) in bucket.iter_mut() {
             if existing_key == &key {
@@ -126,12 +178,12 @@ where
     /// ```
     pub fn remove(&mut self, key: &K) -> Option<V> {
         let bucket_index = self.hash(key);
-        let bucket = &mut self.buckets[bucket_index];
+        let bucket = self.buckets.find_mut(bucket_index).unwrap();
 
-        if let Some(pos) = bucket
+        let pos = bucket
             .iter()
-            .position(|(existing_key, _)| existing_key == key)
-        {
+            .position(|(existing_key, _)| existing_key == key);
+        if let Some(pos) = pos {
             let (_, value) = bucket.remove(pos).unwrap();
             self.size -= 1;
             return Some(value);
@@ -163,7 +215,7 @@ where
     /// ```
     pub fn get(&self, key: &K) -> Option<&V> {
         let bucket_index = self.hash(key);
-        let bucket = &self.buckets[bucket_index];
+        let bucket = self.buckets.find(bucket_index).unwrap();
 
         for (existing_key, value) in bucket.iter() {
             if existing_key == key {
@@ -176,7 +228,7 @@ where
 
     pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
         let bucket_index = self.hash(key);
-        let bucket = &mut self.buckets[bucket_index];
+        let bucket = self.buckets.find_mut(bucket_index).unwrap();
 
         for (existing_key, value) in bucket.iter_mut() {
             if existing_key == key {
@@ -228,19 +280,25 @@ where
 
     fn resize(&mut self) {
         let new_capacity = self.buckets.len() * 2;
-        let mut new_buckets = linked_vec![LinkedVec::new(); new_capacity];
-
-        for bucket in self.buckets.drain().into_iter() {
-            for (key, value) in bucket.iter() {
-                let new_bucket_index = {
-                    let mut hasher: DefaultHasher = DefaultHasher::new();
-                    key.hash(&mut hasher);
-                    (hasher.finish() as usize) % new_capacity
-                };
-                new_buckets[new_bucket_index].push((key.clone(), value.clone()));
+        let mut old_buckets =
+            std::mem::replace(&mut self.buckets, Self::empty_buckets(new_capacity));
+
+        while let Some(mut bucket) = old_buckets.pop() {
+            while let Some((key, value)) = bucket.pop() {
+                let new_bucket_index = self.hash(&key);
+                self.buckets
+                    .find_mut(new_bucket_index)
+                    .unwrap()
+                    .push((key, value));
             }
         }
+    }
 
-        self.buckets = new_buckets;
+    fn empty_buckets(capacity: usize) -> S::Vec<S::Vec<(K, V)>> {
+        let mut buckets = S::Vec::new();
+        for _ in 0..capacity {
+            buckets.push(S::Vec::new());
+        }
+        buckets
     }
 }
@@ -0,0 +1,161 @@
+/// Describes a cycle found by following the links of a sequence of nodes.
+///
+/// # Fields
+///
+/// * `start` - The number of links followed from the first node before entering the cycle.
+/// * `length` - The number of nodes on the cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of links followed from the first node before entering the cycle.
+    pub start: usize,
+    /// The number of nodes on the cycle.
+    pub length: usize,
+}
+
+/// Detects a cycle in linked nodes with Floyd's tortoise and hare algorithm.
+///
+/// The nodes are given by a first node and a function returning the node linked after a node,
+/// or None at the end of the chain. Nodes can be anything comparable by identity, such as
+/// indices into an array of nodes or pointers. The tortoise follows one link at a time and the
+/// hare two, so they meet inside the cycle if there is one. Restarting the tortoise from the
+/// first node and moving both one link at a time then makes them meet at the start of the
+/// cycle.
+///
+/// Runs in O(start + length) time and O(1) extra space.
+///
+/// # Arguments
+///
+/// * `head` - The first node.
+/// * `next` - The function returning the node linked after a node.
+///
+/// # Returns
+///
+/// An option containing the position and length of the cycle, or None if the chain ends.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::cycle_detection::{floyd, Cycle};
+///
+/// // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+/// let links = [Some(1), Some(2), Some(3), Some(4), Some(2)];
+/// assert_eq!(
+///     floyd(0, |&node| links[node]),
+///     Some(Cycle { start: 2, length: 3 })
+/// );
+///
+/// // 0 -> 1 -> 2 -> end
+/// let links = [Some(1), Some(2), None];
+/// assert_eq!(floyd(0, |&node| links[node]), None);
+/// ```
+pub fn floyd<N, F>(head: N, mut next: F) -> Option<Cycle>
+where
+    N: Clone + PartialEq,
+    F: FnMut(&N) -> Option<N>,
+{
+    let mut tortoise = head.clone();
+    let mut hare = head.clone();
+    loop {
+        hare = next(&hare)?;
+        hare = next(&hare)?;
+        tortoise = next(&tortoise)?;
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    // The hare is as far ahead of the start of the cycle as the head is.
+    let mut start = 0;
+    tortoise = head;
+    while tortoise != hare {
+        tortoise = next(&tortoise)?;
+        hare = next(&hare)?;
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise)?;
+    while tortoise != hare {
+        hare = next(&hare)?;
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Detects a cycle in linked nodes with Brent's algorithm.
+///
+/// The nodes are given as for [`floyd`]. The hare follows one link at a time and the tortoise
+/// teleports to the hare whenever the number of steps since its last move reaches the next
+/// power of two, so that the hare meets it after walking exactly one cycle length. A second
+/// pass with the hare that many nodes ahead then finds the start of the cycle. It follows fewer
+/// links than Floyd's algorithm.
+///
+/// Runs in O(start + length) time and O(1) extra space.
+///
+/// # Arguments
+///
+/// * `head` - The first node.
+/// * `next` - The function returning the node linked after a node.
+///
+/// # Returns
+///
+/// An option containing the position and length of the cycle, or None if the chain ends.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::cycle_detection::{brent, floyd, Cycle};
+///
+/// // The sequence x -> (x * x + 1) % 255 ends in a cycle, like any function on a finite set.
+/// let step = |&x: &u32| Some((x * x + 1) % 255);
+/// let cycle = brent(3, step).unwrap();
+/// assert_eq!(floyd(3, step), Some(cycle));
+///
+/// let mut x = 3;
+/// for _ in 0..cycle.start {
+///     x = step(&x).unwrap();
+/// }
+/// let entry = x;
+/// for _ in 0..cycle.length {
+///     x = step(&x).unwrap();
+/// }
+/// assert_eq!(x, entry);
+///
+/// // A node linked to itself.
+/// assert_eq!(brent(0, |_| Some(0)), Some(Cycle { start: 0, length: 1 }));
+/// ```
+pub fn brent<N, F>(head: N, mut next: F) -> Option<Cycle>
+where
+    N: Clone + PartialEq,
+    F: FnMut(&N) -> Option<N>,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = head.clone();
+    let mut hare = next(&head)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare)?;
+        length += 1;
+    }
+
+    // Put the hare one cycle length ahead, then move both until they meet.
+    let mut start = 0;
+    tortoise = head.clone();
+    hare = head;
+    for _ in 0..length {
+        hare = next(&hare)?;
+    }
+    while tortoise != hare {
+        tortoise = next(&tortoise)?;
+        hare = next(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
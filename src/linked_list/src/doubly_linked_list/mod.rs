@@ -0,0 +1,1385 @@
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ptr::NonNull;
+use std::rc::{Rc, Weak};
+
+/// Type alias for an optional pointer to a node of a doubly linked list.
+type Link<T> = Option<NonNull<Node<T>>>;
+
+/// Represents a node in a doubly linked list.
+///
+/// Nodes are allocated inside an `Rc` whose single strong count belongs to the list, so that
+/// [`NodeRef`] handles can hold a `Weak` to them and notice when they have been removed. Every
+/// field is a cell: the list only ever reaches a node through shared references.
+struct Node<T> {
+    /// The data stored in the node.
+    data: UnsafeCell<T>,
+    /// Pointer to the previous node in the linked list.
+    prev: Cell<Link<T>>,
+    /// Pointer to the next node in the linked list.
+    next: Cell<Link<T>>,
+    /// Identifies the list the node belongs to (see [`ListId`]).
+    owner: RefCell<Rc<ListId>>,
+}
+
+/// Identifies a doubly linked list, so that handles to nodes of other lists can be rejected.
+///
+/// Appending a list moves its nodes in O(1) time by pointing the identity they carry at the
+/// identity of the receiving list instead of relabelling them. A node therefore belongs to the
+/// list whose identity is found by following `parent` links, as in a union-find forest.
+#[derive(Default)]
+struct ListId {
+    /// The identity this one was merged into, or None for the identity of a live list.
+    parent: RefCell<Option<Rc<ListId>>>,
+}
+
+impl ListId {
+    /// Returns the root identity of `id`, compressing the path to it along the way.
+    fn root(id: &Rc<ListId>) -> Rc<ListId> {
+        let mut root = Rc::clone(id);
+        loop {
+            let parent = root.parent.borrow().clone();
+            match parent {
+                Some(parent) => root = parent,
+                None => break,
+            }
+        }
+
+        let mut current = Rc::clone(id);
+        while !Rc::ptr_eq(&current, &root) {
+            let parent = current.parent.replace(Some(Rc::clone(&root)));
+            current = parent.expect("Identities below the root have a parent");
+        }
+        root
+    }
+}
+
+/// Custom drop implementation for releasing a chain of merged identities without recursion.
+impl Drop for ListId {
+    fn drop(&mut self) {
+        let mut parent = self.parent.get_mut().take();
+        while let Some(id) = parent {
+            parent = match Rc::try_unwrap(id) {
+                Ok(mut id) => id.parent.get_mut().take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// A stable handle to a node of a [`DoublyLinkedList`], returned by the methods inserting
+/// elements.
+///
+/// The handle stays valid while its element is in a list, even as other elements are added,
+/// removed or the list is appended to another one. Once the element has been removed (or its
+/// list dropped) every method taking the handle returns None. Handles are checked against the
+/// list they are used with, so a handle to a node of another list is rejected as well.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of the element the handle refers to.
+///
+/// # Fields
+///
+/// * `node` - Weak pointer to the node the handle refers to.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+///
+/// let mut list = DoublyLinkedList::new();
+/// list.push_back(1);
+/// let two = list.push_back(2);
+/// list.push_back(3);
+///
+/// assert_eq!(list.remove(&two), Some(2));
+/// assert!(two.is_removed());
+/// assert_eq!(list.remove(&two), None);
+/// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3]);
+/// ```
+pub struct NodeRef<T> {
+    /// Weak pointer to the node the handle refers to.
+    node: Weak<Node<T>>,
+}
+
+impl<T> NodeRef<T> {
+    /// Checks if the element the handle refers to has been removed from its list.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the element has been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// let node = list.push_back(1);
+    /// assert!(!node.is_removed());
+    ///
+    /// list.pop_back();
+    /// assert!(node.is_removed());
+    /// ```
+    pub fn is_removed(&self) -> bool {
+        self.node.strong_count() == 0
+    }
+}
+
+impl<T> Clone for NodeRef<T> {
+    fn clone(&self) -> Self {
+        NodeRef {
+            node: Weak::clone(&self.node),
+        }
+    }
+}
+
+impl<T> PartialEq for NodeRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.node, &other.node)
+    }
+}
+
+impl<T> Eq for NodeRef<T> {}
+
+impl<T> fmt::Debug for NodeRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeRef")
+            .field("removed", &self.is_removed())
+            .finish()
+    }
+}
+
+/// Represents a doubly linked list.
+///
+/// Each node points at both of its neighbours, so elements can be added and removed at both
+/// ends in O(1) time, and a whole list can be moved to the end of another one in O(1) time.
+/// The methods adding an element return a [`NodeRef`] handle, with which that element can later
+/// be read, removed, moved or used as an insertion point in O(1) time, wherever it sits in the
+/// list.
+///
+/// The handles share bookkeeping with the list through `Rc`, so the list cannot be sent to
+/// another thread.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the linked list.
+///
+/// # Fields
+///
+/// * `size` - The size of the linked list.
+/// * `head` - Pointer to the first node of the linked list.
+/// * `tail` - Pointer to the last node of the linked list.
+/// * `id` - The identity carried by the nodes of the linked list.
+/// * `marker` - Tells the compiler the linked list owns values of type `T`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+///
+/// let mut list = DoublyLinkedList::new();
+/// list.push_back(2);
+/// list.push_back(3);
+/// list.push_front(1);
+///
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list.pop_back(), Some(3));
+/// assert_eq!(list.pop_front(), Some(1));
+/// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2]);
+/// ```
+pub struct DoublyLinkedList<T> {
+    /// The size of the linked list.
+    size: usize,
+    /// Pointer to the first node of the linked list.
+    head: Link<T>,
+    /// Pointer to the last node of the linked list.
+    tail: Link<T>,
+    /// The identity carried by the nodes of the linked list.
+    id: Rc<ListId>,
+    /// Tells the compiler the linked list owns values of type `T`.
+    marker: PhantomData<T>,
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Creates a new empty doubly linked list.
+    ///
+    /// # Returns
+    ///
+    /// A new empty doubly linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        DoublyLinkedList {
+            size: 0,
+            head: None,
+            tail: None,
+            id: Rc::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the size of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=3).collect();
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks if the linked list is empty.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the linked list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_back(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Adds an element to the front of the linked list in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to be added.
+    ///
+    /// # Returns
+    ///
+    /// A handle to the new element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_front(2);
+    /// let one = list.push_front(1);
+    ///
+    /// assert_eq!(list.front(), Some(&1));
+    /// assert_eq!(list.get(&one), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, data: T) -> NodeRef<T> {
+        let node = self.allocate(data);
+        // SAFETY: `node` is a fresh node and `head` is the first node of this list.
+        unsafe { self.link_between(node, None, self.head) };
+        Self::handle(node)
+    }
+
+    /// Adds an element to the back of the linked list in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to be added.
+    ///
+    /// # Returns
+    ///
+    /// A handle to the new element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    /// let two = list.push_back(2);
+    ///
+    /// assert_eq!(list.back(), Some(&2));
+    /// assert_eq!(list.get(&two), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, data: T) -> NodeRef<T> {
+        let node = self.allocate(data);
+        // SAFETY: `node` is a fresh node and `tail` is the last node of this list.
+        unsafe { self.link_between(node, self.tail, None) };
+        Self::handle(node)
+    }
+
+    /// Removes the first element of the linked list in O(1) time.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the removed data, or None if the linked list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=2).collect();
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        // SAFETY: `head` is a node of this list.
+        self.head.map(|node| unsafe { self.remove_node(node) })
+    }
+
+    /// Removes the last element of the linked list in O(1) time.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the removed data, or None if the linked list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=2).collect();
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        // SAFETY: `tail` is a node of this list.
+        self.tail.map(|node| unsafe { self.remove_node(node) })
+    }
+
+    /// Returns a reference to the first element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the first element, or None if the linked list is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=3).collect();
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: `head` is a node of this list, which is borrowed for the returned lifetime.
+        self.head
+            .map(|node| unsafe { &*Self::node(node).data.get() })
+    }
+
+    /// Returns a mutable reference to the first element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a mutable reference to the first element, or None if the linked
+    /// list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=3).collect();
+    /// *list.front_mut().unwrap() = 10;
+    /// assert_eq!(list.front(), Some(&10));
+    /// ```
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `head` is a node of this list, which is mutably borrowed for the returned
+        // lifetime.
+        self.head
+            .map(|node| unsafe { &mut *Self::node(node).data.get() })
+    }
+
+    /// Returns a reference to the last element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the last element, or None if the linked list is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=3).collect();
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: `tail` is a node of this list, which is borrowed for the returned lifetime.
+        self.tail
+            .map(|node| unsafe { &*Self::node(node).data.get() })
+    }
+
+    /// Returns a mutable reference to the last element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a mutable reference to the last element, or None if the linked
+    /// list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=3).collect();
+    /// *list.back_mut().unwrap() = 30;
+    /// assert_eq!(list.back(), Some(&30));
+    /// ```
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `tail` is a node of this list, which is mutably borrowed for the returned
+        // lifetime.
+        self.tail
+            .map(|node| unsafe { &mut *Self::node(node).data.get() })
+    }
+
+    /// Moves all elements of `other` to the back of the linked list in O(1) time, leaving
+    /// `other` empty.
+    ///
+    /// Handles to the moved elements stay valid and now refer to elements of this list.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The linked list whose elements are moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=2).collect();
+    /// let mut other = DoublyLinkedList::new();
+    /// let three = other.push_back(3);
+    ///
+    /// list.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(list.get(&three), Some(&3));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    ///
+    /// // Appending takes the same time however long the lists are.
+    /// let mut first: DoublyLinkedList<_> = (0..200_000).collect();
+    /// let mut second: DoublyLinkedList<_> = (0..200_000).collect();
+    /// for _ in 0..200_000 {
+    ///     first.append(&mut second);
+    ///     second.append(&mut first);
+    /// }
+    /// assert_eq!(second.len(), 400_000);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            mem::swap(self, other);
+            return;
+        }
+
+        if let (Some(tail), Some(head)) = (self.tail, other.head) {
+            // SAFETY: `tail` is the last node of this list and `head` the first node of `other`.
+            unsafe {
+                Self::node(tail).next.set(Some(head));
+                Self::node(head).prev.set(Some(tail));
+            }
+        }
+        self.tail = other.tail.take();
+        other.head = None;
+        self.size += mem::take(&mut other.size);
+
+        // The moved nodes still carry the identity of `other`: merge it into ours.
+        *other.id.parent.borrow_mut() = Some(Rc::clone(&self.id));
+        other.id = Rc::default();
+    }
+
+    /// Splits the linked list into two at the given index.
+    ///
+    /// The linked list keeps the elements before `at` and the returned linked list holds the
+    /// rest. Handles to the elements stay valid and follow them. Takes O(min(at, len - at))
+    /// time.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index of the first element to split off.
+    ///
+    /// # Returns
+    ///
+    /// A linked list containing the elements from index `at` onwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the size of the linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    /// let two = list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// let mut rest = list.split_off(1);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1]);
+    /// assert_eq!(rest.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    ///
+    /// assert_eq!(list.remove(&two), None);
+    /// assert_eq!(rest.remove(&two), Some(2));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.size, "Index out of bounds");
+
+        let mut other = DoublyLinkedList::new();
+        if at == self.size {
+            return other;
+        }
+        if at == 0 {
+            mem::swap(self, &mut other);
+            return other;
+        }
+
+        let split = self.node_at(at).expect("Index is in bounds");
+        // SAFETY: `split` is a node of this list and, as `at > 0`, it has a previous node.
+        let before = unsafe {
+            let before = Self::node(split).prev.take();
+            if let Some(before) = before {
+                Self::node(before).next.set(None);
+            }
+            before
+        };
+        other.head = Some(split);
+        other.tail = self.tail;
+        other.size = self.size - at;
+        self.tail = before;
+        self.size = at;
+
+        // Give the fresh identity to the shorter half, whose nodes have to be relabelled.
+        if other.size <= self.size {
+            other.relabel();
+        } else {
+            mem::swap(&mut self.id, &mut other.id);
+            self.relabel();
+        }
+        other
+    }
+
+    /// Removes all elements from the linked list.
+    ///
+    /// Handles to the removed elements become invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=3).collect();
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Checks if the linked list contains the given element.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The element to look for.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the element is in the linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=3).collect();
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+
+    /// Returns a reference to the element a handle refers to, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the element, or None if the handle does not refer to
+    /// an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// let one = list.push_back(1);
+    /// let other: DoublyLinkedList<i32> = DoublyLinkedList::new();
+    ///
+    /// assert_eq!(list.get(&one), Some(&1));
+    /// assert_eq!(other.get(&one), None);
+    /// ```
+    pub fn get(&self, node: &NodeRef<T>) -> Option<&T> {
+        // SAFETY: the node belongs to this list, which is borrowed for the returned lifetime.
+        self.locate(node)
+            .map(|node| unsafe { &*Self::node(node).data.get() })
+    }
+
+    /// Returns a mutable reference to the element a handle refers to, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a mutable reference to the element, or None if the handle does not
+    /// refer to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// let one = list.push_back(1);
+    /// *list.get_mut(&one).unwrap() += 10;
+    /// assert_eq!(list.front(), Some(&11));
+    /// ```
+    pub fn get_mut(&mut self, node: &NodeRef<T>) -> Option<&mut T> {
+        // SAFETY: the node belongs to this list, which is mutably borrowed for the returned
+        // lifetime.
+        self.locate(node)
+            .map(|node| unsafe { &mut *Self::node(node).data.get() })
+    }
+
+    /// Removes the element a handle refers to, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the removed data, or None if the handle does not refer to an
+    /// element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// let handles: Vec<_> = (0..5).map(|data| list.push_back(data)).collect();
+    ///
+    /// assert_eq!(list.remove(&handles[2]), Some(2));
+    /// assert_eq!(list.remove(&handles[0]), Some(0));
+    /// assert_eq!(list.remove(&handles[4]), Some(4));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3]);
+    /// ```
+    pub fn remove(&mut self, node: &NodeRef<T>) -> Option<T> {
+        let node = self.locate(node)?;
+        // SAFETY: the node belongs to this list.
+        Some(unsafe { self.remove_node(node) })
+    }
+
+    /// Inserts an element right after the element a handle refers to, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    /// * `data` - The data to be inserted.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a handle to the new element, or None (without inserting anything)
+    /// if `node` does not refer to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// let one = list.push_back(1);
+    /// list.push_back(4);
+    ///
+    /// let two = list.insert_after(&one, 2).unwrap();
+    /// list.insert_after(&two, 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    pub fn insert_after(&mut self, node: &NodeRef<T>, data: T) -> Option<NodeRef<T>> {
+        let node = self.locate(node)?;
+        let new_node = self.allocate(data);
+        // SAFETY: `node` and its next node are adjacent nodes of this list.
+        unsafe {
+            let next = Self::node(node).next.get();
+            self.link_between(new_node, Some(node), next);
+        }
+        Some(Self::handle(new_node))
+    }
+
+    /// Inserts an element right before the element a handle refers to, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    /// * `data` - The data to be inserted.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a handle to the new element, or None (without inserting anything)
+    /// if `node` does not refer to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    /// let three = list.push_back(3);
+    ///
+    /// list.insert_before(&three, 2);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn insert_before(&mut self, node: &NodeRef<T>, data: T) -> Option<NodeRef<T>> {
+        let node = self.locate(node)?;
+        let new_node = self.allocate(data);
+        // SAFETY: the previous node of `node` and `node` are adjacent nodes of this list.
+        unsafe {
+            let prev = Self::node(node).prev.get();
+            self.link_between(new_node, prev, Some(node));
+        }
+        Some(Self::handle(new_node))
+    }
+
+    /// Moves the element a handle refers to to the front of the linked list, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the element was moved, false if the handle does not refer
+    /// to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    /// let two = list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// assert!(list.move_to_front(&two));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2, &1, &3]);
+    /// ```
+    pub fn move_to_front(&mut self, node: &NodeRef<T>) -> bool {
+        match self.locate(node) {
+            Some(node) => {
+                // SAFETY: `node` is a node of this list, and once unlinked `head` is the first
+                // node of the remaining list.
+                unsafe {
+                    self.unlink(node);
+                    self.link_between(node, None, self.head);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the element a handle refers to to the back of the linked list, in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the element was moved, false if the handle does not refer
+    /// to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    /// let two = list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// assert!(list.move_to_back(&two));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3, &2]);
+    /// ```
+    pub fn move_to_back(&mut self, node: &NodeRef<T>) -> bool {
+        match self.locate(node) {
+            Some(node) => {
+                // SAFETY: `node` is a node of this list, and once unlinked `tail` is the last
+                // node of the remaining list.
+                unsafe {
+                    self.unlink(node);
+                    self.link_between(node, self.tail, None);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a handle to the first element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a handle to the first element, or None if the linked list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=3).collect();
+    /// let first = list.front_node().unwrap();
+    /// assert_eq!(list.remove(&first), Some(1));
+    /// ```
+    pub fn front_node(&self) -> Option<NodeRef<T>> {
+        self.head.map(Self::handle)
+    }
+
+    /// Returns a handle to the last element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a handle to the last element, or None if the linked list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=3).collect();
+    /// let last = list.back_node().unwrap();
+    /// assert_eq!(list.remove(&last), Some(3));
+    /// ```
+    pub fn back_node(&self) -> Option<NodeRef<T>> {
+        self.tail.map(Self::handle)
+    }
+
+    /// Returns a handle to the element following the one a handle refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a handle to the next element, or None if `node` refers to the last
+    /// element or does not refer to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=6).collect();
+    ///
+    /// // Remove every even element while walking the list through handles.
+    /// let mut node = list.front_node();
+    /// while let Some(current) = node {
+    ///     node = list.next_node(&current);
+    ///     if list.get(&current).unwrap() % 2 == 0 {
+    ///         list.remove(&current);
+    ///     }
+    /// }
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3, &5]);
+    /// ```
+    pub fn next_node(&self, node: &NodeRef<T>) -> Option<NodeRef<T>> {
+        let node = self.locate(node)?;
+        // SAFETY: `node` is a node of this list.
+        unsafe { Self::node(node).next.get() }.map(Self::handle)
+    }
+
+    /// Returns a handle to the element preceding the one a handle refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - A handle to an element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a handle to the previous element, or None if `node` refers to the
+    /// first element or does not refer to an element of this linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=3).collect();
+    /// let last = list.back_node().unwrap();
+    /// let middle = list.prev_node(&last).unwrap();
+    /// assert_eq!(list.get(&middle), Some(&2));
+    /// ```
+    pub fn prev_node(&self, node: &NodeRef<T>) -> Option<NodeRef<T>> {
+        let node = self.locate(node)?;
+        // SAFETY: `node` is a node of this list.
+        unsafe { Self::node(node).prev.get() }.map(Self::handle)
+    }
+
+    /// Returns an iterator over the elements of the linked list, front to back.
+    ///
+    /// The iterator is double-ended, so `rev` walks the linked list back to front.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=3).collect();
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a mutable iterator over the elements of the linked list, front to back.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over mutable references to the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<_> = (1..=3).collect();
+    /// for item in list.iter_mut().rev().take(2) {
+    ///     *item *= 10;
+    /// }
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &20, &30]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Allocates a detached node carrying the identity of this list.
+    fn allocate(&self, data: T) -> NonNull<Node<T>> {
+        let node = Rc::new(Node {
+            data: UnsafeCell::new(data),
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            owner: RefCell::new(Rc::clone(&self.id)),
+        });
+        // SAFETY: `Rc::into_raw` never returns a null pointer.
+        unsafe { NonNull::new_unchecked(Rc::into_raw(node) as *mut Node<T>) }
+    }
+
+    /// Creates a handle to a node of the list.
+    fn handle(node: NonNull<Node<T>>) -> NodeRef<T> {
+        // SAFETY: `node` came from `Rc::into_raw` and the list still owns that strong count;
+        // `ManuallyDrop` keeps it from being released here.
+        let node = ManuallyDrop::new(unsafe { Rc::from_raw(node.as_ptr() as *const Node<T>) });
+        NodeRef {
+            node: Rc::downgrade(&node),
+        }
+    }
+
+    /// Returns the node a handle refers to if it is a node of this list.
+    fn locate(&self, node: &NodeRef<T>) -> Option<NonNull<Node<T>>> {
+        let node = node.node.upgrade()?;
+        let owner = ListId::root(&node.owner.borrow());
+        if !Rc::ptr_eq(&owner, &self.id) {
+            return None;
+        }
+        *node.owner.borrow_mut() = owner;
+
+        // The list keeps the node alive after the upgraded count is released.
+        NonNull::new(Rc::as_ptr(&node) as *mut Node<T>)
+    }
+
+    /// Returns the node at the given index, walking from the nearer end.
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index >= self.size {
+            return None;
+        }
+
+        // SAFETY: the walks stay within the `size` nodes of this list.
+        unsafe {
+            if index < self.size / 2 {
+                let mut current = self.head;
+                for _ in 0..index {
+                    current = Self::node(current?).next.get();
+                }
+                current
+            } else {
+                let mut current = self.tail;
+                for _ in index + 1..self.size {
+                    current = Self::node(current?).prev.get();
+                }
+                current
+            }
+        }
+    }
+
+    /// Makes every node of the list carry the identity of the list directly.
+    fn relabel(&mut self) {
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: `node` is a node of this list.
+            let node = unsafe { Self::node(node) };
+            *node.owner.borrow_mut() = Rc::clone(&self.id);
+            current = node.next.get();
+        }
+    }
+
+    /// Dereferences a node pointer.
+    ///
+    /// # Safety
+    ///
+    /// `node` must point at a live node, which must outlive the returned reference.
+    unsafe fn node<'a>(node: NonNull<Node<T>>) -> &'a Node<T> {
+        &*node.as_ptr()
+    }
+
+    /// Links a detached node between two adjacent nodes of the list.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be detached, `prev` must be None or a node of this list, and `next` must be
+    /// the node following `prev` (the first node if `prev` is None).
+    unsafe fn link_between(&mut self, node: NonNull<Node<T>>, prev: Link<T>, next: Link<T>) {
+        Self::node(node).prev.set(prev);
+        Self::node(node).next.set(next);
+        match prev {
+            Some(prev) => Self::node(prev).next.set(Some(node)),
+            None => self.head = Some(node),
+        }
+        match next {
+            Some(next) => Self::node(next).prev.set(Some(node)),
+            None => self.tail = Some(node),
+        }
+        self.size += 1;
+    }
+
+    /// Unlinks a node from the list without releasing it.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a node of this list.
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) {
+        let prev = Self::node(node).prev.take();
+        let next = Self::node(node).next.take();
+        match prev {
+            Some(prev) => Self::node(prev).next.set(next),
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => Self::node(next).prev.set(prev),
+            None => self.tail = prev,
+        }
+        self.size -= 1;
+    }
+
+    /// Unlinks a node from the list and releases it, invalidating its handles.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a node of this list.
+    unsafe fn remove_node(&mut self, node: NonNull<Node<T>>) -> T {
+        self.unlink(node);
+        let node = Rc::from_raw(node.as_ptr() as *const Node<T>);
+        Rc::into_inner(node)
+            .expect("Only the list holds strong counts")
+            .data
+            .into_inner()
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Custom drop implementation for releasing the nodes one by one.
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DoublyLinkedList<T> {}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Converts the linked list into an iterator yielding its elements front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<_> = (1..=4).collect();
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(4));
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over references to the elements of a doubly linked list.
+///
+/// # Fields
+///
+/// * `head` - The next node to yield from the front.
+/// * `tail` - The next node to yield from the back.
+/// * `len` - The number of elements left to yield.
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            self.len -= 1;
+            // SAFETY: `node` is one of the `len` nodes left, which live as long as the list
+            // is borrowed.
+            let node = unsafe { DoublyLinkedList::node(node) };
+            self.head = node.next.get();
+            // SAFETY: as above; the list is only read while borrowed.
+            unsafe { &*node.data.get() }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            self.len -= 1;
+            // SAFETY: `node` is one of the `len` nodes left, which live as long as the list
+            // is borrowed.
+            let node = unsafe { DoublyLinkedList::node(node) };
+            self.tail = node.prev.get();
+            // SAFETY: as above; the list is only read while borrowed.
+            unsafe { &*node.data.get() }
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+/// An iterator over mutable references to the elements of a doubly linked list.
+///
+/// # Fields
+///
+/// * `head` - The next node to yield from the front.
+/// * `tail` - The next node to yield from the back.
+/// * `len` - The number of elements left to yield.
+pub struct IterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            self.len -= 1;
+            // SAFETY: `node` is one of the `len` nodes left, which live as long as the list
+            // is borrowed.
+            let node = unsafe { DoublyLinkedList::node(node) };
+            self.head = node.next.get();
+            // SAFETY: as above; each node is yielded once, so the references never alias.
+            unsafe { &mut *node.data.get() }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            self.len -= 1;
+            // SAFETY: `node` is one of the `len` nodes left, which live as long as the list
+            // is borrowed.
+            let node = unsafe { DoublyLinkedList::node(node) };
+            self.tail = node.prev.get();
+            // SAFETY: as above; each node is yielded once, so the references never alias.
+            unsafe { &mut *node.data.get() }
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// An iterator that moves the elements out of a doubly linked list.
+///
+/// # Fields
+///
+/// * `list` - The linked list being consumed.
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
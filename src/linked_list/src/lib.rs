@@ -1,7 +1,11 @@
 // --- region: modules
 
+pub mod cycle_detection;
+pub mod doubly_linked_list;
 pub mod linked_list;
 pub mod linked_stack;
 pub mod parenthese_checker;
+pub mod persistent_list;
+pub mod skip_list;
 
 // --- endregion: modules
@@ -1,32 +1,21 @@
-/// Type alias for a singly linked list node with an optional next node.
-pub type Next<T> = Option<Box<Node<T>>>;
-
-/// Represents a node in a singly linked list.
-#[derive(Debug)]
-pub struct Node<T> {
-    /// The data stored in the node.
-    pub data: T,
-    /// Pointer to the next node in the linked list.
-    pub next: Next<T>,
-}
+use std::cmp::Ordering;
+use std::mem;
 
-impl<T> Node<T> {
-    /// Creates a new node with the given data and next node pointer.
-    pub fn new(data: T, next: Next<T>) -> Self {
-        Node { data, next }
-    }
-}
+use vec::vec::LinkedVec;
+pub use vec::vec::{IntoIter, Iter, IterMut};
 
 /// Represents a singly linked list.
 ///
+/// The nodes, their merge sort and the cursor are those of [`LinkedVec`]; the linked list
+/// adds a stack interface and the classic in-place list algorithms on top of them.
+///
 /// # Generic Parameters
 ///
 /// * `T` - Type of elements stored in the linked list.
 ///
 /// # Fields
 ///
-/// * `size` - The size of the linked list.
-/// * `head` - Pointer to the head node of the linked list.
+/// * `list` - The linked vector holding the elements, front first.
 ///
 /// # Examples
 ///
@@ -41,11 +30,86 @@ impl<T> Node<T> {
 /// assert_eq!(list.size(), 1);
 /// assert_eq!(last, Some(2));
 /// ```
+///
+/// The in-place algorithms give the same results as the matching operations on a `Vec`:
+///
+/// ```
+/// use crate::linked_list::linked_list::LinkedList;
+///
+/// // Pseudo-random values with many duplicates, and the linked list holding them in order.
+/// fn generate(seed: u32, len: u32) -> Vec<u32> {
+///     (0..len)
+///         .map(|i| (i ^ seed).wrapping_mul(2_654_435_761) % 97)
+///         .collect()
+/// }
+/// fn list_of(data: &[u32]) -> LinkedList<u32> {
+///     let mut list = LinkedList::new();
+///     for &item in data.iter().rev() {
+///         list.push(item);
+///     }
+///     list
+/// }
+/// fn to_vec(list: &LinkedList<u32>) -> Vec<u32> {
+///     list.iter().copied().collect()
+/// }
+///
+/// let data = generate(0, 1_000);
+///
+/// let mut list = list_of(&data);
+/// let mut expected = data.clone();
+/// list.reverse();
+/// expected.reverse();
+/// assert_eq!(to_vec(&list), expected);
+///
+/// let list = list_of(&data);
+/// for n in 0..=data.len() {
+///     let expected = data.len().checked_sub(n + 1).map(|index| &data[index]);
+///     assert_eq!(list.nth_from_end(n), expected);
+/// }
+///
+/// for len in 0..=100 {
+///     let prefix = &data[..len];
+///     assert_eq!(list_of(prefix).middle(), prefix.get(len / 2));
+/// }
+///
+/// let mut list = list_of(&data);
+/// let (mut expected, mut others) = (data.clone(), data.clone());
+/// expected.retain(|&item| item % 3 == 0);
+/// others.retain(|&item| item % 3 != 0);
+/// assert_eq!(list.partition(|&item| item % 3 == 0), expected.len());
+/// expected.extend(others);
+/// assert_eq!(to_vec(&list), expected);
+///
+/// let mut list = list_of(&data);
+/// let mut expected = Vec::new();
+/// for &item in &data {
+///     if !expected.contains(&item) {
+///         expected.push(item);
+///     }
+/// }
+/// list.remove_duplicates();
+/// assert_eq!(to_vec(&list), expected);
+/// assert_eq!(list.size(), expected.len());
+///
+/// let mut list = list_of(&data);
+/// let mut expected = data.clone();
+/// list.sort();
+/// expected.sort();
+/// assert_eq!(to_vec(&list), expected);
+///
+/// let (mut first, mut second) = (generate(1, 600), generate(2, 400));
+/// first.sort();
+/// second.sort();
+/// let mut list = list_of(&first);
+/// list.merge_sorted(list_of(&second));
+/// let mut expected = [first, second].concat();
+/// expected.sort();
+/// assert_eq!(to_vec(&list), expected);
+/// assert_eq!(list.size(), 1_000);
+/// ```
 pub struct LinkedList<T> {
-    /// The size of the linked list.
-    size: usize,
-    /// Pointer to the head node of the linked list.
-    head: Next<T>,
+    /// The linked vector holding the elements, front first.
+    list: LinkedVec<T>,
 }
 
 impl<T> LinkedList<T> {
@@ -65,8 +129,7 @@ impl<T> LinkedList<T> {
     /// ```
     pub fn new() -> Self {
         LinkedList {
-            size: 0,
-            head: None,
+            list: LinkedVec::new(),
         }
     }
 
@@ -86,9 +149,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.peek(), Some(&1));
     /// ```
     pub fn push(&mut self, data: T) {
-        let new_head = Box::new(Node::new(data, self.head.take()));
-        self.head = Some(new_head);
-        self.size += 1;
+        self.list.push_front(data);
     }
 
     /// Pops an element from the front of the linked list.
@@ -108,11 +169,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.is_empty(), true);
     /// ```
     pub fn pop(&mut self) -> Option<T> {
-        self.head.take().map(|node| {
-            self.head = node.next;
-            self.size -= 1;
-            node.data
-        })
+        self.list.pop_front()
     }
 
     /// Returns a reference to the data at the front of the linked list.
@@ -131,7 +188,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.peek(), Some(&1));
     /// ```
     pub fn peek(&self) -> Option<&T> {
-        self.head.as_ref().map(|node| &node.data)
+        self.list.first()
     }
 
     /// Returns a mutable reference to the data at the front of the linked list.
@@ -153,7 +210,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.peek(), Some(&2));
     /// ```
     pub fn peek_mut(&mut self) -> Option<&mut T> {
-        self.head.as_mut().map(|node| &mut node.data)
+        self.list.find_mut(0)
     }
 
     /// Checks if the linked list is empty.
@@ -173,7 +230,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.is_empty(), false);
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.size == 0
+        self.list.is_empty()
     }
 
     /// Returns the size of the linked list.
@@ -193,7 +250,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.size(), 1);
     /// ```
     pub fn size(&self) -> usize {
-        self.size
+        self.list.len()
     }
 
     /// Clears the linked list, removing all elements.
@@ -209,8 +266,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.is_empty(), true);
     /// ```
     pub fn clear(&mut self) {
-        self.size = 0;
-        self.head = None;
+        self.list.clear();
     }
 
     /// Returns an iterator over the elements of the linked list.
@@ -232,10 +288,8 @@ impl<T> LinkedList<T> {
     /// assert_eq!(iter.next(), Some(&1));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> Iter<T> {
-        Iter {
-            next: self.head.as_deref(),
-        }
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
     }
 
     /// Returns a mutable iterator over the elements of the linked list.
@@ -259,10 +313,8 @@ impl<T> LinkedList<T> {
     /// assert_eq!(iter.next(), Some(&mut 1));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        IterMut {
-            next: self.head.as_deref_mut(),
-        }
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.list.iter_mut()
     }
 
     /// Consumes the linked list and returns an iterator over its elements.
@@ -285,59 +337,562 @@ impl<T> LinkedList<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+        self.list.into_iter()
     }
-}
 
-/// Iterator over the elements of a linked list.
-pub struct Iter<'a, T: 'a> {
-    next: Option<&'a Node<T>>,
-}
+    /// Returns a cursor pointing at the first element of the linked list.
+    ///
+    /// # Returns
+    ///
+    /// A [`CursorMut`] to edit the linked list at the cursor position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(2);
+    /// list.push(1);
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// cursor.move_next();
+    /// *cursor.current().unwrap() = 20;
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &20]);
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            cursor: self.list.cursor_front_mut(),
+        }
+    }
+
+    /// Reverses the order of the elements in place, in O(n) time and O(1) extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in 1..=3 {
+    ///     list.push(data);
+    /// }
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    ///
+    /// list.reverse();
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut rest = mem::take(&mut self.list);
+        while !rest.is_empty() {
+            // Move the front node of the rest before the nodes reversed so far.
+            let after = rest.split_off(1);
+            rest.append(&mut self.list);
+            self.list = mem::replace(&mut rest, after);
+        }
+    }
+
+    /// Returns the element `n` positions before the end of the linked list, the last element
+    /// being 0 positions from the end.
+    ///
+    /// A leading pointer walks `n` nodes ahead, then both pointers advance together until the
+    /// leading one reaches the last node: O(n) time and O(1) extra space, without using the size.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The distance from the last element.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the element, or None if the linked list has `n`
+    /// elements or fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in (1..=5).rev() {
+    ///     list.push(data);
+    /// }
+    ///
+    /// assert_eq!(list.nth_from_end(0), Some(&5));
+    /// assert_eq!(list.nth_from_end(1), Some(&4));
+    /// assert_eq!(list.nth_from_end(4), Some(&1));
+    /// assert_eq!(list.nth_from_end(5), None);
+    /// ```
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        let mut lead = self.list.iter();
+        lead.nth(n)?;
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+        let mut trail = self.list.iter();
+        for _ in lead {
+            trail.next();
+        }
+        trail.next()
+    }
+
+    /// Returns the middle element of the linked list, or the second of the two middle elements
+    /// if the size is even.
+    ///
+    /// A slow pointer advances one node for every two nodes of a fast pointer, so it is halfway
+    /// when the fast one reaches the end: O(n) time and O(1) extra space.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the middle element, or None if the linked list is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in (1..=5).rev() {
+    ///     list.push(data);
+    /// }
+    /// assert_eq!(list.middle(), Some(&3));
+    ///
+    /// list.push(0);
+    /// assert_eq!(list.middle(), Some(&3));
+    /// ```
+    pub fn middle(&self) -> Option<&T> {
+        let mut slow = self.list.iter();
+        let mut fast = self.list.iter();
+        while fast.next().is_some() && fast.next().is_some() {
+            slow.next();
+        }
+        slow.next()
+    }
+
+    /// Moves the elements satisfying a predicate before all the others, keeping the relative
+    /// order within both groups, in O(n) time and O(1) extra space.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - The predicate selecting the elements to move to the front.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements satisfying the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in [7, 2, 9, 4, 1, 8].into_iter().rev() {
+    ///     list.push(data);
+    /// }
+    ///
+    /// let small = list.partition(|&data| data < 5);
+    /// assert_eq!(small, 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2, &4, &1, &7, &9, &8]);
+    /// ```
+    pub fn partition<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // Keep the list empty while its nodes are being moved around.
+        let mut rest = mem::take(&mut self.list);
+        let mut matching = LinkedVec::new();
+        let mut others = LinkedVec::new();
+
+        while let Some(data) = rest.first() {
+            let target = if pred(data) {
+                &mut matching
+            } else {
+                &mut others
+            };
+            let after = rest.split_off(1);
+            target.append(&mut rest);
+            rest = after;
+        }
+
+        let count = matching.len();
+        matching.append(&mut others);
+        self.list = matching;
+        count
+    }
+
+    /// Removes every element equal to an earlier one, keeping the first occurrences in order.
+    ///
+    /// Each element is compared with all the elements kept before it, which takes O(n²) time
+    /// but only O(1) extra space and works on unsorted linked lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in [3, 1, 3, 2, 1, 3].into_iter().rev() {
+    ///     list.push(data);
+    /// }
+    ///
+    /// list.remove_duplicates();
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&3, &1, &2]);
+    /// assert_eq!(list.size(), 3);
+    /// ```
+    pub fn remove_duplicates(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut rest = mem::take(&mut self.list);
+        while let Some(data) = rest.first() {
+            let duplicate = self.list.iter().any(|kept| kept == data);
+            let after = rest.split_off(1);
+            if !duplicate {
+                self.list.append(&mut rest);
+            }
+            rest = after;
+        }
+    }
+
+    /// Merges another sorted linked list into this sorted one, keeping the result sorted, in
+    /// O(n + m) time and O(1) extra space.
+    ///
+    /// Nodes are relinked rather than copied. The merge is stable: of two equal elements, the
+    /// one from this linked list comes first. If a comparison panics, the linked list is left
+    /// empty and the elements of both linked lists are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The sorted linked list to merge in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut odds = LinkedList::new();
+    /// for data in [7, 5, 3, 1] {
+    ///     odds.push(data);
+    /// }
+    /// let mut evens = LinkedList::new();
+    /// for data in [8, 6, 4, 2] {
+    ///     evens.push(data);
+    /// }
+    ///
+    /// odds.merge_sorted(evens);
+    /// assert_eq!(odds.iter().copied().collect::<Vec<_>>(), (1..=8).collect::<Vec<_>>());
+    /// assert_eq!(odds.size(), 8);
+    /// ```
+    pub fn merge_sorted(&mut self, mut other: LinkedList<T>)
+    where
+        T: Ord,
+    {
+        // Keep the list empty while the nodes are being merged.
+        let mut first = mem::take(&mut self.list);
+        let mut second = mem::take(&mut other.list);
+        while let (Some(a), Some(b)) = (first.first(), second.first()) {
+            let source = if b.cmp(a) == Ordering::Less {
+                &mut second
+            } else {
+                &mut first
+            };
+            let after = source.split_off(1);
+            self.list.append(source);
+            *source = after;
+        }
+
+        self.list.append(&mut first);
+        self.list.append(&mut second);
+    }
+
+    /// Sorts the linked list in ascending order.
+    ///
+    /// Uses a stable, bottom-up merge sort that relinks the nodes: O(n log n) time and O(1)
+    /// extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in [3, 1, 4, 1, 5] {
+    ///     list.push(data);
+    /// }
+    ///
+    /// list.sort();
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 1, 3, 4, 5]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.map(|node| {
-            self.next = node.next.as_deref();
-            &node.data
-        })
+    /// Sorts the linked list with a comparator function.
+    ///
+    /// Uses a stable, bottom-up merge sort that relinks the nodes: O(n log n) time and O(1)
+    /// extra space. Runs of 1, 2, 4, ... nodes are merged pairwise until a single run is left.
+    ///
+    /// If `compare` panics, the linked list is left empty and its elements are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - The function returning the ordering of two elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for word in ["pear", "fig", "apple", "kiwi"] {
+    ///     list.push(word);
+    /// }
+    ///
+    /// // Stable: words of the same length keep their order.
+    /// list.sort_by(|a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&"fig", &"kiwi", &"pear", &"apple"]);
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.list.sort_by(compare);
     }
 }
 
-/// Mutable iterator over the elements of a linked list.
-pub struct IterMut<'a, T: 'a> {
-    next: Option<&'a mut Node<T>>,
+/// A cursor over a linked list with editing operations, created by
+/// [`LinkedList::cursor_front_mut`].
+///
+/// The cursor points at an element or at the end of the linked list, where `current` returns
+/// None. As the linked list is singly linked, the cursor can only move forward, and it stays at
+/// the end once there rather than wrapping around to the first element.
+///
+/// Every operation runs in O(1) time.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the linked list.
+///
+/// # Fields
+///
+/// * `cursor` - The cursor of the underlying linked vector, which does the editing.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::linked_list::LinkedList;
+///
+/// let mut list = LinkedList::new();
+/// for data in (1..=5).rev() {
+///     list.push(data);
+/// }
+///
+/// let mut cursor = list.cursor_front_mut();
+/// while let Some(&mut data) = cursor.current() {
+///     if data % 2 == 0 {
+///         cursor.remove_current();
+///     } else {
+///         cursor.insert_after(data * 10);
+///         cursor.move_next();
+///         cursor.move_next();
+///     }
+/// }
+/// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 10, 3, 30, 5, 50]);
+/// assert_eq!(list.size(), 6);
+/// ```
+pub struct CursorMut<'a, T: 'a> {
+    /// The cursor of the underlying linked vector, which does the editing.
+    cursor: vec::vec::CursorMut<'a, T>,
 }
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+impl<T> CursorMut<'_, T> {
+    /// Returns the index of the element the cursor points at, or None at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.index(), Some(0));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), None);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), None);
+    /// ```
+    pub fn index(&self) -> Option<usize> {
+        self.cursor.index()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.take().map(|node| {
-            self.next = node.next.as_deref_mut();
-            &mut node.data
-        })
+    /// Moves the cursor to the next element, or to the end after the last element.
+    ///
+    /// Does nothing if the cursor is already at the end.
+    pub fn move_next(&mut self) {
+        self.cursor.move_next();
     }
-}
 
-/// Consuming iterator over the elements of a linked list.
-pub struct IntoIter<T>(LinkedList<T>);
+    /// Returns a mutable reference to the element the cursor points at, or None at the end.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.cursor.current()
+    }
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+    /// Returns a mutable reference to the element after the cursor, or None if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(2);
+    /// list.push(1);
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.peek_next(), Some(&mut 2));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_next(), None);
+    /// ```
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.cursor.peek_next()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop()
+    /// Inserts an element after the cursor; the cursor keeps pointing at the same element.
+    ///
+    /// At the end of the linked list, the element is appended and the cursor points at it.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The element to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(2);
+    /// list.push(1);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_after(10);
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 10, 2]);
+    /// ```
+    pub fn insert_after(&mut self, data: T) {
+        self.cursor.insert_after(data);
+    }
+
+    /// Inserts an element before the cursor, or appends it at the end of the linked list.
+    ///
+    /// The cursor keeps pointing at the same element, whose index grows by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The element to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_before(0);
+    /// assert_eq!(cursor.index(), Some(1));
+    /// cursor.move_next();
+    /// cursor.insert_before(2);
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn insert_before(&mut self, data: T) {
+        self.cursor.insert_before(data);
     }
-}
 
-/// Custom drop implementation for releasing linked list memory.
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        let mut link = self.head.take();
-        while let Some(mut node) = link {
-            link = node.next.take();
+    /// Removes the element the cursor points at and moves the cursor to the next element.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or None at the end of the linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(2);
+    /// list.push(1);
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.remove_current(), Some(1));
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// assert_eq!(list.size(), 1);
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.cursor.remove_current()
+    }
+
+    /// Moves all elements of `other` after the cursor; the cursor keeps pointing at the same
+    /// element.
+    ///
+    /// At the end of the linked list, the elements are appended and the cursor points at the
+    /// first of them. The nodes are relinked, not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The linked list whose elements are inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push(4);
+    /// list.push(1);
+    /// let mut other = LinkedList::new();
+    /// other.push(3);
+    /// other.push(2);
+    ///
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.splice_after(other);
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn splice_after(&mut self, other: LinkedList<T>) {
+        self.cursor.splice_after(other.list);
+    }
+
+    /// Splits the linked list after the cursor.
+    ///
+    /// # Returns
+    ///
+    /// A linked list holding the elements after the cursor, which is empty at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// for data in (1..=4).rev() {
+    ///     list.push(data);
+    /// }
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// let rest = cursor.split_after();
+    /// assert_eq!(rest.iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+    /// assert_eq!(list.size(), 2);
+    /// ```
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        LinkedList {
+            list: self.cursor.split_after(),
         }
     }
 }
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,14 +2,16 @@
 
 // --- endregion: imports
 
+use ::linked_list::cycle_detection::{brent, floyd};
+use ::linked_list::doubly_linked_list::DoublyLinkedList;
+use ::linked_list::linked_list::LinkedList;
+use ::linked_list::linked_stack::LinkedStack;
 use ::linked_list::parenthese_checker::parenthese_checker;
+use ::linked_list::persistent_list::PersistentList;
+use ::linked_list::skip_list::SkipList;
 use vec::linked_vec;
 
-use crate::{linked_list::LinkedList, linked_stack::LinkedStack};
-
 // --- region: modules
-mod linked_list;
-mod linked_stack;
 // --- endregion: modules
 
 fn main() {
@@ -34,6 +36,123 @@ fn main() {
 
     println!("Is linked list empty? {}", linked_list.is_empty());
 
+    for data in (1..=5).rev() {
+        linked_list.push(data);
+    }
+    let mut cursor = linked_list.cursor_front_mut();
+    cursor.move_next();
+    cursor.insert_before(15);
+    cursor.remove_current();
+    let rest = cursor.split_after();
+    println!(
+        "Edited with a cursor: {:?} - split off: {:?}",
+        linked_list.iter().collect::<Vec<_>>(),
+        rest.iter().collect::<Vec<_>>()
+    );
+
+    for data in [4, 1, 3, 1, 5, 9, 2, 6, 5, 3] {
+        linked_list.push(data);
+    }
+    println!(
+        "Middle: {:?}, second from end: {:?}",
+        linked_list.middle(),
+        linked_list.nth_from_end(1)
+    );
+    linked_list.reverse();
+    println!("Reversed: {:?}", linked_list.iter().collect::<Vec<_>>());
+    linked_list.remove_duplicates();
+    println!(
+        "Without duplicates: {:?}",
+        linked_list.iter().collect::<Vec<_>>()
+    );
+    let odd = linked_list.partition(|data| data % 2 == 1);
+    println!(
+        "Odd numbers first ({} of them): {:?}",
+        odd,
+        linked_list.iter().collect::<Vec<_>>()
+    );
+    linked_list.sort();
+    let mut sorted = LinkedList::new();
+    for data in [8, 7, 0] {
+        sorted.push(data);
+    }
+    linked_list.merge_sorted(sorted);
+    println!(
+        "Sorted and merged: {:?}",
+        linked_list.iter().collect::<Vec<_>>()
+    );
+
+    // CYCLE DETECTION
+    println!("\n\n***CYCLE DETECTION***");
+    let links = [Some(1), Some(2), Some(3), Some(4), Some(5), Some(2)];
+    println!("Links: {:?}", links);
+    println!("Floyd: {:?}", floyd(0, |&node| links[node]));
+    println!("Brent: {:?}", brent(0, |&node| links[node]));
+
+    // DOUBLY LINKED LIST
+    println!("\n\n***DOUBLY LINKED LIST***");
+    let mut doubly_linked_list = DoublyLinkedList::new();
+    let handles: Vec<_> = (1..=5)
+        .map(|data| doubly_linked_list.push_back(data))
+        .collect();
+    doubly_linked_list.push_front(0);
+    println!("Doubly linked list: {:?}", doubly_linked_list);
+
+    doubly_linked_list.remove(&handles[2]);
+    doubly_linked_list.move_to_front(&handles[4]);
+    doubly_linked_list.insert_after(&handles[0], 15);
+    println!("Edited through handles: {:?}", doubly_linked_list);
+    println!(
+        "Backwards: {:?}",
+        doubly_linked_list.iter().rev().collect::<Vec<_>>()
+    );
+
+    let mut back_half = doubly_linked_list.split_off(3);
+    println!("Split: {:?} - {:?}", doubly_linked_list, back_half);
+    back_half.append(&mut doubly_linked_list);
+    println!("Appended: {:?}", back_half);
+    println!(
+        "Popped front: {:?}, popped back: {:?}",
+        back_half.pop_front(),
+        back_half.pop_back()
+    );
+
+    // PERSISTENT LIST
+    println!("\n\n***PERSISTENT LIST***");
+    let history = PersistentList::new().prepend("open").prepend("edit");
+    let saved = history.prepend("save");
+    let undone = saved.tail();
+    let branched = undone.prepend("rename");
+    println!("Saved version: {:?}", saved);
+    println!("After undo: {:?}", undone);
+    println!("Branched version: {:?}", branched);
+    println!("Undo shares the saved history: {}", undone.ptr_eq(&history));
+
+    // SKIP LIST
+    println!("\n\n***SKIP LIST***");
+    let mut skip_list = SkipList::with_seed(2024);
+    for (key, value) in [(30, "thirty"), (10, "ten"), (50, "fifty"), (20, "twenty")] {
+        skip_list.insert(key, value);
+    }
+    println!("Skip list: {:?}", skip_list);
+    println!("Levels in use: {}", skip_list.levels());
+    println!(
+        "Keys in 15..40: {:?}",
+        skip_list.range(15..40).collect::<Vec<_>>()
+    );
+    println!(
+        "First: {:?}, last: {:?}",
+        skip_list.first(),
+        skip_list.last()
+    );
+    println!(
+        "Floor of 25: {:?}, ceiling of 25: {:?}",
+        skip_list.floor(&25),
+        skip_list.ceiling(&25)
+    );
+    skip_list.remove(&10);
+    println!("After removing 10: {:?}", skip_list);
+
     // LINKED STACK
     println!("\n\n***LINKED STACK***");
 
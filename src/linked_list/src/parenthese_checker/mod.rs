@@ -2,7 +2,7 @@ use vec::vec::LinkedVec;
 
 use crate::linked_stack::LinkedStack;
 
-/// Checks if the parentheses in the given string are balanced.
+/// Checks if the parentheses in the given string are balanced. Other characters are ignored.
 ///
 /// # Arguments
 ///
@@ -22,6 +22,7 @@ use crate::linked_stack::LinkedStack;
 /// assert_eq!(parenthese_checker("()()"), true);
 /// assert_eq!(parenthese_checker("(()"), false);
 /// assert_eq!(parenthese_checker("())"), false);
+/// assert_eq!(parenthese_checker("(a)"), true);
 /// ```
 pub fn parenthese_checker(string: &str) -> bool {
     let mut char_list = LinkedVec::new();
@@ -39,8 +40,7 @@ pub fn parenthese_checker(string: &str) -> bool {
     while is_balanced && index < char_list.len() {
         if char_list.find(index).unwrap() == &'(' {
             stack.push(char_list.find(index).unwrap());
-        } else {
-            // if the character is ')'
+        } else if char_list.find(index).unwrap() == &')' {
             if stack.is_empty() {
                 // If the stack is empty and encountering a closing parenthesis, the string is unbalanced
                 is_balanced = false;
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use super::{PersistentList, PointerKind};
+
+/// Shares the nodes through [`Arc`]: versions of the list can be sent to and shared between
+/// threads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ArcKind;
+
+impl PointerKind for ArcKind {
+    type Pointer<T> = Arc<T>;
+
+    fn new<T>(data: T) -> Arc<T> {
+        Arc::new(data)
+    }
+
+    fn ptr_eq<T>(first: &Arc<T>, second: &Arc<T>) -> bool {
+        Arc::ptr_eq(first, second)
+    }
+
+    fn into_inner<T>(pointer: Arc<T>) -> Option<T> {
+        Arc::into_inner(pointer)
+    }
+}
+
+/// Represents a persistent (immutable) singly linked list whose nodes can be shared between
+/// threads.
+///
+/// This is the thread safe variant of [`PersistentList`]: nodes are shared through `Arc`, so
+/// versions of the list can be sent to and shared between threads. Create one with
+/// [`with_pointer_kind`](PersistentList::with_pointer_kind), `default` or `collect`.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the list.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::persistent_list::arc_persistent_list::ArcPersistentList;
+///
+/// // Each thread extends the same shared list without copying it.
+/// let base: ArcPersistentList<_> = (1..=3).collect();
+/// let handles: Vec<_> = (10..13)
+///     .map(|data| {
+///         let base = base.clone();
+///         std::thread::spawn(move || base.prepend(data))
+///     })
+///     .collect();
+/// for handle in handles {
+///     let list = handle.join().unwrap();
+///     assert!(list.tail().ptr_eq(&base));
+/// }
+///
+/// let empty = ArcPersistentList::with_pointer_kind();
+/// assert_eq!(empty.prepend("draw circle").head(), Some(&"draw circle"));
+/// ```
+pub type ArcPersistentList<T> = PersistentList<T, ArcKind>;
@@ -0,0 +1,455 @@
+pub mod arc_persistent_list;
+
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// Selects which shared pointer a [`PersistentList`] links its nodes with.
+///
+/// Lists take a `PointerKind` type parameter (defaulting to [`RcKind`]) and share their nodes
+/// through `P::Pointer`, so the same code backs both the single threaded and the thread safe
+/// list.
+pub trait PointerKind {
+    /// The shared pointer type owning a value of type `T`.
+    type Pointer<T>: Deref<Target = T> + Clone;
+
+    /// Moves a value into a new shared pointer.
+    fn new<T>(data: T) -> Self::Pointer<T>;
+
+    /// Checks if two pointers point to the same allocation.
+    fn ptr_eq<T>(first: &Self::Pointer<T>, second: &Self::Pointer<T>) -> bool;
+
+    /// Returns the inner value if this is the last pointer to it, or None otherwise.
+    fn into_inner<T>(pointer: Self::Pointer<T>) -> Option<T>;
+}
+
+/// Shares the nodes through [`Rc`]: cheap to clone, but the list cannot leave its thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RcKind;
+
+impl PointerKind for RcKind {
+    type Pointer<T> = Rc<T>;
+
+    fn new<T>(data: T) -> Rc<T> {
+        Rc::new(data)
+    }
+
+    fn ptr_eq<T>(first: &Rc<T>, second: &Rc<T>) -> bool {
+        Rc::ptr_eq(first, second)
+    }
+
+    fn into_inner<T>(pointer: Rc<T>) -> Option<T> {
+        Rc::into_inner(pointer)
+    }
+}
+
+/// Type alias for a shared pointer to the next node of a persistent list.
+type Link<T, P> = Option<<P as PointerKind>::Pointer<Node<T, P>>>;
+
+/// Represents a node in a persistent list.
+///
+/// A node is never modified once created, so it can be shared by any number of lists.
+struct Node<T, P: PointerKind> {
+    /// The data stored in the node.
+    data: T,
+    /// Pointer to the next node in the list.
+    next: Link<T, P>,
+}
+
+/// Represents a persistent (immutable) singly linked list.
+///
+/// Operations never modify a list: `prepend` and `tail` return new lists that share their
+/// nodes with the original one, in O(1) time and without copying any element. This makes it
+/// cheap to keep every past version of a list around, for example as an undo history.
+///
+/// By default nodes are shared through `Rc`, so the list cannot be sent to another thread; see
+/// [`ArcPersistentList`](arc_persistent_list::ArcPersistentList) for the thread safe variant.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the list.
+/// * `P` - The [`PointerKind`] sharing the nodes, [`RcKind`] by default.
+///
+/// # Fields
+///
+/// * `size` - The size of the list.
+/// * `head` - Pointer to the first node of the list.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::persistent_list::PersistentList;
+///
+/// // Every edit produces a new version; the old ones stay untouched.
+/// let empty = PersistentList::new();
+/// let first = empty.prepend("draw circle");
+/// let second = first.prepend("fill red");
+/// let third = second.prepend("add shadow");
+///
+/// let undone = third.tail();
+/// assert_eq!(undone.iter().collect::<Vec<_>>(), vec![&"fill red", &"draw circle"]);
+/// assert_eq!(third.len(), 3);
+/// assert!(undone.ptr_eq(&second));
+///
+/// let redone = undone.prepend("add border");
+/// assert_eq!(redone.head(), Some(&"add border"));
+/// assert_eq!(third.head(), Some(&"add shadow"));
+/// ```
+pub struct PersistentList<T, P: PointerKind = RcKind> {
+    /// The size of the list.
+    size: usize,
+    /// Pointer to the first node of the list.
+    head: Link<T, P>,
+}
+
+impl<T> PersistentList<T> {
+    /// Creates a new empty persistent list sharing its nodes through `Rc`.
+    ///
+    /// # Returns
+    ///
+    /// A new empty persistent list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<i32> = PersistentList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_pointer_kind()
+    }
+}
+
+impl<T, P: PointerKind> PersistentList<T, P> {
+    /// Creates a new empty persistent list sharing its nodes through the pointer kind `P`.
+    ///
+    /// # Returns
+    ///
+    /// A new empty persistent list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::arc_persistent_list::ArcKind;
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<i32, ArcKind> = PersistentList::with_pointer_kind();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn with_pointer_kind() -> Self {
+        PersistentList {
+            size: 0,
+            head: None,
+        }
+    }
+
+    /// Returns a new list with an element added to the front of this one, in O(1) time.
+    ///
+    /// The new list shares all the nodes of this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to be added.
+    ///
+    /// # Returns
+    ///
+    /// A new list starting with `data` and followed by the elements of this list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list = PersistentList::new().prepend(2);
+    /// let longer = list.prepend(1);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2]);
+    /// assert_eq!(longer.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    pub fn prepend(&self, data: T) -> Self {
+        PersistentList {
+            size: self.size + 1,
+            head: Some(P::new(Node {
+                data,
+                next: self.head.clone(),
+            })),
+        }
+    }
+
+    /// Returns the list without its first element, in O(1) time.
+    ///
+    /// The new list shares all its nodes with this one.
+    ///
+    /// # Returns
+    ///
+    /// The list of every element but the first, which is empty if this list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<_> = (1..=3).collect();
+    /// let tail = list.tail();
+    ///
+    /// assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// assert_eq!(list.len(), 3);
+    /// assert!(PersistentList::<i32>::new().tail().is_empty());
+    /// ```
+    pub fn tail(&self) -> Self {
+        match &self.head {
+            Some(node) => PersistentList {
+                size: self.size - 1,
+                head: node.next.clone(),
+            },
+            None => PersistentList::with_pointer_kind(),
+        }
+    }
+
+    /// Returns a reference to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the first element, or None if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list = PersistentList::new().prepend(1);
+    /// assert_eq!(list.head(), Some(&1));
+    /// assert_eq!(list.tail().head(), None);
+    /// ```
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    /// Returns the size of the list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<_> = (1..=3).collect();
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list = PersistentList::new();
+    /// assert!(list.is_empty());
+    /// assert!(!list.prepend(1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Checks if two lists start with the very same node, and therefore hold the same elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare with.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether both lists share their first node (or are both empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<_> = (1..=3).collect();
+    /// let same: PersistentList<_> = (1..=3).collect();
+    ///
+    /// assert!(list.prepend(0).tail().ptr_eq(&list));
+    /// assert!(!list.ptr_eq(&same));
+    /// ```
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(first), Some(second)) => P::ptr_eq(first, second),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns a new list holding the elements of this one in reverse order.
+    ///
+    /// Takes O(n) time, as none of the nodes can be shared.
+    ///
+    /// # Returns
+    ///
+    /// A new list with the elements in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<_> = (1..=3).collect();
+    /// assert_eq!(list.reverse().iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn reverse(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .fold(PersistentList::with_pointer_kind(), |list, data| {
+                list.prepend(data.clone())
+            })
+    }
+
+    /// Returns an iterator over the elements of the list, front to back.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::persistent_list::PersistentList;
+    ///
+    /// let list: PersistentList<_> = (1..=3).collect();
+    /// assert_eq!(list.iter().sum::<i32>(), 6);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter {
+            next: self.head.as_deref(),
+            len: self.size,
+        }
+    }
+}
+
+impl<T, P: PointerKind> Default for PersistentList<T, P> {
+    fn default() -> Self {
+        Self::with_pointer_kind()
+    }
+}
+
+/// Clones the list in O(1) time by sharing its nodes.
+impl<T, P: PointerKind> Clone for PersistentList<T, P> {
+    fn clone(&self) -> Self {
+        PersistentList {
+            size: self.size,
+            head: self.head.clone(),
+        }
+    }
+}
+
+/// Custom drop implementation for releasing the nodes one by one.
+///
+/// Dropping the nodes recursively would overflow the stack on long lists. Nodes still shared
+/// with another list stop the walk, as that list keeps them alive. `into_inner` only fails for
+/// nodes some other pointer still owns, even when several threads drop lists sharing the same
+/// nodes at once, so no node is ever left to a recursive drop.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::persistent_list::PersistentList;
+///
+/// let list: PersistentList<_> = (0..1_000_000).collect();
+/// let shorter = list.tail();
+/// drop(list);
+/// assert_eq!(shorter.len(), 999_999);
+/// ```
+impl<T, P: PointerKind> Drop for PersistentList<T, P> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = P::into_inner(node).and_then(|mut node| node.next.take());
+        }
+    }
+}
+
+impl<T: fmt::Debug, P: PointerKind> fmt::Debug for PersistentList<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, P: PointerKind> PartialEq for PersistentList<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq, P: PointerKind> Eq for PersistentList<T, P> {}
+
+/// Builds a list holding the elements in iteration order.
+impl<T, P: PointerKind> FromIterator<T> for PersistentList<T, P> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        items
+            .into_iter()
+            .rev()
+            .fold(PersistentList::with_pointer_kind(), |list, data| {
+                list.prepend(data)
+            })
+    }
+}
+
+impl<'a, T, P: PointerKind> IntoIterator for &'a PersistentList<T, P> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over references to the elements of a persistent list.
+///
+/// # Fields
+///
+/// * `next` - The next node to yield.
+/// * `len` - The number of elements left to yield.
+pub struct Iter<'a, T, P: PointerKind = RcKind> {
+    next: Option<&'a Node<T, P>>,
+    len: usize,
+}
+
+impl<'a, T, P: PointerKind> Iterator for Iter<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, P: PointerKind> ExactSizeIterator for Iter<'_, T, P> {}
+
+impl<T, P: PointerKind> Clone for Iter<'_, T, P> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
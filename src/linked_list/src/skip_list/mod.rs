@@ -0,0 +1,799 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The maximum number of levels used by [`SkipList::new`] and [`SkipList::with_seed`].
+pub const DEFAULT_MAX_LEVEL: usize = 16;
+
+/// The promotion probability used by [`SkipList::new`] and [`SkipList::with_seed`].
+pub const DEFAULT_PROBABILITY: f64 = 0.5;
+
+/// Represents a node in a skip list.
+///
+/// # Fields
+///
+/// * `key` - The key stored in the node.
+/// * `value` - The value stored in the node.
+/// * `forward` - The index of the next node on each level the node takes part in.
+#[derive(Clone)]
+struct Node<K, V> {
+    /// The key stored in the node.
+    key: K,
+    /// The value stored in the node.
+    value: V,
+    /// The index of the next node on each level the node takes part in.
+    forward: Vec<Option<usize>>,
+}
+
+/// Represents a skip list: an ordered map built on linked nodes.
+///
+/// Every entry sits on the bottom level, a sorted linked list of all the entries. Each entry is
+/// also promoted to the level above with a fixed probability, then to the next one with the same
+/// probability, and so on up to a maximum level. The upper levels are sparser and sparser linked
+/// lists acting as express lanes: searches start on the top level and drop a level whenever the
+/// next key is too large, which gives O(log n) expected time for insertion, removal, lookup and
+/// for finding the start of a range scan.
+///
+/// Nodes are stored in a vector and linked by index; slots of removed nodes are reused.
+///
+/// # Generic Parameters
+///
+/// * `K` - Type of the keys, kept in ascending order.
+/// * `V` - Type of the values.
+///
+/// # Fields
+///
+/// * `nodes` - The nodes of the skip list, None for a free slot.
+/// * `free` - The indices of the free slots in `nodes`.
+/// * `head` - The index of the first node on each level.
+/// * `level` - The number of levels currently in use.
+/// * `size` - The number of entries in the skip list.
+/// * `probability` - The probability of promoting a node to the next level.
+/// * `rng` - The random number generator deciding the promotions.
+///
+/// # Examples
+///
+/// ```
+/// use crate::linked_list::skip_list::SkipList;
+///
+/// let mut scores = SkipList::with_seed(42);
+/// scores.insert("carol", 72);
+/// scores.insert("alice", 90);
+/// scores.insert("bob", 85);
+///
+/// assert_eq!(scores.get("bob"), Some(&85));
+/// assert_eq!(scores.first(), Some((&"alice", &90)));
+/// assert_eq!(
+///     scores.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+///     vec!["alice", "bob", "carol"]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct SkipList<K, V> {
+    /// The nodes of the skip list, None for a free slot.
+    nodes: Vec<Option<Node<K, V>>>,
+    /// The indices of the free slots in `nodes`.
+    free: Vec<usize>,
+    /// The index of the first node on each level.
+    head: Vec<Option<usize>>,
+    /// The number of levels currently in use.
+    level: usize,
+    /// The number of entries in the skip list.
+    size: usize,
+    /// The probability of promoting a node to the next level.
+    probability: f64,
+    /// The random number generator deciding the promotions.
+    rng: StdRng,
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    /// Creates a new empty skip list with the default parameters and a randomly seeded
+    /// random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A new empty skip list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<i32, i32> = SkipList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_rng(
+            DEFAULT_MAX_LEVEL,
+            DEFAULT_PROBABILITY,
+            StdRng::from_entropy(),
+        )
+    }
+
+    /// Creates a new empty skip list with the default parameters and a seeded random number
+    /// generator.
+    ///
+    /// Skip lists built with the same seed and the same operations have the same shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A new empty skip list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut first = SkipList::with_seed(7);
+    /// let mut second = SkipList::with_seed(7);
+    /// for key in 0..1000 {
+    ///     first.insert(key, ());
+    ///     second.insert(key, ());
+    /// }
+    /// assert_eq!(first.levels(), second.levels());
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_config(DEFAULT_MAX_LEVEL, DEFAULT_PROBABILITY, seed)
+    }
+
+    /// Creates a new empty skip list with the given parameters.
+    ///
+    /// With a promotion probability `p`, each level holds about `p` times as many nodes as the
+    /// one below. A maximum level around `log(1 / p, n)` suits skip lists of `n` entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_level` - The maximum number of levels.
+    /// * `probability` - The probability of promoting a node to the next level.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A new empty skip list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_level` is zero or if `probability` is not strictly between 0 and 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list = SkipList::with_config(4, 0.25, 1);
+    /// for key in 0..1000 {
+    ///     list.insert(key, key * 2);
+    /// }
+    /// assert!(list.levels() <= 4);
+    /// assert_eq!(list.get(&500), Some(&1000));
+    /// ```
+    pub fn with_config(max_level: usize, probability: f64, seed: u64) -> Self {
+        Self::with_rng(max_level, probability, StdRng::seed_from_u64(seed))
+    }
+
+    /// Creates a new empty skip list drawing its promotions from `rng`.
+    fn with_rng(max_level: usize, probability: f64, rng: StdRng) -> Self {
+        assert!(max_level > 0, "Max level must be positive");
+        assert!(
+            probability > 0.0 && probability < 1.0,
+            "Probability must be between 0 and 1"
+        );
+
+        SkipList {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: vec![None; max_level],
+            level: 0,
+            size: 0,
+            probability,
+            rng,
+        }
+    }
+
+    /// Returns the number of entries in the skip list.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries in the skip list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = (0..5).map(|key| (key, ())).collect();
+    /// assert_eq!(list.len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks if the skip list is empty.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the skip list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list = SkipList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.insert(1, "one");
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the number of levels currently in use.
+    ///
+    /// # Returns
+    ///
+    /// The number of levels of the tallest node, or 0 if the skip list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list = SkipList::with_config(8, 0.5, 3);
+    /// assert_eq!(list.levels(), 0);
+    ///
+    /// list.insert(1, ());
+    /// assert!((1..=8).contains(&list.levels()));
+    /// ```
+    pub fn levels(&self) -> usize {
+        self.level
+    }
+
+    /// Inserts an entry into the skip list in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry.
+    /// * `value` - The value of the entry.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the previous value of the key, or None if the key was not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list = SkipList::new();
+    /// assert_eq!(list.insert(1, "one"), None);
+    /// assert_eq!(list.insert(1, "uno"), Some("one"));
+    /// assert_eq!(list.get(&1), Some(&"uno"));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let update = self.predecessors(|other| other < &key);
+        if let Some(next) = self.next_at(update[0], 0) {
+            let node = self.node_mut(next);
+            if node.key == key {
+                return Some(std::mem::replace(&mut node.value, value));
+            }
+        }
+
+        let height = self.random_height();
+        let forward = (0..height)
+            .map(|level| self.next_at(update[level], level))
+            .collect();
+        let index = self.allocate(Node {
+            key,
+            value,
+            forward,
+        });
+        for (level, &before) in update.iter().enumerate().take(height) {
+            self.set_next(before, level, Some(index));
+        }
+
+        self.level = self.level.max(height);
+        self.size += 1;
+        None
+    }
+
+    /// Removes an entry from the skip list in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry to remove.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the value of the removed entry, or None if the key was not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list: SkipList<_, _> = (1..=3).map(|key| (key, key * 10)).collect();
+    /// assert_eq!(list.remove(&2), Some(20));
+    /// assert_eq!(list.remove(&2), None);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let update = self.predecessors(|other| other.borrow() < key);
+        let index = self.next_at(update[0], 0)?;
+        if self.node(index).key.borrow() != key {
+            return None;
+        }
+
+        let node = self.nodes[index].take().expect("Linked slots hold a node");
+        self.free.push(index);
+        for (level, &next) in node.forward.iter().enumerate() {
+            self.set_next(update[level], level, next);
+        }
+        while self.level > 0 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+
+        self.size -= 1;
+        Some(node.value)
+    }
+
+    /// Returns a reference to the value of a key, in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a reference to the value, or None if the key is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list = SkipList::new();
+    /// list.insert(String::from("one"), 1);
+    /// assert_eq!(list.get("one"), Some(&1));
+    /// assert_eq!(list.get("two"), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(&self.node(index).value)
+    }
+
+    /// Returns a mutable reference to the value of a key, in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for.
+    ///
+    /// # Returns
+    ///
+    /// An option containing a mutable reference to the value, or None if the key is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list = SkipList::new();
+    /// list.insert(1, 10);
+    /// *list.get_mut(&1).unwrap() += 5;
+    /// assert_eq!(list.get(&1), Some(&15));
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(&mut self.node_mut(index).value)
+    }
+
+    /// Checks if the skip list contains a key, in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the key is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = [(1, 'a'), (3, 'c')].into_iter().collect();
+    /// assert!(list.contains_key(&3));
+    /// assert!(!list.contains_key(&2));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Returns the entry with the smallest key, in O(1) time.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the first entry, or None if the skip list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = [(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    /// assert_eq!(list.first(), Some((&1, &'a')));
+    /// ```
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.head[0].map(|index| self.entry(index))
+    }
+
+    /// Returns the entry with the largest key, in O(log n) expected time.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the last entry, or None if the skip list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = [(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    /// assert_eq!(list.last(), Some((&3, &'c')));
+    /// ```
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.last_where(|_| true).map(|index| self.entry(index))
+    }
+
+    /// Returns the entry with the largest key less than or equal to the given key, in
+    /// O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to compare with.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the entry, or None if every key is greater than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = [(10, 'a'), (20, 'b'), (30, 'c')].into_iter().collect();
+    /// assert_eq!(list.floor(&25), Some((&20, &'b')));
+    /// assert_eq!(list.floor(&20), Some((&20, &'b')));
+    /// assert_eq!(list.floor(&5), None);
+    /// ```
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.last_where(|other| other.borrow() <= key)
+            .map(|index| self.entry(index))
+    }
+
+    /// Returns the entry with the smallest key greater than or equal to the given key, in
+    /// O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to compare with.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the entry, or None if every key is less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = [(10, 'a'), (20, 'b'), (30, 'c')].into_iter().collect();
+    /// assert_eq!(list.ceiling(&15), Some((&20, &'b')));
+    /// assert_eq!(list.ceiling(&20), Some((&20, &'b')));
+    /// assert_eq!(list.ceiling(&35), None);
+    /// ```
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.first_where(|other| other.borrow() >= key)
+            .map(|index| self.entry(index))
+    }
+
+    /// Returns an iterator over the entries whose keys fall within a range, in ascending key
+    /// order.
+    ///
+    /// Finding the first entry takes O(log n) expected time, then each entry takes O(1) time.
+    /// A range whose start is greater than its end yields nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of keys, such as `a..b`, `a..=b`, `a..` or `..b`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the keys and values in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = (0..10).map(|key| (key, key * key)).collect();
+    ///
+    /// let squares: Vec<_> = list.range(3..6).map(|(_, square)| *square).collect();
+    /// assert_eq!(squares, vec![9, 16, 25]);
+    /// assert_eq!(list.range(8..).count(), 2);
+    /// assert_eq!(list.range(..=1).count(), 2);
+    /// assert_eq!(list.range(20..30).count(), 0);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.first_where(|key| key.borrow() >= start),
+            Bound::Excluded(start) => self.first_where(|key| key.borrow() > start),
+            Bound::Unbounded => self.head[0],
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.first_where(|key| key.borrow() > end),
+            Bound::Excluded(end) => self.first_where(|key| key.borrow() >= end),
+            Bound::Unbounded => None,
+        };
+
+        let next = match (start, end) {
+            (Some(start), Some(end)) if self.node(start).key > self.node(end).key => None,
+            _ => start,
+        };
+        Range {
+            list: self,
+            next,
+            end,
+        }
+    }
+
+    /// Returns an iterator over the entries of the skip list, in ascending key order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the keys and values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let list: SkipList<_, _> = [(2, 'b'), (1, 'a')].into_iter().collect();
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![(&1, &'a'), (&2, &'b')]);
+    /// ```
+    pub fn iter(&self) -> Range<'_, K, V> {
+        Range {
+            list: self,
+            next: self.head[0],
+            end: None,
+        }
+    }
+
+    /// Removes all entries from the skip list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::linked_list::skip_list::SkipList;
+    ///
+    /// let mut list: SkipList<_, _> = (0..5).map(|key| (key, ())).collect();
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.first(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.head.fill(None);
+        self.level = 0;
+        self.size = 0;
+    }
+
+    /// Returns the index of the node holding a key.
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.first_where(|other| other.borrow() >= key)
+            .filter(|&index| self.node(index).key.borrow() == key)
+    }
+
+    /// Returns, for every level, the index of the last node whose key satisfies `before`
+    /// (None standing for the head).
+    ///
+    /// `before` must hold for a prefix of the keys in ascending order.
+    fn predecessors(&self, before: impl Fn(&K) -> bool) -> Vec<Option<usize>> {
+        let mut update = vec![None; self.head.len()];
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next_at(current, level) {
+                if !before(&self.node(next).key) {
+                    break;
+                }
+                current = Some(next);
+            }
+            update[level] = current;
+        }
+        update
+    }
+
+    /// Returns the index of the last node whose key satisfies `before`, which must hold for a
+    /// prefix of the keys in ascending order.
+    fn last_where(&self, before: impl Fn(&K) -> bool) -> Option<usize> {
+        self.predecessors(before)[0]
+    }
+
+    /// Returns the index of the first node whose key satisfies `after`, which must hold for a
+    /// suffix of the keys in ascending order.
+    fn first_where(&self, after: impl Fn(&K) -> bool) -> Option<usize> {
+        let before = self.last_where(|key| !after(key));
+        self.next_at(before, 0)
+    }
+
+    /// Returns the index of the node following `node` (None standing for the head) on a level.
+    fn next_at(&self, node: Option<usize>, level: usize) -> Option<usize> {
+        match node {
+            Some(index) => self.node(index).forward[level],
+            None => self.head[level],
+        }
+    }
+
+    /// Links `next` after `node` (None standing for the head) on a level.
+    fn set_next(&mut self, node: Option<usize>, level: usize, next: Option<usize>) {
+        match node {
+            Some(index) => self.node_mut(index).forward[level] = next,
+            None => self.head[level] = next,
+        }
+    }
+
+    /// Draws the number of levels of a new node.
+    fn random_height(&mut self) -> usize {
+        let mut height = 1;
+        while height < self.head.len() && self.rng.gen_bool(self.probability) {
+            height += 1;
+        }
+        height
+    }
+
+    /// Stores a node, reusing a free slot if there is one.
+    fn allocate(&mut self, node: Node<K, V>) -> usize {
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Returns the node stored at an index.
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.nodes[index]
+            .as_ref()
+            .expect("Linked slots hold a node")
+    }
+
+    /// Returns the node stored at an index, mutably.
+    fn node_mut(&mut self, index: usize) -> &mut Node<K, V> {
+        self.nodes[index]
+            .as_mut()
+            .expect("Linked slots hold a node")
+    }
+
+    /// Returns the key and value of the node stored at an index.
+    fn entry(&self, index: usize) -> (&K, &V) {
+        let node = self.node(index);
+        (&node.key, &node.value)
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for SkipList<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SkipList<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SkipList<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut list = SkipList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a SkipList<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Range<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a skip list within a range of keys, in ascending key order.
+///
+/// # Fields
+///
+/// * `list` - The skip list being iterated.
+/// * `next` - The index of the next node to yield.
+/// * `end` - The index of the first node past the range, or None to run to the end.
+pub struct Range<'a, K, V> {
+    /// The skip list being iterated.
+    list: &'a SkipList<K, V>,
+    /// The index of the next node to yield.
+    next: Option<usize>,
+    /// The index of the first node past the range, or None to run to the end.
+    end: Option<usize>,
+}
+
+impl<'a, K: Ord, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next.filter(|&index| Some(index) != self.end)?;
+        self.next = self.list.node(index).forward[0];
+        Some(self.list.entry(index))
+    }
+}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    fn clone(&self) -> Self {
+        Range { ..*self }
+    }
+}
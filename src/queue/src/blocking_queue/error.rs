@@ -0,0 +1,85 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error of sending into a closed [`BlockingQueue`](super::BlockingQueue), giving back the
+/// element that couldn't be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Queue is closed")
+    }
+}
+
+impl<T: fmt::Debug> Error for SendError<T> {}
+
+/// The ways sending into a [`BlockingQueue`](super::BlockingQueue) without waiting can fail,
+/// giving back the element that couldn't be sent.
+///
+/// # Variants
+///
+/// * `Full` - The queue has no room for the element.
+/// * `Closed` - The queue is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrySendError<T> {
+    Full(T),
+    Closed(T),
+}
+
+impl<T> TrySendError<T> {
+    /// Returns the element that couldn't be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            TrySendError::Full(item) | TrySendError::Closed(item) => item,
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "Queue is full"),
+            TrySendError::Closed(_) => write!(f, "Queue is closed"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Error for TrySendError<T> {}
+
+/// The error of receiving from a [`BlockingQueue`](super::BlockingQueue) that is closed and
+/// empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RecvError;
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Queue is closed and empty")
+    }
+}
+
+impl Error for RecvError {}
+
+/// The ways receiving from a [`BlockingQueue`](super::BlockingQueue) without waiting, or
+/// waiting for a limited time, can fail.
+///
+/// # Variants
+///
+/// * `Empty` - The queue has no element yet, either right away or before the timeout.
+/// * `Closed` - The queue is closed and empty, so it will never have another element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TryRecvError {
+    Empty,
+    Closed,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "Queue is empty"),
+            TryRecvError::Closed => write!(f, "Queue is closed and empty"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
@@ -0,0 +1,306 @@
+pub mod error;
+
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::queue::Queue;
+use error::{RecvError, SendError, TryRecvError, TrySendError};
+
+/// The elements of a [`BlockingQueue`] and whether it is closed, guarded by its mutex.
+#[derive(Debug)]
+struct State<T> {
+    queue: Queue<T>,
+    closed: bool,
+}
+
+/// A bounded queue passing elements between threads, with any number of senders and receivers.
+///
+/// Sending waits while the queue is full and receiving waits while it is empty. Once the queue
+/// is closed, sending fails right away, while receiving goes on until the queue is empty, so
+/// that no element is lost. The queue is shared between threads by reference, for example
+/// through an [`Arc`](std::sync::Arc) or a scoped thread.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements passed through the queue.
+///
+/// # Fields
+///
+/// * `state` - The elements and whether the queue is closed.
+/// * `not_empty` - Wakes up the receivers waiting for an element.
+/// * `not_full` - Wakes up the senders waiting for room.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::blocking_queue::BlockingQueue;
+/// use crate::queue::blocking_queue::error::{TryRecvError, TrySendError};
+/// use std::time::Duration;
+///
+/// let queue = BlockingQueue::new(2);
+/// queue.send(1).unwrap();
+/// queue.try_send(2).unwrap();
+/// assert_eq!(queue.try_send(3), Err(TrySendError::Full(3)));
+///
+/// assert_eq!(queue.recv(), Ok(1));
+/// queue.close();
+/// assert!(queue.send(4).is_err());
+/// assert_eq!(queue.recv_timeout(Duration::from_millis(10)), Ok(2));
+/// assert_eq!(queue.try_recv(), Err(TryRecvError::Closed));
+/// ```
+///
+/// Stress test with four senders and four receivers:
+///
+/// ```
+/// use crate::queue::blocking_queue::BlockingQueue;
+/// use std::thread;
+///
+/// let queue = BlockingQueue::new(16);
+/// let (count, sum) = thread::scope(|scope| {
+///     let receivers: Vec<_> = (0..4)
+///         .map(|_| scope.spawn(|| queue.iter().fold((0, 0u64), |(n, s), x| (n + 1, s + x))))
+///         .collect();
+///     let senders: Vec<_> = (0..4u64)
+///         .map(|id| {
+///             let queue = &queue;
+///             scope.spawn(move || (0..25_000).for_each(|x| queue.send(id * 25_000 + x).unwrap()))
+///         })
+///         .collect();
+///     senders.into_iter().for_each(|sender| sender.join().unwrap());
+///     queue.close();
+///     receivers
+///         .into_iter()
+///         .map(|receiver| receiver.join().unwrap())
+///         .fold((0, 0), |(n, s), (m, t)| (n + m, s + t))
+/// });
+///
+/// assert_eq!(count, 100_000);
+/// assert_eq!(sum, (0..100_000).sum::<u64>());
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<T> BlockingQueue<T> {
+    /// Creates a new empty queue with the given maximum capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum capacity of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero, as nothing could ever be sent.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        BlockingQueue {
+            state: Mutex::new(State {
+                queue: Queue::new(capacity),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+
+    /// Sends an element, waiting while the queue is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to send.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the element is in the queue, or a [`SendError`] giving it back if the
+    /// queue is closed, including while waiting.
+    pub fn send(&self, item: T) -> Result<(), SendError<T>> {
+        let mut state = self.lock();
+        while !state.closed && state.queue.is_full() {
+            state = self
+                .not_full
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        if state.closed {
+            return Err(SendError(item));
+        }
+        self.push(state, item);
+        Ok(())
+    }
+
+    /// Sends an element if the queue has room for it right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to send.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the element is in the queue, or a [`TrySendError`] giving it back if the
+    /// queue is full or closed.
+    pub fn try_send(&self, item: T) -> Result<(), TrySendError<T>> {
+        let state = self.lock();
+        if state.closed {
+            return Err(TrySendError::Closed(item));
+        }
+        if state.queue.is_full() {
+            return Err(TrySendError::Full(item));
+        }
+        self.push(state, item);
+        Ok(())
+    }
+
+    /// Receives an element, waiting while the queue is empty.
+    ///
+    /// # Returns
+    ///
+    /// The oldest element of the queue, or a [`RecvError`] if the queue is closed and empty.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.lock();
+        while !state.closed && state.queue.is_empty() {
+            state = self
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        self.pop(state).ok_or(RecvError)
+    }
+
+    /// Receives an element if the queue has one right away.
+    ///
+    /// # Returns
+    ///
+    /// The oldest element of the queue, or [`TryRecvError::Empty`] if the queue is empty, or
+    /// [`TryRecvError::Closed`] if it is also closed.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let state = self.lock();
+        let closed = state.closed;
+        self.pop(state).ok_or(if closed {
+            TryRecvError::Closed
+        } else {
+            TryRecvError::Empty
+        })
+    }
+
+    /// Receives an element, waiting at most for a timeout while the queue is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - How long to wait for an element.
+    ///
+    /// # Returns
+    ///
+    /// The oldest element of the queue, or [`TryRecvError::Empty`] if the queue is still empty
+    /// after the timeout, or [`TryRecvError::Closed`] if it is closed and empty.
+    ///
+    /// A timeout too large to be represented as a point in time, such as [`Duration::MAX`],
+    /// waits without a deadline, like [`recv`](Self::recv).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::queue::blocking_queue::BlockingQueue;
+    /// use crate::queue::blocking_queue::error::TryRecvError;
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// let queue = BlockingQueue::new(1);
+    /// assert_eq!(queue.recv_timeout(Duration::from_millis(1)), Err(TryRecvError::Empty));
+    ///
+    /// let received = thread::scope(|scope| {
+    ///     scope.spawn(|| {
+    ///         thread::sleep(Duration::from_millis(10));
+    ///         queue.send(42).unwrap();
+    ///     });
+    ///     queue.recv_timeout(Duration::MAX)
+    /// });
+    /// assert_eq!(received, Ok(42));
+    ///
+    /// queue.close();
+    /// assert_eq!(queue.recv_timeout(Duration::MAX), Err(TryRecvError::Closed));
+    /// ```
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, TryRecvError> {
+        let Some(deadline) = Instant::now().checked_add(timeout) else {
+            return self.recv().map_err(|RecvError| TryRecvError::Closed);
+        };
+        let mut state = self.lock();
+        while !state.closed && state.queue.is_empty() {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(TryRecvError::Empty);
+            }
+            state = self
+                .not_empty
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+        self.pop(state).ok_or(TryRecvError::Closed)
+    }
+
+    /// Closes the queue, waking up every waiting sender and receiver.
+    ///
+    /// Sending fails from then on, while the elements still in the queue can be received.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /// Checks if the queue is closed.
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().queue.is_empty()
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn size(&self) -> usize {
+        self.lock().queue.size()
+    }
+
+    /// Returns the maximum capacity of the queue.
+    pub fn capacity(&self) -> usize {
+        self.lock().queue.capacity()
+    }
+
+    /// Returns an iterator receiving the elements of the queue.
+    ///
+    /// # Returns
+    ///
+    /// An iterator waiting for and yielding the elements, which ends once the queue is closed
+    /// and empty.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.recv().ok())
+    }
+
+    /// Locks the state, ignoring the poisoning of a thread that panicked while holding the
+    /// lock, as no operation leaves the state inconsistent.
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Enqueues an element into a queue with room for it, and wakes up a receiver.
+    fn push(&self, mut state: MutexGuard<'_, State<T>>, item: T) {
+        let _ = state.queue.enqueue(item);
+        drop(state);
+        self.not_empty.notify_one();
+    }
+
+    /// Dequeues an element, if any, and wakes up a sender.
+    fn pop(&self, mut state: MutexGuard<'_, State<T>>) -> Option<T> {
+        let item = state.queue.try_dequeue();
+        drop(state);
+        if item.is_some() {
+            self.not_full.notify_one();
+        }
+        item
+    }
+}
@@ -0,0 +1,111 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A source of the current time, which a [`DelayQueue`](super::DelayQueue) compares the
+/// deadlines of its elements to.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}
+
+/// The clock of the operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves forward when told to, for testing code relying on time.
+///
+/// # Fields
+///
+/// * `now` - The current time of the clock.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::delay_queue::clock::{Clock, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(5));
+///
+/// assert_eq!(clock.now() - start, Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Cell<Instant>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    /// Creates a new clock, stopped at the current time of the operating system.
+    pub fn new() -> Self {
+        ManualClock {
+            now: Cell::new(Instant::now()),
+        }
+    }
+
+    /// Moves the clock forward, stopping at the latest time that can be represented.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How far to move the clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::queue::delay_queue::clock::{Clock, ManualClock};
+    /// use std::time::Duration;
+    ///
+    /// let clock = ManualClock::new();
+    /// let start = clock.now();
+    /// clock.advance(Duration::MAX);
+    /// clock.advance(Duration::MAX);
+    /// assert!(clock.now() > start);
+    /// ```
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(saturating_add(self.now.get(), duration));
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+/// Returns the time a duration after an instant, or the latest time that can be represented if
+/// that would overflow.
+///
+/// `Instant` has no maximum value, so the latest time is found by adding halving steps of
+/// `duration` for as long as they fit.
+pub(super) fn saturating_add(instant: Instant, duration: Duration) -> Instant {
+    if let Some(later) = instant.checked_add(duration) {
+        return later;
+    }
+    let mut latest = instant;
+    let mut step = duration;
+    while !step.is_zero() {
+        match latest.checked_add(step) {
+            Some(later) => latest = later,
+            None => step /= 2,
+        }
+    }
+    latest
+}
@@ -0,0 +1,218 @@
+pub mod clock;
+
+use std::time::{Duration, Instant};
+
+use crate::priority_queue::{Handle, PriorityQueue};
+use clock::{saturating_add, Clock, SystemClock};
+
+/// A queue whose elements only come out once their deadline has passed.
+///
+/// Elements come out by deadline, the earliest first, and in the order they were inserted
+/// among equal deadlines. The current time comes from a [`Clock`], the one of the operating
+/// system by default, which can be replaced by a [`ManualClock`](clock::ManualClock) in tests.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the queue.
+/// * `C` - The [`Clock`] telling the current time, [`SystemClock`] by default.
+///
+/// # Fields
+///
+/// * `clock` - The clock telling the current time.
+/// * `queue` - The elements, by deadline.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::delay_queue::DelayQueue;
+/// use crate::queue::delay_queue::clock::ManualClock;
+/// use std::time::Duration;
+///
+/// let mut retries = DelayQueue::with_clock(ManualClock::new());
+/// retries.insert("fetch", Duration::from_secs(10));
+/// let upload = retries.insert("upload", Duration::from_secs(30));
+/// retries.insert("ping", Duration::from_secs(10));
+///
+/// assert_eq!(retries.poll(), None);
+/// assert_eq!(retries.time_until_next(), Some(Duration::from_secs(10)));
+///
+/// retries.clock().advance(Duration::from_secs(10));
+/// assert_eq!(retries.poll_all(), ["fetch", "ping"]);
+///
+/// retries.reset(upload, Duration::from_secs(5));
+/// retries.clock().advance(Duration::from_secs(5));
+/// assert_eq!(retries.poll(), Some("upload"));
+/// assert!(retries.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct DelayQueue<T, C: Clock = SystemClock> {
+    clock: C,
+    queue: PriorityQueue<T, Instant>,
+}
+
+impl<T> Default for DelayQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DelayQueue<T> {
+    /// Creates a new empty delay queue, on the clock of the operating system.
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<T, C: Clock> DelayQueue<T, C> {
+    /// Creates a new empty delay queue on the given clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The clock telling the current time.
+    pub fn with_clock(clock: C) -> Self {
+        DelayQueue {
+            clock,
+            queue: PriorityQueue::new(),
+        }
+    }
+
+    /// Returns the clock of the queue.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Inserts an element which comes out once a delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to insert.
+    /// * `delay` - How long from now the element stays in the queue. A delay too long to be
+    ///   represented, such as [`Duration::MAX`], gives the latest deadline that can be.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::queue::delay_queue::clock::ManualClock;
+    /// use crate::queue::delay_queue::DelayQueue;
+    /// use std::time::Duration;
+    ///
+    /// let mut queue = DelayQueue::with_clock(ManualClock::new());
+    /// queue.insert("never", Duration::MAX);
+    /// queue.insert("soon", Duration::from_secs(1));
+    ///
+    /// queue.clock().advance(Duration::from_secs(1));
+    /// assert_eq!(queue.poll_all(), ["soon"]);
+    /// assert!(queue.time_until_next() > Some(Duration::from_secs(1_000_000_000)));
+    /// ```
+    pub fn insert(&mut self, item: T, delay: Duration) -> Handle {
+        let deadline = saturating_add(self.clock.now(), delay);
+        self.insert_at(item, deadline)
+    }
+
+    /// Inserts an element which comes out once a deadline has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to insert.
+    /// * `deadline` - When the element comes out of the queue.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the element.
+    pub fn insert_at(&mut self, item: T, deadline: Instant) -> Handle {
+        self.queue.push(item, deadline)
+    }
+
+    /// Pops the element with the earliest deadline, if it has passed.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the element, or `None` if the queue is empty or no deadline has
+    /// passed yet.
+    pub fn poll(&mut self) -> Option<T> {
+        let (_, &deadline) = self.queue.try_peek()?;
+        if deadline > self.clock.now() {
+            return None;
+        }
+        self.queue.try_pop().map(|(item, _)| item)
+    }
+
+    /// Pops every element whose deadline has passed.
+    ///
+    /// # Returns
+    ///
+    /// The elements, by deadline.
+    pub fn poll_all(&mut self) -> Vec<T> {
+        std::iter::from_fn(|| self.poll()).collect()
+    }
+
+    /// Returns the earliest deadline of the elements.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the deadline, or `None` if the queue is empty.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.queue.try_peek().map(|(_, &deadline)| deadline)
+    }
+
+    /// Returns how long until the earliest deadline of the elements passes.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the duration, zero if the deadline has already passed, or `None`
+    /// if the queue is empty.
+    pub fn time_until_next(&self) -> Option<Duration> {
+        let deadline = self.next_deadline()?;
+        Some(deadline.saturating_duration_since(self.clock.now()))
+    }
+
+    /// Gives an element still in the queue a new delay, from now.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle of the element.
+    /// * `delay` - How long from now the element stays in the queue. A delay too long to be
+    ///   represented, such as [`Duration::MAX`], gives the latest deadline that can be.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the previous deadline, or `None` if the element isn't in the queue
+    /// anymore.
+    pub fn reset(&mut self, handle: Handle, delay: Duration) -> Option<Instant> {
+        let deadline = saturating_add(self.clock.now(), delay);
+        self.queue.change_priority(handle, deadline)
+    }
+
+    /// Removes an element from the queue, whether its deadline has passed or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle of the element.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the element and its deadline, or `None` if the element isn't in
+    /// the queue anymore.
+    pub fn remove(&mut self, handle: Handle) -> Option<(T, Instant)> {
+        self.queue.remove(handle)
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns the number of elements in the queue, whether their deadline has passed or not.
+    pub fn size(&self) -> usize {
+        self.queue.size()
+    }
+
+    /// Clears the queue, removing all elements.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+}
@@ -1,4 +1,5 @@
-use queue::queue::Queue;
+use crate::queue::Queue;
+use vec::error::CollectionError;
 use vec::vec::LinkedVec;
 
 /// # Hot Potato game
@@ -6,6 +7,11 @@ use vec::vec::LinkedVec;
 /// Simulates the "Hot Potato" game where a potato is passed around in a circle
 /// and whoever is holding the potato when the game ends is out.
 ///
+/// The last player holds the potato first and passes it towards the front of `names`. This is
+/// the Josephus problem on the players in reverse order, so the winner is the last player of
+/// [`elimination_order`](crate::josephus::elimination_order) on the reversed names; see
+/// [`josephus`](crate::josephus) for the whole order, or for a varying number of passes.
+///
 /// # Arguments
 ///
 /// * `names` - A vector containing the names of players participating in the game.
@@ -13,33 +19,44 @@ use vec::vec::LinkedVec;
 ///
 /// # Returns
 ///
-/// * A reference to the name of the player left in the game after all eliminations.
+/// * The name of the player left in the game after all eliminations, or
+///   [`CollectionError::Empty`] if there is no player.
 ///
 /// # Example
 ///
 /// ```
 /// use queue::hot_potato::hot_potato;
+/// use vec::error::CollectionError;
 /// use vec::linked_vec;
 ///
 /// let names = linked_vec!["Alice", "Bob", "Charlie", "David", "Emma"];
 /// let winner = hot_potato(names, 3);
-/// assert_eq!(winner, "Charlie");
+/// assert_eq!(winner, Ok("Emma"));
+///
+/// let ids = linked_vec![1, 2, 3, 4, 5, 6, 7];
+/// let order = queue::josephus::elimination_order((1..=7).rev(), 7);
+/// assert_eq!(hot_potato(ids, 7).ok(), order.last().copied());
+///
+/// let nobody: vec::vec::LinkedVec<&str> = linked_vec![];
+/// assert_eq!(hot_potato(nobody, 3), Err(CollectionError::Empty));
 /// ```
-pub fn hot_potato(names: LinkedVec<&str>, num: usize) -> &str {
+pub fn hot_potato<T>(mut names: LinkedVec<T>, num: usize) -> Result<T, CollectionError> {
     let mut queue = Queue::new(names.len());
 
-    for name in names.reverse().iter() {
-        let _ = queue.enqueue(*name);
+    let names: Vec<T> = names.drain().collect();
+    for name in names.into_iter().rev() {
+        queue.enqueue(name)?;
     }
 
     while queue.size() > 1 {
-        for _ in 0..num {
-            let front_name = queue.dequeue().unwrap();
-            let _ = queue.enqueue(front_name);
+        // Passing the potato around the whole circle gives it back to the same player.
+        for _ in 0..num % queue.size() {
+            let front_name = queue.dequeue()?;
+            queue.enqueue(front_name)?;
         }
 
-        queue.dequeue();
+        queue.dequeue()?;
     }
 
-    queue.dequeue().unwrap()
+    queue.dequeue()
 }
@@ -0,0 +1,275 @@
+use crate::queue::Queue;
+
+/// A round of the Josephus problem, as recorded by [`trace`].
+///
+/// # Fields
+///
+/// * `passes` - How many times the front element went to the back of the queue.
+/// * `queue` - The elements at the start of the round, front first.
+/// * `eliminated` - The element removed at the end of the round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round<T> {
+    /// How many times the front element went to the back of the queue.
+    pub passes: usize,
+    /// The elements at the start of the round, front first.
+    pub queue: Vec<T>,
+    /// The element removed at the end of the round.
+    pub eliminated: T,
+}
+
+/// Every round of the Josephus problem, and the last element left.
+///
+/// # Fields
+///
+/// * `rounds` - The rounds, in order.
+/// * `survivor` - The element left once every other one is eliminated, or None if there was
+///   no element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace<T> {
+    /// The rounds, in order.
+    pub rounds: Vec<Round<T>>,
+    /// The element left once every other one is eliminated.
+    pub survivor: Option<T>,
+}
+
+/// # Josephus elimination order
+///
+/// Solves the Josephus problem by simulation: the elements stand in a queue, and each round the
+/// front element goes to the back `passes` times before the front element is eliminated.
+///
+/// A round takes O(min(passes, n)) operations, as passing the elements around the whole queue
+/// changes nothing, so the whole order takes O(n * min(passes, n)). Use
+/// [`elimination_indices`] for a large number of elements.
+///
+/// # Arguments
+///
+/// * `items` - The elements, front first.
+/// * `passes` - How many elements go to the back of the queue before one is eliminated.
+///
+/// # Returns
+///
+/// The elements in the order they are eliminated, the survivor last.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::josephus::elimination_order;
+///
+/// // Every third soldier of seven is eliminated.
+/// assert_eq!(elimination_order(1..=7, 2), [3, 6, 2, 7, 5, 1, 4]);
+/// assert_eq!(elimination_order(Vec::<char>::new(), 2), []);
+/// ```
+pub fn elimination_order<T>(items: impl IntoIterator<Item = T>, passes: usize) -> Vec<T> {
+    simulate(items, |_| passes, |_, _| {})
+}
+
+/// # Josephus elimination order with varying passes
+///
+/// Solves the Josephus problem by simulation, like [`elimination_order`], with a number of
+/// passes that changes every round, such as one drawn from a random number generator or taken
+/// from an iterator.
+///
+/// # Arguments
+///
+/// * `items` - The elements, front first.
+/// * `passes` - Returns the number of passes of a round from its number, counted from 0.
+///
+/// # Returns
+///
+/// The elements in the order they are eliminated, the survivor last.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::josephus::{elimination_order, elimination_order_by};
+///
+/// let mut passes = [0, 1, 2].into_iter();
+/// let order = elimination_order_by("abcd".chars(), |_| passes.next().unwrap_or(0));
+/// assert_eq!(order, ['a', 'c', 'd', 'b']);
+///
+/// // A growing number of passes, one more each round.
+/// assert_eq!(elimination_order_by(1..=5, |round| round), [1, 3, 2, 5, 4]);
+/// assert_eq!(elimination_order_by(1..=5, |_| 4), elimination_order(1..=5, 4));
+/// ```
+pub fn elimination_order_by<T>(
+    items: impl IntoIterator<Item = T>,
+    passes: impl FnMut(usize) -> usize,
+) -> Vec<T> {
+    simulate(items, passes, |_, _| {})
+}
+
+/// # Josephus trace
+///
+/// Solves the Josephus problem by simulation, like [`elimination_order_by`], recording the
+/// queue at the start of every round.
+///
+/// # Arguments
+///
+/// * `items` - The elements, front first.
+/// * `passes` - Returns the number of passes of a round from its number, counted from 0.
+///
+/// # Returns
+///
+/// The rounds, each eliminating one element, and the survivor.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::josephus::{trace, Round};
+///
+/// let trace = trace(["Ann", "Bob", "Cid"], |_| 1);
+/// assert_eq!(
+///     trace.rounds,
+///     [
+///         Round { passes: 1, queue: vec!["Ann", "Bob", "Cid"], eliminated: "Bob" },
+///         Round { passes: 1, queue: vec!["Cid", "Ann"], eliminated: "Ann" },
+///     ]
+/// );
+/// assert_eq!(trace.survivor, Some("Cid"));
+/// ```
+pub fn trace<T: Clone>(
+    items: impl IntoIterator<Item = T>,
+    passes: impl FnMut(usize) -> usize,
+) -> Trace<T> {
+    let mut states = Vec::new();
+    let mut order = simulate(items, passes, |queue, passes| {
+        states.push((passes, queue.iter().cloned().collect()));
+    });
+    let survivor = order.pop();
+    let rounds = states
+        .into_iter()
+        .zip(order)
+        .map(|((passes, queue), eliminated)| Round {
+            passes,
+            queue,
+            eliminated,
+        })
+        .collect();
+    Trace { rounds, survivor }
+}
+
+/// # Josephus survivor
+///
+/// Finds the survivor of the Josephus problem without simulating it, with the recurrence
+/// `J(1) = 0` and `J(m) = (J(m - 1) + passes + 1) mod m`, in O(n).
+///
+/// # Arguments
+///
+/// * `n` - The number of elements.
+/// * `passes` - How many elements go to the back of the queue before one is eliminated.
+///
+/// # Returns
+///
+/// The index of the survivor among the elements, front first, or None if there is no element.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::josephus::survivor;
+///
+/// assert_eq!(survivor(7, 2), Some(3));
+/// assert_eq!(survivor(41, 2), Some(30));
+/// assert_eq!(survivor(10_000_000, 1), Some(3_222_784));
+/// assert_eq!(survivor(0, 2), None);
+/// ```
+pub fn survivor(n: usize, passes: usize) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
+    Some((2..=n).fold(0, |index, m| (index + passes % m + 1) % m))
+}
+
+/// # Josephus elimination indices
+///
+/// Finds the order in which the elements of the Josephus problem are eliminated without moving
+/// them, in O(n log n). A Fenwick tree counts the elements left before each index, so that the
+/// element at some rank among the elements left is found by binary lifting.
+///
+/// # Arguments
+///
+/// * `n` - The number of elements.
+/// * `passes` - How many elements go to the back of the queue before one is eliminated.
+///
+/// # Returns
+///
+/// The indices of the elements, front first, in the order they are eliminated, the survivor
+/// last.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::josephus::{elimination_indices, elimination_order, survivor};
+///
+/// assert_eq!(elimination_indices(7, 2), [2, 5, 1, 6, 4, 0, 3]);
+/// assert_eq!(elimination_indices(100, 6), elimination_order(0..100, 6));
+///
+/// let order = elimination_indices(1_000_000, 999);
+/// assert_eq!(order.last().copied(), survivor(1_000_000, 999));
+/// ```
+pub fn elimination_indices(n: usize, passes: usize) -> Vec<usize> {
+    // `counts[i]` holds the number of elements left among the indices (i - lowbit(i), i],
+    // counted from 1. Every element is left at first.
+    let mut counts = vec![0; n + 1];
+    for index in 1..=n {
+        counts[index] += 1;
+        let parent = index + (index & index.wrapping_neg());
+        if parent <= n {
+            counts[parent] += counts[index];
+        }
+    }
+    let top = if n == 0 { 0 } else { 1 << n.ilog2() };
+
+    let mut order = Vec::with_capacity(n);
+    let mut rank = 0;
+    for left in (1..=n).rev() {
+        rank = (rank + passes % left) % left;
+
+        // Find the last index with at most `rank` elements left up to it.
+        let mut index = 0;
+        let mut before = rank;
+        let mut step = top;
+        while step > 0 {
+            if index + step <= n && counts[index + step] <= before {
+                index += step;
+                before -= counts[index];
+            }
+            step >>= 1;
+        }
+
+        order.push(index);
+        let mut node = index + 1;
+        while node <= n {
+            counts[node] -= 1;
+            node += node & node.wrapping_neg();
+        }
+    }
+    order
+}
+
+/// Runs the Josephus problem on a queue, showing it and the number of passes to `observe` at
+/// the start of every round.
+fn simulate<T>(
+    items: impl IntoIterator<Item = T>,
+    mut passes: impl FnMut(usize) -> usize,
+    mut observe: impl FnMut(&Queue<T>, usize),
+) -> Vec<T> {
+    let mut queue = Queue::growable();
+    for item in items {
+        let _ = queue.enqueue(item);
+    }
+
+    let mut order = Vec::with_capacity(queue.size());
+    while queue.size() > 1 {
+        let count = passes(order.len());
+        observe(&queue, count);
+        // Passing the elements around the whole queue leaves it as it was.
+        for _ in 0..count % queue.size() {
+            if let Some(item) = queue.try_dequeue() {
+                let _ = queue.enqueue(item);
+            }
+        }
+        order.extend(queue.try_dequeue());
+    }
+    order.extend(queue.try_dequeue());
+    order
+}
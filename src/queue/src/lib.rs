@@ -1,3 +1,9 @@
 // --- region: modules
 pub mod queue;
+pub mod priority_queue;
+pub mod delay_queue;
+pub mod blocking_queue;
+pub mod spsc_queue;
+pub mod hot_potato;
+pub mod josephus;
 // --- endregion: modules
\ No newline at end of file
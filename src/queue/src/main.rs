@@ -1,11 +1,18 @@
-use vec::linked_vec;
+use vec::{linked_vec, vec::LinkedVec};
 
 // --- region: imports
-use crate::{hot_potato::hot_potato, queue::Queue};
+use crate::queue::Queue;
+use ::queue::hot_potato::hot_potato;
+use ::queue::josephus::{elimination_indices, elimination_order, elimination_order_by, survivor, trace};
+use ::queue::priority_queue::PriorityQueue;
+use ::queue::delay_queue::{clock::{Clock, ManualClock}, DelayQueue};
+use ::queue::blocking_queue::{error::TrySendError, BlockingQueue};
+use ::queue::spsc_queue::spsc_queue;
+use std::thread;
+use std::time::Duration;
 // --- enddregion: imports
 
 // --- region: modules
-mod hot_potato;
 mod queue;
 // --- endregion: modules
 
@@ -22,11 +29,151 @@ fn main() {
         println!("Got an error when enqueue 5 to the queue: {error}");
     }
 
+    println!("Dequeued {:?}", queue.dequeue());
     println!("Sum of queue = {}", queue.into_iter().sum::<i32>());
 
-    // HOT POTATO
+    let mut empty_queue: Queue<i32> = Queue::new(1);
+    if let Err(error) = empty_queue.dequeue() {
+        println!("Got an error when dequeue from an empty queue: {error}");
+    }
+
+    // RING BUFFER QUEUE
+    println!("\n\n***RING BUFFER QUEUE***");
+    let mut ring = Queue::new(3);
+    for item in ["a", "b", "c"] {
+        ring.enqueue(item).unwrap();
+    }
+    println!("{:?} full: {}, capacity: {}", ring, ring.is_full(), ring.capacity());
+    println!("Dequeued {:?}, then enqueued d: {:?}", ring.try_dequeue(), ring.enqueue("d"));
+    println!("front {:?}, back {:?}, in order: {:?}", ring.peek(), ring.peek_back(), ring.iter().collect::<Vec<_>>());
+
+    let mut growable = Queue::growable();
+    for item in 1..=10 {
+        growable.enqueue(item).unwrap();
+    }
+    growable.iter_mut().for_each(|item| *item *= 10);
+    println!("growable: {}, size: {}, capacity: {}, full: {}", growable.is_growable(), growable.size(), growable.capacity(), growable.is_full());
+    println!("front {:?}, back {:?}", growable.try_peek(), growable.try_peek_back());
+    growable.clear();
+    println!("empty after clear: {}, peek: {:?}", growable.is_empty(), growable.peek());
+
+        // PRIORITY QUEUE
+    println!("\n\n***PRIORITY QUEUE***");
+    let mut jobs = PriorityQueue::new();
+    let backup = jobs.push("backup", 3);
+    let report = jobs.push("report", 2);
+    jobs.push("deploy", 1);
+    let cleanup = jobs.push("cleanup", 3);
+    println!("next job: {:?}, {} jobs, report: {:?}", jobs.peek(), jobs.size(), jobs.get(report));
+    println!("report priority was {:?}, removed cleanup: {:?}", jobs.change_priority(report, 0), jobs.remove(cleanup));
+    println!("backup queued: {}, cleanup queued: {}", jobs.contains(backup), jobs.contains(cleanup));
+    println!("queued: {:?}", jobs.iter().collect::<Vec<_>>());
+    println!("popped {:?}, then {:?}", jobs.pop(), jobs.try_pop());
+    println!("try_peek: {:?}, empty: {}", jobs.try_peek(), jobs.is_empty());
+    jobs.clear();
+    println!("in order: {:?}", jobs.into_iter().collect::<Vec<_>>());
+
+
+    // DELAY QUEUE
+    println!("\n\n***DELAY QUEUE***");
+    let mut retries = DelayQueue::with_clock(ManualClock::new());
+    retries.insert("fetch", Duration::from_secs(10));
+    let upload = retries.insert("upload", Duration::from_secs(30));
+    let ping = retries.insert_at("ping", retries.clock().now() + Duration::from_secs(10));
+    println!("polled {:?}, {} waiting, next in {:?}", retries.poll(), retries.size(), retries.time_until_next());
+    retries.clock().advance(Duration::from_secs(10));
+    println!("after 10s: {:?}, next deadline passed: {}", retries.poll_all(), retries.next_deadline().is_some_and(|deadline| deadline <= retries.clock().now()));
+    println!("reset upload: {}, ping already polled: {}", retries.reset(upload, Duration::from_secs(5)).is_some(), retries.remove(ping).is_none());
+    retries.clock().advance(Duration::from_secs(5));
+    println!("after 5s: {:?}, empty: {}", retries.poll(), retries.is_empty());
+    let mut system: DelayQueue<&str> = DelayQueue::new();
+    system.insert("now", Duration::ZERO);
+    println!("system clock: {:?}", system.poll());
+    system.clear();
+
+
+        // BLOCKING QUEUE
+    println!("\n\n***BLOCKING QUEUE***");
+    let work = BlockingQueue::new(4);
+    let results: Vec<usize> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..2)
+            .map(|_| scope.spawn(|| work.iter().map(|job: &str| job.len()).sum()))
+            .collect();
+        for job in ["parse", "compile", "link", "test", "package", "deploy"] {
+            work.send(job).unwrap();
+        }
+        work.close();
+        workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+    });
+    println!("letters handled per worker: {:?}, total {}", results, results.iter().sum::<usize>());
+    println!("closed: {}, empty: {}, size: {}, capacity: {}", work.is_closed(), work.is_empty(), work.size(), work.capacity());
+    println!("send after close: {:?}, try_recv: {:?}", work.send("late").map_err(|error| error.to_string()), work.try_recv());
+    let small = BlockingQueue::new(1);
+    small.try_send(1).unwrap();
+    if let Err(error @ TrySendError::Full(_)) = small.try_send(2) {
+        println!("try_send: {error}, got back {}", error.into_inner());
+    }
+    println!("recv {:?}, then timeout: {:?}", small.recv(), small.recv_timeout(Duration::from_millis(10)));
+
+
+    // SPSC QUEUE
+    println!("\n\n***SPSC QUEUE***");
+    let (mut producer, mut consumer) = spsc_queue(8);
+    println!("capacity: {}, {}", producer.capacity(), consumer.capacity());
+    let sender = thread::spawn(move || {
+        for item in 1..=100u32 {
+            let mut pending = item;
+            while let Err(item) = producer.push(pending) {
+                pending = item;
+                thread::yield_now();
+            }
+        }
+        (producer.size(), producer.is_full(), producer.is_abandoned())
+    });
+    let mut sum = 0;
+    let mut received = 0;
+    while received < 100 {
+        match consumer.pop() {
+            Some(item) => {
+                sum += item;
+                received += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    println!("producer state at the end: {:?}", sender.join().unwrap());
+    println!("sum of 1..=100 = {sum}, empty: {}, abandoned: {}", consumer.is_empty(), consumer.is_abandoned());
+
+
+        // HOT POTATO
     println!("\n\n***HOT POTATO***");
     let players = linked_vec!["John", "James", "Marry", "Anna"];
-    let winner = hot_potato(players, 23);
-    println!("Winner is {winner}");
+    match hot_potato(players, 23) {
+        Ok(winner) => println!("Winner is {winner}"),
+        Err(error) => println!("No winner: {error}"),
+    }
+    if let Err(error) = hot_potato(LinkedVec::<&str>::new(), 23) {
+        println!("Got an error when playing without players: {error}");
+    }
+
+
+    // JOSEPHUS
+    println!("\n\n***JOSEPHUS***");
+    println!("Elimination order of 7 soldiers, every third: {:?}", elimination_order(1..=7, 2));
+    println!("Survivor among 41: {:?}, indices for 7: {:?}", survivor(41, 2).map(|index| index + 1), elimination_indices(7, 2));
+    let mut rng = rand_passes(7);
+    println!("Random passes: {:?}", elimination_order_by(["A", "B", "C", "D", "E"], |_| rng()));
+    let trace = trace(["Ann", "Bob", "Cid", "Dan"], |round| round + 1);
+    for (number, round) in trace.rounds.iter().enumerate() {
+        println!("Round {number}: {:?}, {} passes, {} is out", round.queue, round.passes, round.eliminated);
+    }
+    println!("Survivor: {:?}", trace.survivor);
+}
+
+/// Returns a small deterministic generator of pass counts, standing in for a random one.
+fn rand_passes(mut state: u64) -> impl FnMut() -> usize {
+    move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 60) as usize
+    }
 }
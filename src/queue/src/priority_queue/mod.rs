@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use vec::error::CollectionError;
+
+/// Identifies an element pushed into a [`PriorityQueue`], to look it up, change its priority or
+/// remove it later on.
+///
+/// Handles are never reused by the queue that issued them, so a handle whose element has been
+/// popped or removed stays invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Handle(u64);
+
+/// An element of the heap, along with its priority and handle.
+#[derive(Debug)]
+struct Entry<T, P> {
+    item: T,
+    priority: P,
+    handle: Handle,
+}
+
+/// A priority queue where the element with the lowest priority comes out first.
+///
+/// Elements of equal priority come out in the order they were pushed, including after their
+/// priority changed. The elements are kept in a binary heap, along with the position of each
+/// handle in the heap, so that pushing, popping and changing a priority are O(log n). Wrap the
+/// priorities in [`std::cmp::Reverse`] to get the element with the highest priority first.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the queue.
+/// * `P` - Type of the priorities of the elements.
+///
+/// # Fields
+///
+/// * `heap` - The elements, each smaller than or equal to its children.
+/// * `positions` - The index in `heap` of the element of each handle.
+/// * `next_handle` - The handle to give to the next element pushed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::priority_queue::PriorityQueue;
+///
+/// let mut jobs = PriorityQueue::new();
+/// jobs.push("backup", 3);
+/// let report = jobs.push("report", 2);
+/// jobs.push("deploy", 1);
+/// jobs.push("cleanup", 3);
+///
+/// assert_eq!(jobs.peek(), Ok((&"deploy", &1)));
+/// assert_eq!(jobs.change_priority(report, 0), Some(2));
+///
+/// let order: Vec<_> = jobs.into_iter().map(|(job, _)| job).collect();
+/// assert_eq!(order, ["report", "deploy", "backup", "cleanup"]);
+/// ```
+///
+/// Getting the highest priority first:
+///
+/// ```
+/// use crate::queue::priority_queue::PriorityQueue;
+/// use std::cmp::Reverse;
+///
+/// let mut queue = PriorityQueue::new();
+/// queue.push('a', Reverse(1));
+/// queue.push('b', Reverse(5));
+///
+/// assert_eq!(queue.pop(), Ok(('b', Reverse(5))));
+/// ```
+#[derive(Debug)]
+pub struct PriorityQueue<T, P: Ord> {
+    heap: Vec<Entry<T, P>>,
+    positions: HashMap<Handle, usize>,
+    next_handle: u64,
+}
+
+impl<T, P: Ord> Default for PriorityQueue<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P: Ord> PriorityQueue<T, P> {
+    /// Creates a new empty priority queue.
+    pub fn new() -> Self {
+        PriorityQueue {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+            next_handle: 0,
+        }
+    }
+
+    /// Pushes an element into the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to push.
+    /// * `priority` - The priority of the element.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the element.
+    pub fn push(&mut self, item: T, priority: P) -> Handle {
+        let handle = Handle(self.next_handle);
+        self.next_handle += 1;
+
+        self.positions.insert(handle, self.heap.len());
+        self.heap.push(Entry {
+            item,
+            priority,
+            handle,
+        });
+        self.sift_up(self.heap.len() - 1);
+
+        handle
+    }
+
+    /// Pops the element with the lowest priority, the oldest one among equals.
+    ///
+    /// # Returns
+    ///
+    /// The element and its priority, or [`CollectionError::Empty`] if the queue is empty.
+    pub fn pop(&mut self) -> Result<(T, P), CollectionError> {
+        self.try_pop().ok_or(CollectionError::Empty)
+    }
+
+    /// Pops the element with the lowest priority, the oldest one among equals.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the element and its priority, or `None` if the queue is empty.
+    pub fn try_pop(&mut self) -> Option<(T, P)> {
+        let handle = self.heap.first()?.handle;
+        self.remove(handle)
+    }
+
+    /// Returns the element with the lowest priority, the oldest one among equals.
+    ///
+    /// # Returns
+    ///
+    /// References to the element and its priority, or [`CollectionError::Empty`] if the queue
+    /// is empty.
+    pub fn peek(&self) -> Result<(&T, &P), CollectionError> {
+        self.try_peek().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns the element with the lowest priority, the oldest one among equals.
+    ///
+    /// # Returns
+    ///
+    /// An option containing references to the element and its priority, or `None` if the
+    /// queue is empty.
+    pub fn try_peek(&self) -> Option<(&T, &P)> {
+        self.heap
+            .first()
+            .map(|entry| (&entry.item, &entry.priority))
+    }
+
+    /// Changes the priority of an element still in the queue.
+    ///
+    /// The element keeps its place among the elements of equal priority, by the order they
+    /// were pushed in.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle of the element.
+    /// * `priority` - The new priority of the element.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the previous priority, or `None` if the element isn't in the queue
+    /// anymore.
+    pub fn change_priority(&mut self, handle: Handle, priority: P) -> Option<P> {
+        let index = *self.positions.get(&handle)?;
+        let previous = std::mem::replace(&mut self.heap[index].priority, priority);
+        self.sift_up(index);
+        self.sift_down(self.positions[&handle]);
+        Some(previous)
+    }
+
+    /// Returns the element and the priority of a handle.
+    ///
+    /// # Returns
+    ///
+    /// An option containing references to the element and its priority, or `None` if the
+    /// element isn't in the queue anymore.
+    pub fn get(&self, handle: Handle) -> Option<(&T, &P)> {
+        let entry = &self.heap[*self.positions.get(&handle)?];
+        Some((&entry.item, &entry.priority))
+    }
+
+    /// Checks if the element of a handle is still in the queue.
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.positions.contains_key(&handle)
+    }
+
+    /// Removes an element from the queue, whatever its priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle of the element.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the element and its priority, or `None` if the element isn't in
+    /// the queue anymore.
+    pub fn remove(&mut self, handle: Handle) -> Option<(T, P)> {
+        let index = self.positions.remove(&handle)?;
+        let last = self.heap.len() - 1;
+        self.heap.swap(index, last);
+        let entry = self.heap.pop()?;
+
+        if index < last {
+            let moved = self.heap[index].handle;
+            self.positions.insert(moved, index);
+            self.sift_up(index);
+            self.sift_down(self.positions[&moved]);
+        }
+
+        Some((entry.item, entry.priority))
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn size(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Clears the queue, removing all elements and invalidating their handles.
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.positions.clear();
+    }
+
+    /// Returns an iterator over the elements of the queue and their priorities.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements and their priorities, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &P)> {
+        self.heap.iter().map(|entry| (&entry.item, &entry.priority))
+    }
+
+    /// Checks if the element at index `a` must come out before the one at index `b`.
+    fn precedes(&self, a: usize, b: usize) -> bool {
+        let (a, b) = (&self.heap[a], &self.heap[b]);
+        (&a.priority, a.handle) < (&b.priority, b.handle)
+    }
+
+    /// Swaps two elements of the heap, keeping track of their positions.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions.insert(self.heap[a].handle, a);
+        self.positions.insert(self.heap[b].handle, b);
+    }
+
+    /// Moves the element at `index` up until its parent comes out before it.
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.precedes(index, parent) {
+                break;
+            }
+            self.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the element at `index` down until it comes out before its children.
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let mut first = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.heap.len() && self.precedes(child, first) {
+                    first = child;
+                }
+            }
+            if first == index {
+                break;
+            }
+            self.swap(index, first);
+            index = first;
+        }
+    }
+}
+
+/// An iterator consuming a [`PriorityQueue`] and yielding its elements and their priorities,
+/// in the order they would be popped.
+#[derive(Debug)]
+pub struct IntoIter<T, P: Ord>(PriorityQueue<T, P>);
+
+impl<T, P: Ord> Iterator for IntoIter<T, P> {
+    type Item = (T, P);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.try_pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.size(), Some(self.0.size()))
+    }
+}
+
+impl<T, P: Ord> IntoIterator for PriorityQueue<T, P> {
+    type Item = (T, P);
+    type IntoIter = IntoIter<T, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
@@ -1,8 +1,14 @@
-use std::fmt::Debug;
+use std::fmt;
 
-use vec::vec::LinkedVec;
+use vec::array_vec::ring_buf::RingBuf;
+use vec::error::CollectionError;
 
-/// A generic queue data structure implemented using a linked vector.
+/// A generic queue data structure implemented using a circular buffer.
+///
+/// The elements live in a [`RingBuf`], wrapping around to the start of its buffer when they
+/// reach its end, so that enqueueing and dequeueing are O(1). The buffer is only allocated once
+/// an element is enqueued, and doubles whenever it is full: a fixed queue stops growing at the
+/// capacity it was created with, while a growable queue has no limit.
 ///
 /// # Generic Parameters
 ///
@@ -10,8 +16,7 @@ use vec::vec::LinkedVec;
 ///
 /// # Fields
 ///
-/// * `cap` - Maximum capacity of the queue.
-/// * `data` - Internal linked vector storing the elements of the queue.
+/// * `buf` - The circular buffer storing the elements of the queue.
 ///
 /// # Examples
 ///
@@ -27,15 +32,56 @@ use vec::vec::LinkedVec;
 /// // Dequeue an element from the queue.
 /// let dequeued = queue.dequeue();
 ///
-/// assert_eq!(dequeued, Some(1));
+/// assert_eq!(dequeued, Ok(1));
+/// ```
+///
+/// Telling a full queue from an empty one:
+///
+/// ```
+/// use crate::queue::queue::Queue;
+/// use vec::error::CollectionError;
+///
+/// let mut queue = Queue::new(1);
+/// assert_eq!(queue.dequeue(), Err(CollectionError::Empty));
+///
+/// queue.enqueue("first").unwrap();
+/// assert!(queue.is_full());
+/// match queue.enqueue("second") {
+///     Err(CollectionError::Full { capacity }) => assert_eq!(capacity, 1),
+///     other => panic!("unexpected {:?}", other),
+/// }
+///
+/// assert_eq!(queue.try_dequeue(), Some("first"));
+/// assert_eq!(queue.try_dequeue(), None);
+/// ```
+///
+/// Wrapping around the end of the buffer, and growing:
+///
+/// ```
+/// use crate::queue::queue::Queue;
+///
+/// let mut queue = Queue::new(3);
+/// for item in 1..=3 {
+///     queue.enqueue(item).unwrap();
+/// }
+/// assert_eq!(queue.dequeue(), Ok(1));
+/// queue.enqueue(4).unwrap();
+/// assert_eq!(queue.iter().collect::<Vec<_>>(), [&2, &3, &4]);
+/// assert_eq!((queue.peek(), queue.peek_back()), (Ok(&2), Ok(&4)));
+///
+/// let mut growable = Queue::growable();
+/// for item in 0..100 {
+///     growable.enqueue(item).unwrap();
+/// }
+/// assert!(!growable.is_full());
+/// assert!(growable.capacity() >= 100);
+/// assert_eq!(growable.into_iter().collect::<Vec<_>>(), (0..100).collect::<Vec<_>>());
 /// ```
-#[derive(Debug)]
 pub struct Queue<T> {
-    cap: usize,
-    data: LinkedVec<T>,
+    buf: RingBuf<T>,
 }
 
-impl<T: Debug + Clone + PartialEq> Queue<T> {
+impl<T> Queue<T> {
     /// Creates a new empty queue with the given maximum capacity.
     ///
     /// # Arguments
@@ -44,11 +90,34 @@ impl<T: Debug + Clone + PartialEq> Queue<T> {
     ///
     /// # Returns
     ///
-    /// A new empty queue with the specified capacity.
+    /// A new empty queue with the specified capacity, which doesn't allocate until an element
+    /// is enqueued. Its buffer then doubles as needed up to the capacity, so a large capacity
+    /// costs nothing until it is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::queue::queue::Queue;
+    ///
+    /// let mut queue = Queue::new(usize::MAX);
+    /// queue.enqueue(1).unwrap();
+    /// assert_eq!(queue.capacity(), usize::MAX);
+    /// ```
     pub fn new(size: usize) -> Self {
         Queue {
-            cap: size,
-            data: LinkedVec::new(),
+            buf: RingBuf::new(size),
+        }
+    }
+
+    /// Creates a new empty queue without maximum capacity, which grows as elements are
+    /// enqueued.
+    ///
+    /// # Returns
+    ///
+    /// A new empty growable queue, which doesn't allocate until an element is enqueued.
+    pub fn growable() -> Self {
+        Queue {
+            buf: RingBuf::growable(),
         }
     }
 
@@ -60,15 +129,22 @@ impl<T: Debug + Clone + PartialEq> Queue<T> {
     ///
     /// # Returns
     ///
-    /// `Ok(())` if successful, otherwise returns an error if the queue is full.
-    pub fn enqueue(&mut self, item: T) -> Result<(), String> {
-        if self.cap == self.data.len() {
-            return Err("Max size!".into());
-        }
-
-        self.data.insert(0, item);
+    /// `Ok(())` if successful, otherwise [`CollectionError::Full`] if the queue is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing the buffer would make it larger than `isize::MAX` bytes.
+    pub fn enqueue(&mut self, item: T) -> Result<(), CollectionError> {
+        self.buf.push_back(item)
+    }
 
-        Ok(())
+    /// Dequeues an element from the queue.
+    ///
+    /// # Returns
+    ///
+    /// The dequeued element, or [`CollectionError::Empty`] if the queue is empty.
+    pub fn dequeue(&mut self) -> Result<T, CollectionError> {
+        self.try_dequeue().ok_or(CollectionError::Empty)
     }
 
     /// Dequeues an element from the queue.
@@ -76,8 +152,44 @@ impl<T: Debug + Clone + PartialEq> Queue<T> {
     /// # Returns
     ///
     /// An optional value containing the dequeued element, or `None` if the queue is empty.
-    pub fn dequeue(&mut self) -> Option<T> {
-        self.data.pop()
+    pub fn try_dequeue(&mut self) -> Option<T> {
+        self.buf.pop_front()
+    }
+
+    /// Returns a reference to the front element of the queue, the next one to be dequeued.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the front element, or [`CollectionError::Empty`] if the queue is empty.
+    pub fn peek(&self) -> Result<&T, CollectionError> {
+        self.try_peek().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the front element of the queue, the next one to be dequeued.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the front element, or `None` if the queue is empty.
+    pub fn try_peek(&self) -> Option<&T> {
+        self.buf.get(0)
+    }
+
+    /// Returns a reference to the back element of the queue, the last one enqueued.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the back element, or [`CollectionError::Empty`] if the queue is empty.
+    pub fn peek_back(&self) -> Result<&T, CollectionError> {
+        self.try_peek_back().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the back element of the queue, the last one enqueued.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the back element, or `None` if the queue is empty.
+    pub fn try_peek_back(&self) -> Option<&T> {
+        self.buf.get(self.buf.len().checked_sub(1)?)
     }
 
     /// Checks if the queue is empty.
@@ -86,7 +198,32 @@ impl<T: Debug + Clone + PartialEq> Queue<T> {
     ///
     /// `true` if the queue is empty, `false` otherwise.
     pub fn is_empty(&self) -> bool {
-        self.data.len() == 0
+        self.buf.is_empty()
+    }
+
+    /// Checks if the queue is full.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the queue is fixed and holds as many elements as its capacity, `false`
+    /// otherwise. A growable queue is never full.
+    pub fn is_full(&self) -> bool {
+        self.buf.is_full()
+    }
+
+    /// Checks if the queue grows as elements are enqueued.
+    pub fn is_growable(&self) -> bool {
+        self.buf.is_growable()
+    }
+
+    /// Returns the capacity of the queue.
+    ///
+    /// # Returns
+    ///
+    /// The maximum capacity of a fixed queue, or the number of elements a growable queue can
+    /// hold before it grows.
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
     }
 
     /// Returns the current size of the queue.
@@ -95,38 +232,46 @@ impl<T: Debug + Clone + PartialEq> Queue<T> {
     ///
     /// The number of elements currently in the queue.
     pub fn size(&self) -> usize {
-        self.data.len()
+        self.buf.len()
     }
 
     /// Clears the queue, removing all elements.
     pub fn clear(&mut self) {
-        self.data = LinkedVec::new();
+        self.buf.clear();
     }
 
     /// Returns an iterator over the elements of the queue.
     ///
     /// # Returns
     ///
-    /// An iterator yielding references to the elements of the queue.
+    /// An iterator yielding references to the elements of the queue, from front to back.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data.iter()
+        let (front, back) = self.buf.as_slices();
+        front.iter().chain(back)
     }
 
     /// Returns a mutable iterator over the elements of the queue.
     ///
     /// # Returns
     ///
-    /// A mutable iterator yielding references to the elements of the queue.
+    /// A mutable iterator yielding references to the elements of the queue, from front to back.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.data.iter_mut()
+        let (front, back) = self.buf.as_mut_slices();
+        front.iter_mut().chain(back)
     }
 
     /// Consumes the queue and returns an iterator over its elements.
     ///
     /// # Returns
     ///
-    /// An iterator consuming the queue and yielding its elements.
-    pub fn into_iter(self) -> impl Iterator<Item = T> {
-        self.data.into_iter()
+    /// An iterator consuming the queue and yielding its elements, from front to back.
+    pub fn into_iter(mut self) -> impl Iterator<Item = T> {
+        std::iter::from_fn(move || self.try_dequeue())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Queue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
     }
 }
@@ -0,0 +1,273 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The ring buffer shared by a [`Producer`] and a [`Consumer`].
+///
+/// Positions run from 0 to twice the capacity before wrapping around, so that a full buffer
+/// (`tail - head == capacity`) can be told from an empty one (`tail == head`) while every slot
+/// is used. The slot of a position is the position modulo the capacity.
+///
+/// # Fields
+///
+/// * `slots` - The elements, initialized from `head` up to `tail`.
+/// * `head` - The position of the next element to pop, only written by the consumer.
+/// * `tail` - The position of the next element to push, only written by the producer.
+struct Shared<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// SAFETY: a slot is only accessed by the producer before it publishes it through `tail`, and by
+// the consumer after it sees it through `tail` and before it gives it back through `head`.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    /// Returns the number of elements between two positions.
+    fn distance(&self, head: usize, tail: usize) -> usize {
+        let wrap = 2 * self.slots.len();
+        (tail + wrap - head) % wrap
+    }
+
+    /// Returns the position following `position`.
+    fn next(&self, position: usize) -> usize {
+        (position + 1) % (2 * self.slots.len())
+    }
+
+    /// Returns the slot of a position.
+    fn slot(&self, position: usize) -> *mut MaybeUninit<T> {
+        self.slots[position % self.slots.len()].get()
+    }
+}
+
+/// Custom drop implementation for dropping the elements neither side popped.
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let mut head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        while head != tail {
+            // SAFETY: the slots from `head` up to `tail` are initialized, and both sides are gone.
+            unsafe { (*self.slot(head)).assume_init_drop() };
+            head = self.next(head);
+        }
+    }
+}
+
+/// The sending side of a single-producer single-consumer queue, created by [`spsc_queue`].
+///
+/// # Fields
+///
+/// * `shared` - The ring buffer shared with the consumer.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The receiving side of a single-producer single-consumer queue, created by [`spsc_queue`].
+///
+/// # Fields
+///
+/// * `shared` - The ring buffer shared with the producer.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// # Single-producer single-consumer queue
+///
+/// Creates a bounded lock-free queue passing elements from one thread to another.
+///
+/// Pushing and popping never wait nor lock: they only read the position of the other side and
+/// publish their own with atomic operations, which makes the queue a fast path when a single
+/// thread produces the elements. Use a [`BlockingQueue`](crate::blocking_queue::BlockingQueue)
+/// for several producers or consumers, or to wait for elements or room.
+///
+/// # Arguments
+///
+/// * `capacity` - Maximum capacity of the queue.
+///
+/// # Returns
+///
+/// The producer and the consumer of the queue, which can each be moved to another thread.
+///
+/// # Panics
+///
+/// Panics if the capacity is zero.
+///
+/// # Examples
+///
+/// ```
+/// use crate::queue::spsc_queue::spsc_queue;
+///
+/// let (mut producer, mut consumer) = spsc_queue(2);
+/// producer.push('a').unwrap();
+/// producer.push('b').unwrap();
+/// assert_eq!(producer.push('c'), Err('c'));
+///
+/// assert_eq!(consumer.pop(), Some('a'));
+/// assert_eq!(consumer.size(), 1);
+/// drop(producer);
+/// assert!(consumer.is_abandoned());
+/// assert_eq!(consumer.pop(), Some('b'));
+/// assert_eq!(consumer.pop(), None);
+/// ```
+///
+/// Stress test passing a million elements through a small buffer, in order:
+///
+/// ```
+/// use crate::queue::spsc_queue::spsc_queue;
+/// use std::thread;
+///
+/// let (mut producer, mut consumer) = spsc_queue(64);
+/// let sender = thread::spawn(move || {
+///     for item in 0..1_000_000u64 {
+///         let mut pending = item;
+///         while let Err(item) = producer.push(pending) {
+///             pending = item;
+///             thread::yield_now();
+///         }
+///     }
+/// });
+///
+/// let mut expected = 0;
+/// while expected < 1_000_000 {
+///     match consumer.pop() {
+///         Some(item) => {
+///             assert_eq!(item, expected);
+///             expected += 1;
+///         }
+///         None => thread::yield_now(),
+///     }
+/// }
+/// sender.join().unwrap();
+/// assert!(consumer.is_empty());
+/// ```
+///
+/// Stress test dropping the queue with elements left in it:
+///
+/// ```
+/// use crate::queue::spsc_queue::spsc_queue;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let token = Arc::new(());
+/// for _ in 0..100 {
+///     let (mut producer, mut consumer) = spsc_queue(8);
+///     let sent = token.clone();
+///     let sender = thread::spawn(move || {
+///         for _ in 0..1_000 {
+///             let _ = producer.push(sent.clone());
+///         }
+///     });
+///     let received = thread::spawn(move || (0..1_000).filter(|_| consumer.pop().is_some()).count());
+///     sender.join().unwrap();
+///     assert!(received.join().unwrap() <= 1_000);
+/// }
+/// assert_eq!(Arc::strong_count(&token), 1);
+/// ```
+pub fn spsc_queue<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(capacity > 0, "capacity must be greater than zero");
+    let shared = Arc::new(Shared {
+        slots: (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+    let producer = Producer {
+        shared: shared.clone(),
+    };
+    (producer, Consumer { shared })
+}
+
+impl<T> Producer<T> {
+    /// Pushes an element into the queue if it has room for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to push.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the element is in the queue, or `Err` giving it back if the queue is full.
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        let shared = &*self.shared;
+        let tail = shared.tail.load(Ordering::Relaxed);
+        let head = shared.head.load(Ordering::Acquire);
+        if shared.distance(head, tail) == shared.slots.len() {
+            return Err(item);
+        }
+
+        // SAFETY: the queue isn't full, so the consumer is done with the slot of `tail`, and
+        // won't read it before `tail` is published.
+        unsafe { (*shared.slot(tail)).write(item) };
+        shared.tail.store(shared.next(tail), Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the number of elements in the queue, which the consumer may be lowering.
+    pub fn size(&self) -> usize {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let head = self.shared.head.load(Ordering::Acquire);
+        self.shared.distance(head, tail)
+    }
+
+    /// Checks if the queue is full, which the consumer may be changing.
+    pub fn is_full(&self) -> bool {
+        self.size() == self.capacity()
+    }
+
+    /// Returns the maximum capacity of the queue.
+    pub fn capacity(&self) -> usize {
+        self.shared.slots.len()
+    }
+
+    /// Checks if the consumer was dropped, in which case nothing pushed will ever be popped.
+    pub fn is_abandoned(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+}
+
+impl<T> Consumer<T> {
+    /// Pops the oldest element of the queue.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the element, or `None` if the queue is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let shared = &*self.shared;
+        let head = shared.head.load(Ordering::Relaxed);
+        let tail = shared.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+
+        // SAFETY: the queue isn't empty, so the producer initialized the slot of `head` before
+        // publishing `tail`, and won't write it again before `head` moves past it.
+        let item = unsafe { (*shared.slot(head)).assume_init_read() };
+        shared.head.store(shared.next(head), Ordering::Release);
+        Some(item)
+    }
+
+    /// Returns the number of elements in the queue, which the producer may be raising.
+    pub fn size(&self) -> usize {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        self.shared.distance(head, tail)
+    }
+
+    /// Checks if the queue is empty, which the producer may be changing.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns the maximum capacity of the queue.
+    pub fn capacity(&self) -> usize {
+        self.shared.slots.len()
+    }
+
+    /// Checks if the producer was dropped, in which case the elements left are the last ones.
+    pub fn is_abandoned(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+}
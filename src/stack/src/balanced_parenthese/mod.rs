@@ -2,7 +2,7 @@ use crate::stack::Stack;
 
 /// # Parentheses Checker 1
 ///
-/// Checks if a string made of starting and ending symbols is balanced or not.
+/// Checks if the parentheses of a string are balanced or not. Other characters are ignored.
 ///
 /// # Arguments
 ///
@@ -20,6 +20,7 @@ use crate::stack::Stack;
 ///
 /// assert_eq!(res1, true);
 /// assert_eq!(res2, false);
+/// assert_eq!(parenthese_checker_1("(a + b)"), true);
 /// ```
 pub fn parenthese_checker_1(string: &str) -> bool {
     let mut char_list = Vec::new();
@@ -35,8 +36,7 @@ pub fn parenthese_checker_1(string: &str) -> bool {
     while is_balanced && index < char_list.len() {
         if char_list[index] == '(' {
             stack.push(char_list[index])
-        } else {
-            // else if the character is ')'
+        } else if char_list[index] == ')' {
             if stack.is_empty() {
                 is_balanced = false;
             } else {
@@ -51,6 +51,8 @@ pub fn parenthese_checker_1(string: &str) -> bool {
 /// # Parentheses Checker 2
 ///
 /// Checks if a string including different types of left and right parentheses is balanced or not.
+/// Use [`DelimiterChecker`](crate::delimiter_checker::DelimiterChecker) to find where it isn't,
+/// or to check other delimiters.
 ///
 /// # Arguments
 ///
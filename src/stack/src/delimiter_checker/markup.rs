@@ -0,0 +1,238 @@
+use std::ops::Range;
+
+use vec::storage::Contiguous;
+
+use super::{DelimiterError, DelimiterErrorKind, Opener};
+use crate::stack::Stack;
+
+/// The HTML elements that never have a closing tag.
+const HTML_VOID_ELEMENTS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// The HTML elements whose content is raw text, which can't hold tags.
+const HTML_RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// A checker of balanced HTML or XML tags.
+///
+/// Every opening tag must be closed by a closing tag of the same name, in order, unless it is
+/// self-closing like `<br/>`. Attribute values may hold `<` and `>` when quoted, and comments,
+/// CDATA sections, declarations such as `<!DOCTYPE html>` and processing instructions such as
+/// `<?xml version="1.0"?>` are skipped. A `<` that doesn't start a tag is treated as text.
+///
+/// # Fields
+///
+/// * `case_sensitive` - Whether tag names are compared with their case.
+/// * `void_elements` - The names of the elements without closing tag.
+/// * `raw_text_elements` - The names of the elements whose content isn't checked.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::delimiter_checker::markup::TagChecker;
+/// use crate::stack::delimiter_checker::DelimiterErrorKind;
+///
+/// let html = TagChecker::html();
+/// assert!(html.check("<!DOCTYPE html><P class=\"a>b\">Hi<br></p><script>if (a<b) {}</script>").is_ok());
+///
+/// let source = "<ul>\n  <li>one\n</ul>";
+/// let error = html.check(source).unwrap_err();
+/// assert_eq!(
+///     error.kind,
+///     DelimiterErrorKind::Mismatched { expected: "</li>".to_string(), found: "</ul>".to_string() }
+/// );
+/// assert_eq!(error.location(source), (3, 1));
+/// let open: Vec<_> = error.unclosed.iter().map(|opener| opener.text.as_str()).collect();
+/// assert_eq!(open, ["<ul>", "<li>"]);
+///
+/// let xml = TagChecker::xml();
+/// assert!(xml.check("<?xml version=\"1.0\"?><a><b/><![CDATA[</a>]]></a>").is_ok());
+/// let error = xml.check("<a><!-- </a> -->").unwrap_err();
+/// assert_eq!(error.kind, DelimiterErrorKind::Unclosed("<a>".to_string()));
+/// assert_eq!(error.span, 0..3);
+/// assert!(xml.check("<a></A>").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagChecker {
+    case_sensitive: bool,
+    void_elements: Vec<String>,
+    raw_text_elements: Vec<String>,
+}
+
+impl TagChecker {
+    /// Creates a new checker for HTML, where tag names are case-insensitive, void elements such
+    /// as `<br>` don't need to be closed, and the content of `<script>` and `<style>` is
+    /// skipped.
+    pub fn html() -> Self {
+        TagChecker {
+            case_sensitive: false,
+            void_elements: HTML_VOID_ELEMENTS.map(String::from).to_vec(),
+            raw_text_elements: HTML_RAW_TEXT_ELEMENTS.map(String::from).to_vec(),
+        }
+    }
+
+    /// Creates a new checker for XML, where tag names are case-sensitive and every element
+    /// must be closed.
+    pub fn xml() -> Self {
+        TagChecker {
+            case_sensitive: true,
+            void_elements: Vec::new(),
+            raw_text_elements: Vec::new(),
+        }
+    }
+
+    /// Checks if the tags of a string are balanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The markup to be checked.
+    ///
+    /// # Returns
+    ///
+    /// Nothing if every opening tag is closed in order, or a [`DelimiterError`] locating the
+    /// first malformed, unexpected or mismatched closing tag, or else the innermost unclosed
+    /// tag or the unterminated comment.
+    pub fn check(&self, source: &str) -> Result<(), DelimiterError> {
+        let mut tags: Stack<(&str, Opener), Contiguous> = Stack::with_storage();
+        let mut index = 0;
+
+        while let Some(offset) = source[index..].find('<') {
+            let start = index + offset;
+            let rest = &source[start..];
+            let section = [
+                ("<!--", "-->"),
+                ("<![CDATA[", "]]>"),
+                ("<?", "?>"),
+                ("<!", ">"),
+            ]
+            .into_iter()
+            .find(|(open, _)| rest.starts_with(open));
+            if let Some((open, close)) = section {
+                match rest[open.len()..].find(close) {
+                    Some(end) => index = start + open.len() + end + close.len(),
+                    None => {
+                        let kind = DelimiterErrorKind::Unterminated(open.to_string());
+                        return error(kind, start..start + open.len(), &tags);
+                    }
+                }
+            } else if let Some(after) = rest.strip_prefix("</") {
+                let name = &after[..name_length(after)];
+                let trailing = &after[name.len()..];
+                let Some(end) = trailing.find('>') else {
+                    let kind = DelimiterErrorKind::Unterminated(format!("</{}", name));
+                    return error(kind, start..start + 2 + name.len(), &tags);
+                };
+                let span = start..start + 2 + name.len() + end + 1;
+                if name.is_empty() || !trailing[..end].trim().is_empty() {
+                    return error(DelimiterErrorKind::MalformedTag, span, &tags);
+                }
+                let found = format!("</{}>", name);
+                match tags.try_peek() {
+                    Some(&(open, _)) if self.same_name(open, name) => {
+                        let _ = tags.pop();
+                    }
+                    Some(&(open, _)) => {
+                        let expected = format!("</{}>", open);
+                        let kind = DelimiterErrorKind::Mismatched { expected, found };
+                        return error(kind, span, &tags);
+                    }
+                    None => {
+                        return error(DelimiterErrorKind::UnexpectedCloser(found), span, &tags);
+                    }
+                }
+                index = span.end;
+            } else if name_length(&rest[1..]) > 0 {
+                let name = &rest[1..1 + name_length(&rest[1..])];
+                let Some(end) = tag_end(rest, 1 + name.len()) else {
+                    let kind = DelimiterErrorKind::Unterminated(format!("<{}", name));
+                    return error(kind, start..start + 1 + name.len(), &tags);
+                };
+                let span = start..start + end;
+                index = span.end;
+                if rest[..end - 1].ends_with('/') || self.contains(&self.void_elements, name) {
+                    continue;
+                }
+                let text = format!("<{}>", name);
+                tags.push((name, Opener { text, span }));
+                if self.contains(&self.raw_text_elements, name) {
+                    let content = &source[index..];
+                    index += self.find_closing(content, name).unwrap_or(content.len());
+                }
+            } else {
+                index = start + 1;
+            }
+        }
+
+        match tags.try_peek() {
+            Some((_, opener)) => {
+                let kind = DelimiterErrorKind::Unclosed(opener.text.clone());
+                let span = opener.span.clone();
+                error(kind, span, &tags)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Checks if two tag names are the same.
+    fn same_name(&self, a: &str, b: &str) -> bool {
+        if self.case_sensitive {
+            a == b
+        } else {
+            a.eq_ignore_ascii_case(b)
+        }
+    }
+
+    /// Checks if a tag name is in a list of names.
+    fn contains(&self, names: &[String], name: &str) -> bool {
+        names.iter().any(|other| self.same_name(other, name))
+    }
+
+    /// Finds the start of the closing tag of a raw text element in its content.
+    fn find_closing(&self, content: &str, name: &str) -> Option<usize> {
+        content
+            .match_indices("</")
+            .map(|(index, _)| index)
+            .find(|&index| {
+                let after = &content[index + 2..];
+                let length = name_length(after);
+                length > 0 && self.same_name(&after[..length], name)
+            })
+    }
+}
+
+/// Creates the error for a string checked up to some point, with the tags still open.
+fn error(
+    kind: DelimiterErrorKind,
+    span: Range<usize>,
+    tags: &Stack<(&str, Opener), Contiguous>,
+) -> Result<(), DelimiterError> {
+    let unclosed = tags.iter().map(|(_, opener)| opener.clone()).collect();
+    Err(DelimiterError::new(kind, span, unclosed))
+}
+
+/// Returns the length in bytes of the tag name at the start of `text`, or 0 if there is none.
+fn name_length(text: &str) -> usize {
+    match text.chars().next() {
+        Some(first) if first.is_alphabetic() || first == '_' || first == ':' => text
+            .find(|char: char| !(char.is_alphanumeric() || matches!(char, '_' | ':' | '-' | '.')))
+            .unwrap_or(text.len()),
+        _ => 0,
+    }
+}
+
+/// Finds the end of the tag starting `text`, right after its `>`, from byte `index` on, skipping
+/// quoted attribute values.
+fn tag_end(text: &str, mut index: usize) -> Option<usize> {
+    let mut quote = None;
+    for char in text[index..].chars() {
+        index += char.len_utf8();
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (None, '>') => return Some(index),
+            (Some(open), _) if open == char => quote = None,
+            _ => {}
+        }
+    }
+    None
+}
@@ -0,0 +1,399 @@
+pub mod markup;
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use vec::storage::Contiguous;
+
+use crate::{expression::error::render, stack::Stack};
+
+/// The ways a string can fail to be balanced.
+///
+/// # Variants
+///
+/// * `UnexpectedCloser` - A closing delimiter while nothing is open.
+/// * `Mismatched` - A closing delimiter that doesn't close the innermost open delimiter.
+/// * `Unclosed` - An opening delimiter still open at the end of the string.
+/// * `Unterminated` - A quote, comment or other section that runs to the end of the string.
+/// * `MalformedTag` - A markup tag that can't be read, such as `</>` or `</a b>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DelimiterErrorKind {
+    UnexpectedCloser(String),
+    Mismatched {
+        /// The closing delimiter of the innermost open delimiter.
+        expected: String,
+        /// The closing delimiter found instead.
+        found: String,
+    },
+    Unclosed(String),
+    Unterminated(String),
+    MalformedTag,
+}
+
+impl fmt::Display for DelimiterErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DelimiterErrorKind::UnexpectedCloser(closer) => {
+                write!(f, "unexpected closing `{}`", closer)
+            }
+            DelimiterErrorKind::Mismatched { expected, found } => {
+                write!(f, "expected `{}` but found `{}`", expected, found)
+            }
+            DelimiterErrorKind::Unclosed(opener) => write!(f, "`{}` is never closed", opener),
+            DelimiterErrorKind::Unterminated(opener) => {
+                write!(f, "`{}` is never terminated", opener)
+            }
+            DelimiterErrorKind::MalformedTag => write!(f, "malformed tag"),
+        }
+    }
+}
+
+/// An opening delimiter and where it is.
+///
+/// # Fields
+///
+/// * `text` - The opening delimiter, such as `(` or `<div>` for a markup tag.
+/// * `span` - The byte range of the source string holding it. For a markup tag, it covers the
+///   whole tag including its attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Opener {
+    /// The opening delimiter.
+    pub text: String,
+    /// The byte range of the source string holding it.
+    pub span: Range<usize>,
+}
+
+/// The first place where a string fails to be balanced.
+///
+/// # Fields
+///
+/// * `kind` - What went wrong.
+/// * `span` - The byte range of the source string where it went wrong: the offending closer,
+///   the unclosed opener, or the start of the unterminated section.
+/// * `unclosed` - The delimiters open when it went wrong, outermost first.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::delimiter_checker::{DelimiterChecker, DelimiterErrorKind};
+///
+/// let source = "fn main() {\n    let v = [1, 2);\n}";
+/// let error = DelimiterChecker::brackets().check(source).unwrap_err();
+/// assert_eq!(
+///     error.kind,
+///     DelimiterErrorKind::Mismatched { expected: "]".to_string(), found: ")".to_string() }
+/// );
+/// assert_eq!(error.location(source), (2, 18));
+/// let open: Vec<_> = error.unclosed.iter().map(|opener| opener.text.as_str()).collect();
+/// assert_eq!(open, ["{", "["]);
+/// assert_eq!(
+///     error.render(source),
+///     "error: expected `]` but found `)` (line 2, column 18)\n    let v = [1, 2);\n                 ^"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelimiterError {
+    /// What went wrong.
+    pub kind: DelimiterErrorKind,
+    /// The byte range of the source string where it went wrong.
+    pub span: Range<usize>,
+    /// The delimiters open when it went wrong, outermost first.
+    pub unclosed: Vec<Opener>,
+}
+
+impl DelimiterError {
+    /// Creates a new delimiter error.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - What went wrong.
+    /// * `span` - The byte range of the source string where it went wrong.
+    /// * `unclosed` - The delimiters open when it went wrong, outermost first.
+    pub fn new(kind: DelimiterErrorKind, span: Range<usize>, unclosed: Vec<Opener>) -> Self {
+        DelimiterError {
+            kind,
+            span,
+            unclosed,
+        }
+    }
+
+    /// Returns the byte offset of the source string where the error starts.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// Returns the line and column where the error starts, both counted from 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The string that was checked.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the line number and the column, in characters, of the start of the span.
+    pub fn location(&self, source: &str) -> (usize, usize) {
+        let mut start = self.span.start.min(source.len());
+        while !source.is_char_boundary(start) {
+            start -= 1;
+        }
+        let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+        (
+            source[..start].matches('\n').count() + 1,
+            source[line_start..start].chars().count() + 1,
+        )
+    }
+
+    /// Renders the error as a message followed by the offending line, with the span underlined.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The string that was checked.
+    pub fn render(&self, source: &str) -> String {
+        render(source, &self.span, &self.kind)
+    }
+}
+
+impl fmt::Display for DelimiterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset())
+    }
+}
+
+impl Error for DelimiterError {}
+
+/// The delimiter found at some position of the string.
+enum Delimiter {
+    /// The opener of the pair at this index.
+    Open(usize),
+    /// The closer of the pair at this index.
+    Close(usize),
+}
+
+/// A configurable checker of balanced delimiters.
+///
+/// Delimiters are matched greedily, the longest one first, so that pairs such as `{{`/`}}` and
+/// `{`/`}` can be used together. A pair whose opener and closer are the same, such as `|`/`|`,
+/// closes when it is the innermost open delimiter and opens otherwise. Delimiters are ignored
+/// inside quotes and line comments, and the character following the escape character is never
+/// a delimiter, a quote or the start of a comment.
+///
+/// # Fields
+///
+/// * `pairs` - The opening and closing delimiters.
+/// * `quotes` - The characters opening and closing a quoted section.
+/// * `escape` - The character escaping the following one, if any.
+/// * `line_comments` - The strings starting a comment running to the end of the line.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::delimiter_checker::{DelimiterChecker, DelimiterErrorKind};
+///
+/// let checker = DelimiterChecker::brackets()
+///     .with_quote('"')
+///     .with_escape('\\')
+///     .with_line_comment("#");
+/// assert!(checker.check("key = [\"a)\", \"b\\\"]\"] # a comment with (").is_ok());
+///
+/// let error = checker.check("key = [\"a\", (\"b\"]").unwrap_err();
+/// assert_eq!(error.offset(), 16);
+///
+/// let error = checker.check("key = \"open").unwrap_err();
+/// assert_eq!(error.kind, DelimiterErrorKind::Unterminated("\"".to_string()));
+/// assert_eq!(error.span, 6..7);
+///
+/// let templates = DelimiterChecker::new().with_pair("{{", "}}").with_pair("{", "}");
+/// assert!(templates.check("{{ name }} {a: {b} }").is_ok());
+/// assert!(templates.check("{{ name }").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DelimiterChecker {
+    pairs: Vec<(String, String)>,
+    quotes: Vec<char>,
+    escape: Option<char>,
+    line_comments: Vec<String>,
+}
+
+impl DelimiterChecker {
+    /// Creates a new checker without any delimiter, quote or comment.
+    pub fn new() -> Self {
+        DelimiterChecker::default()
+    }
+
+    /// Creates a new checker for parentheses, square brackets and curly braces.
+    pub fn brackets() -> Self {
+        DelimiterChecker::new()
+            .with_pair("(", ")")
+            .with_pair("[", "]")
+            .with_pair("{", "}")
+    }
+
+    /// Adds a pair of opening and closing delimiters.
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - The opening delimiter.
+    /// * `close` - The closing delimiter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either delimiter is empty.
+    pub fn with_pair(mut self, open: &str, close: &str) -> Self {
+        assert!(
+            !open.is_empty() && !close.is_empty(),
+            "delimiters can't be empty"
+        );
+        self.pairs.push((open.to_string(), close.to_string()));
+        self
+    }
+
+    /// Adds a character opening and closing a quoted section, inside which delimiters are
+    /// ignored.
+    pub fn with_quote(mut self, quote: char) -> Self {
+        self.quotes.push(quote);
+        self
+    }
+
+    /// Sets the character escaping the following one, both inside and outside quotes.
+    pub fn with_escape(mut self, escape: char) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Adds a string starting a comment that runs to the end of the line, inside which
+    /// delimiters are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is empty.
+    pub fn with_line_comment(mut self, start: &str) -> Self {
+        assert!(!start.is_empty(), "comment starts can't be empty");
+        self.line_comments.push(start.to_string());
+        self
+    }
+
+    /// Checks if the delimiters of a string are balanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The string to be checked.
+    ///
+    /// # Returns
+    ///
+    /// Nothing if every opening delimiter is closed in order, or a [`DelimiterError`] locating
+    /// the first unexpected or mismatched closer, or else the innermost unclosed opener or the
+    /// unterminated quote.
+    pub fn check(&self, source: &str) -> Result<(), DelimiterError> {
+        let mut openers: Stack<(usize, Opener), Contiguous> = Stack::with_storage();
+        let mut index = 0;
+
+        while let Some(char) = source[index..].chars().next() {
+            let rest = &source[index..];
+            if Some(char) == self.escape {
+                index += char.len_utf8();
+                index += source[index..].chars().next().map_or(0, char::len_utf8);
+            } else if self
+                .line_comments
+                .iter()
+                .any(|start| rest.starts_with(start.as_str()))
+            {
+                index += rest.find('\n').unwrap_or(rest.len());
+            } else if self.quotes.contains(&char) {
+                let span = index..index + char.len_utf8();
+                index = self.skip_quoted(source, span.end, char).ok_or_else(|| {
+                    let kind = DelimiterErrorKind::Unterminated(char.to_string());
+                    DelimiterError::new(kind, span, unclosed(&openers))
+                })?;
+            } else {
+                let innermost = openers.try_peek().map(|&(pair, _)| pair);
+                match self.delimiter_at(rest, innermost) {
+                    Some(Delimiter::Open(pair)) => {
+                        let text = self.pairs[pair].0.clone();
+                        let span = index..index + text.len();
+                        index = span.end;
+                        openers.push((pair, Opener { text, span }));
+                    }
+                    Some(Delimiter::Close(pair)) => {
+                        let found = self.pairs[pair].1.clone();
+                        let span = index..index + found.len();
+                        index = span.end;
+                        match innermost {
+                            Some(open) if open == pair => {
+                                let _ = openers.pop();
+                            }
+                            Some(open) => {
+                                let expected = self.pairs[open].1.clone();
+                                let kind = DelimiterErrorKind::Mismatched { expected, found };
+                                return Err(DelimiterError::new(kind, span, unclosed(&openers)));
+                            }
+                            None => {
+                                let kind = DelimiterErrorKind::UnexpectedCloser(found);
+                                return Err(DelimiterError::new(kind, span, Vec::new()));
+                            }
+                        }
+                    }
+                    None => index += char.len_utf8(),
+                }
+            }
+        }
+
+        match openers.try_peek() {
+            Some((_, opener)) => {
+                let kind = DelimiterErrorKind::Unclosed(opener.text.clone());
+                let span = opener.span.clone();
+                Err(DelimiterError::new(kind, span, unclosed(&openers)))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Finds the longest delimiter at the start of `rest`, preferring closers on ties.
+    fn delimiter_at(&self, rest: &str, innermost: Option<usize>) -> Option<Delimiter> {
+        let mut longest: Option<(usize, Delimiter)> = None;
+        for (pair, (open, close)) in self.pairs.iter().enumerate() {
+            let symmetric = open == close;
+            let candidates = [
+                (
+                    close,
+                    !symmetric || innermost == Some(pair),
+                    Delimiter::Close(pair),
+                ),
+                (
+                    open,
+                    !symmetric || innermost != Some(pair),
+                    Delimiter::Open(pair),
+                ),
+            ];
+            for (text, allowed, delimiter) in candidates {
+                let longer = longest
+                    .as_ref()
+                    .is_none_or(|&(length, _)| text.len() > length);
+                if allowed && longer && rest.starts_with(text.as_str()) {
+                    longest = Some((text.len(), delimiter));
+                }
+            }
+        }
+        longest.map(|(_, delimiter)| delimiter)
+    }
+
+    /// Finds the end of the quoted section starting at byte `index`, right after the opening
+    /// quote, or None if it isn't closed.
+    fn skip_quoted(&self, source: &str, mut index: usize, quote: char) -> Option<usize> {
+        let mut chars = source[index..].chars();
+        while let Some(char) = chars.next() {
+            index += char.len_utf8();
+            if Some(char) == self.escape {
+                index += chars.next().map_or(0, char::len_utf8);
+            } else if char == quote {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+/// Copies a stack of open delimiters into a vector, outermost first.
+fn unclosed(openers: &Stack<(usize, Opener), Contiguous>) -> Vec<Opener> {
+    openers.iter().map(|(_, opener)| opener.clone()).collect()
+}
@@ -0,0 +1,507 @@
+use std::fmt;
+
+use vec::storage::Contiguous;
+
+use super::error::ParseError;
+use super::token::Operator;
+use super::{Function, Instruction, Number, Program};
+use crate::stack::Stack;
+
+/// The precedence of the unary minus, between `* / %` and `^`.
+const NEGATE_PRECEDENCE: u8 = 3;
+/// The precedence of numbers, variables and function calls, which never need parentheses.
+const ATOM_PRECEDENCE: u8 = 5;
+
+/// An arithmetic expression as a tree.
+///
+/// Expressions are built from the postfix [`Program`] of the shunting-yard parser: every
+/// instruction pops its operands from a stack of subtrees and pushes the subtree it forms.
+/// Numbers are stored as `f64`.
+///
+/// The [`Display`](fmt::Display) implementation prints the infix form with as few parentheses
+/// as needed for it to parse back into the same tree.
+///
+/// # Variants
+///
+/// * `Number` - A number.
+/// * `Variable` - A variable.
+/// * `Negate` - The negation of an expression.
+/// * `Binary` - A binary operator applied to a left and a right expression.
+/// * `Call` - A function called with a list of arguments.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::expression::ast::Expr;
+///
+/// let expr = Expr::parse("((a + b)) * (c - (d - e)) ^ 2").unwrap();
+/// assert_eq!(expr.to_string(), "(a + b) * (c - (d - e)) ^ 2");
+/// assert_eq!(expr.to_prefix(), "* + a b ^ - c - d e 2");
+/// assert_eq!(expr.to_postfix(), "a b + c d e - - 2 ^ *");
+///
+/// let expr = Expr::parse("3*x^2 + 2*x + 7").unwrap();
+/// let slope = expr.derivative("x").unwrap().simplify();
+/// assert_eq!(slope.to_string(), "6 * x + 2");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Variable(String),
+    Negate(Box<Expr>),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+impl Expr {
+    /// Parses an infix expression into a tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The infix expression, as accepted by [`Program::compile`].
+    ///
+    /// # Returns
+    ///
+    /// The tree of the expression, or the [`ParseError`] of the compiler.
+    pub fn parse(source: &str) -> Result<Expr, ParseError> {
+        Ok(Expr::from_program(&Program::compile(source)?))
+    }
+
+    /// Builds the tree of a compiled program, using a stack of subtrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The compiled program.
+    ///
+    /// # Returns
+    ///
+    /// The tree of the expression.
+    pub fn from_program(program: &Program) -> Expr {
+        // A compiled program always has the operands its instructions need, and leaves one tree.
+        fn pop(trees: &mut Stack<Expr, Contiguous>) -> Expr {
+            trees.try_pop().expect("compiled programs are well formed")
+        }
+
+        let mut trees: Stack<Expr, Contiguous> = Stack::with_storage();
+
+        for instruction in program.instructions() {
+            let tree = match instruction {
+                Instruction::Integer(digits) => {
+                    Expr::Number(digits.parse().expect("integer literals are runs of digits"))
+                }
+                Instruction::Decimal(value) => Expr::Number(*value),
+                Instruction::Variable(name) => Expr::Variable(name.clone()),
+                Instruction::Negate => Expr::Negate(Box::new(pop(&mut trees))),
+                Instruction::Binary(operator) => {
+                    let right = pop(&mut trees);
+                    let left = pop(&mut trees);
+                    Expr::Binary(*operator, Box::new(left), Box::new(right))
+                }
+                Instruction::Call(function, arity) => {
+                    let mut arguments: Vec<Expr> = (0..*arity).map(|_| pop(&mut trees)).collect();
+                    arguments.reverse();
+                    Expr::Call(*function, arguments)
+                }
+            };
+            trees.push(tree);
+        }
+
+        pop(&mut trees)
+    }
+
+    /// Returns the infix form of the expression, with as few parentheses as needed.
+    pub fn to_infix(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the prefix (Polish) form of the expression, with tokens separated by spaces.
+    ///
+    /// The unary minus is written `neg` and a call is written with its number of arguments, as
+    /// in `max(2) a b`.
+    pub fn to_prefix(&self) -> String {
+        let mut tokens = Vec::new();
+        self.walk(&mut |expr| tokens.push(expr.token()), &mut |_| {});
+        tokens.join(" ")
+    }
+
+    /// Returns the postfix (reverse Polish) form of the expression, with tokens separated by
+    /// spaces, as printed by [`Program`].
+    pub fn to_postfix(&self) -> String {
+        let mut tokens = Vec::new();
+        self.walk(&mut |_| {}, &mut |expr| tokens.push(expr.token()));
+        tokens.join(" ")
+    }
+
+    /// Returns a copy of the expression where every subtree without variables is replaced by
+    /// its value.
+    ///
+    /// Subtrees whose value isn't a finite number, such as `1 / 0`, are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::expression::ast::Expr;
+    ///
+    /// let expr = Expr::parse("x * (2 + 3 ^ 2) - max(1, sqrt(16)) + 1 / 0").unwrap();
+    /// assert_eq!(expr.fold_constants().to_string(), "x * 11 - 4 + 1 / 0");
+    /// ```
+    pub fn fold_constants(&self) -> Expr {
+        self.rewrite(false)
+    }
+
+    /// Returns a simplified copy of the expression.
+    ///
+    /// Constants are folded as by [`fold_constants`](Expr::fold_constants), then the
+    /// identities `x + 0 = 0 + x = x - 0 = x`, `0 - x = -x`, `x * 1 = 1 * x = x / 1 = x`,
+    /// `x * 0 = 0 * x = 0`, `x ^ 1 = x`, `x ^ 0 = 1 ^ x = 1` and `--x = x` are applied from the
+    /// leaves up, and numbers multiplying a product starting with a number are combined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::expression::ast::Expr;
+    ///
+    /// let expr = Expr::parse("(x * 1 + 0) ^ (2 - 1) - 0 * y + -(-z)").unwrap();
+    /// assert_eq!(expr.simplify().to_string(), "x + z");
+    ///
+    /// let expr = Expr::parse("2 * (3 * x) / 1").unwrap();
+    /// assert_eq!(expr.simplify().to_string(), "6 * x");
+    /// ```
+    pub fn simplify(&self) -> Expr {
+        self.rewrite(true)
+    }
+
+    /// Returns the derivative of the expression with respect to a variable.
+    ///
+    /// Other variables are treated as constants. The sum, difference, product, quotient, power
+    /// (with an exponent not depending on the variable) and chain rules are applied, which
+    /// covers polynomials, rational functions and `sqrt`. The result is not simplified.
+    ///
+    /// # Arguments
+    ///
+    /// * `variable` - The name of the variable.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the derivative, or None if the expression has a `%`, a `min` or
+    /// `max` call, or an exponent depending on the variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::expression::ast::Expr;
+    ///
+    /// let expr = Expr::parse("x^3 - 4*x*y + y^2").unwrap();
+    /// let dx = expr.derivative("x").unwrap().simplify();
+    /// assert_eq!(dx.to_string(), "3 * x ^ 2 - 4 * y");
+    /// let dy = expr.derivative("y").unwrap().simplify();
+    /// assert_eq!(dy.to_string(), "-(4 * x) + 2 * y");
+    ///
+    /// let expr = Expr::parse("sqrt(x) / x").unwrap();
+    /// assert!(expr.derivative("x").is_some());
+    /// assert_eq!(Expr::parse("2 ^ x").unwrap().derivative("x"), None);
+    /// ```
+    pub fn derivative(&self, variable: &str) -> Option<Expr> {
+        Some(match self {
+            Expr::Number(_) => Expr::Number(0.0),
+            Expr::Variable(name) => Expr::Number(if name == variable { 1.0 } else { 0.0 }),
+            Expr::Negate(operand) => Expr::Negate(Box::new(operand.derivative(variable)?)),
+            Expr::Binary(operator, left, right) => {
+                let (u, v) = (left.as_ref(), right.as_ref());
+                match operator {
+                    Operator::Add | Operator::Sub => {
+                        binary(*operator, u.derivative(variable)?, v.derivative(variable)?)
+                    }
+                    Operator::Mul => binary(
+                        Operator::Add,
+                        binary(Operator::Mul, u.derivative(variable)?, v.clone()),
+                        binary(Operator::Mul, u.clone(), v.derivative(variable)?),
+                    ),
+                    Operator::Div => binary(
+                        Operator::Div,
+                        binary(
+                            Operator::Sub,
+                            binary(Operator::Mul, u.derivative(variable)?, v.clone()),
+                            binary(Operator::Mul, u.clone(), v.derivative(variable)?),
+                        ),
+                        binary(Operator::Pow, v.clone(), Expr::Number(2.0)),
+                    ),
+                    Operator::Pow if !v.depends_on(variable) => {
+                        let exponent = match v {
+                            Expr::Number(value) => Expr::Number(value - 1.0),
+                            _ => binary(Operator::Sub, v.clone(), Expr::Number(1.0)),
+                        };
+                        binary(
+                            Operator::Mul,
+                            binary(
+                                Operator::Mul,
+                                v.clone(),
+                                binary(Operator::Pow, u.clone(), exponent),
+                            ),
+                            u.derivative(variable)?,
+                        )
+                    }
+                    Operator::Pow | Operator::Rem => return None,
+                }
+            }
+            Expr::Call(Function::Sqrt, arguments) => binary(
+                Operator::Div,
+                arguments[0].derivative(variable)?,
+                binary(Operator::Mul, Expr::Number(2.0), self.clone()),
+            ),
+            Expr::Call(Function::Min | Function::Max, _) => return None,
+        })
+    }
+
+    /// Checks if the expression contains a variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `variable` - The name of the variable.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the variable appears in the expression, `false` otherwise.
+    pub fn depends_on(&self, variable: &str) -> bool {
+        match self {
+            Expr::Number(_) => false,
+            Expr::Variable(name) => name == variable,
+            Expr::Negate(operand) => operand.depends_on(variable),
+            Expr::Binary(_, left, right) => left.depends_on(variable) || right.depends_on(variable),
+            Expr::Call(_, arguments) => arguments.iter().any(|arg| arg.depends_on(variable)),
+        }
+    }
+
+    /// Returns the tree in the Graphviz DOT language.
+    ///
+    /// Nodes are named `n0`, `n1`, ... in prefix order and labelled with their token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::expression::ast::Expr;
+    ///
+    /// let expr = Expr::parse("-x * 2").unwrap();
+    /// assert_eq!(
+    ///     expr.to_dot(),
+    ///     "digraph expr {\n  \
+    ///        n0 [label=\"*\"];\n  \
+    ///        n0 -> n1;\n  \
+    ///        n1 [label=\"neg\"];\n  \
+    ///        n1 -> n2;\n  \
+    ///        n2 [label=\"x\"];\n  \
+    ///        n0 -> n3;\n  \
+    ///        n3 [label=\"2\"];\n\
+    ///      }\n"
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph expr {\n");
+        let mut next_id = 0;
+        self.write_dot(&mut dot, &mut next_id);
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Writes the node of the expression and its subtrees, and returns the name of the node.
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) -> String {
+        let name = format!("n{}", next_id);
+        *next_id += 1;
+        let label = match self {
+            Expr::Call(function, _) => function.name().to_string(),
+            _ => self.token(),
+        };
+        dot.push_str(&format!("  {} [label={:?}];\n", name, label));
+        for child in self.children() {
+            let child_name = format!("n{}", next_id);
+            dot.push_str(&format!("  {} -> {};\n", name, child_name));
+            child.write_dot(dot, next_id);
+        }
+        name
+    }
+
+    /// Returns the direct subtrees of the expression, from left to right.
+    fn children(&self) -> Vec<&Expr> {
+        match self {
+            Expr::Number(_) | Expr::Variable(_) => Vec::new(),
+            Expr::Negate(operand) => vec![operand],
+            Expr::Binary(_, left, right) => vec![left, right],
+            Expr::Call(_, arguments) => arguments.iter().collect(),
+        }
+    }
+
+    /// Visits the expression depth first, calling `pre` on each node before its subtrees and
+    /// `post` after them.
+    fn walk(&self, pre: &mut dyn FnMut(&Expr), post: &mut dyn FnMut(&Expr)) {
+        pre(self);
+        for child in self.children() {
+            child.walk(pre, post);
+        }
+        post(self);
+    }
+
+    /// Returns the token of the node in prefix and postfix forms.
+    fn token(&self) -> String {
+        match self {
+            Expr::Number(value) => value.to_string(),
+            Expr::Variable(name) => name.clone(),
+            Expr::Negate(_) => Instruction::Negate.to_string(),
+            Expr::Binary(operator, _, _) => operator.to_string(),
+            Expr::Call(function, arguments) => {
+                Instruction::Call(*function, arguments.len()).to_string()
+            }
+        }
+    }
+
+    /// Returns how tightly the expression binds when printed in infix form.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Number(value) if value.is_sign_negative() => NEGATE_PRECEDENCE,
+            Expr::Number(_) | Expr::Variable(_) | Expr::Call(_, _) => ATOM_PRECEDENCE,
+            Expr::Negate(_) => NEGATE_PRECEDENCE,
+            Expr::Binary(operator, _, _) => operator.precedence(),
+        }
+    }
+
+    /// Returns the value of the expression if it is a number.
+    fn value(&self) -> Option<f64> {
+        match self {
+            Expr::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Folds the constants of the expression from the leaves up, also applying the algebraic
+    /// identities if `identities` is set.
+    fn rewrite(&self, identities: bool) -> Expr {
+        match self {
+            Expr::Number(_) | Expr::Variable(_) => self.clone(),
+            Expr::Negate(operand) => match operand.rewrite(identities) {
+                Expr::Number(value) => Expr::Number(-value),
+                Expr::Negate(inner) if identities => *inner,
+                operand => Expr::Negate(Box::new(operand)),
+            },
+            Expr::Binary(operator, left, right) => {
+                let left = left.rewrite(identities);
+                let right = right.rewrite(identities);
+                if let (Some(a), Some(b)) = (left.value(), right.value()) {
+                    if let Some(value) = finite(f64::apply(*operator, a, b).ok()) {
+                        return Expr::Number(value);
+                    }
+                }
+                if identities {
+                    simplify_binary(*operator, left, right)
+                } else {
+                    binary(*operator, left, right)
+                }
+            }
+            Expr::Call(function, arguments) => {
+                let arguments: Vec<Expr> = arguments
+                    .iter()
+                    .map(|argument| argument.rewrite(identities))
+                    .collect();
+                let values: Option<Vec<f64>> = arguments.iter().map(Expr::value).collect();
+                let folded = values.and_then(|values| {
+                    finite(match function {
+                        Function::Min => values.into_iter().reduce(f64::min),
+                        Function::Max => values.into_iter().reduce(f64::max),
+                        Function::Sqrt => Some(values[0].sqrt()),
+                    })
+                });
+                match folded {
+                    Some(value) => Expr::Number(value),
+                    None => Expr::Call(*function, arguments),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(value) => write!(f, "{}", value),
+            Expr::Variable(name) => write!(f, "{}", name),
+            Expr::Negate(operand) => {
+                write!(f, "-")?;
+                write_operand(f, operand, operand.precedence() < NEGATE_PRECEDENCE)
+            }
+            Expr::Binary(operator, left, right) => {
+                let precedence = operator.precedence();
+                let right_associative = operator.is_right_associative();
+                let left_parens = left.precedence() < precedence
+                    || (left.precedence() == precedence && right_associative);
+                // An operand starting with a minus can always follow an operator unparenthesized.
+                let right_parens = right.precedence() != NEGATE_PRECEDENCE
+                    && (right.precedence() < precedence
+                        || (right.precedence() == precedence && !right_associative));
+                write_operand(f, left, left_parens)?;
+                write!(f, " {} ", operator)?;
+                write_operand(f, right, right_parens)
+            }
+            Expr::Call(function, arguments) => {
+                write!(f, "{}(", function.name())?;
+                for (index, argument) in arguments.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", argument)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl From<&Program> for Expr {
+    fn from(program: &Program) -> Self {
+        Expr::from_program(program)
+    }
+}
+
+/// Builds a binary expression.
+fn binary(operator: Operator, left: Expr, right: Expr) -> Expr {
+    Expr::Binary(operator, Box::new(left), Box::new(right))
+}
+
+/// Keeps a value only if it is a finite number.
+fn finite(value: Option<f64>) -> Option<f64> {
+    value.filter(|value| value.is_finite())
+}
+
+/// Writes an operand of an operator, between parentheses if `parens` is set.
+fn write_operand(f: &mut fmt::Formatter<'_>, operand: &Expr, parens: bool) -> fmt::Result {
+    if parens {
+        write!(f, "({})", operand)
+    } else {
+        write!(f, "{}", operand)
+    }
+}
+
+/// Applies the algebraic identities to a binary expression with simplified operands.
+fn simplify_binary(operator: Operator, left: Expr, right: Expr) -> Expr {
+    let (a, b) = (left.value(), right.value());
+    match operator {
+        Operator::Add if a == Some(0.0) => right,
+        Operator::Add | Operator::Sub if b == Some(0.0) => left,
+        Operator::Sub if a == Some(0.0) => match right {
+            Expr::Negate(operand) => *operand,
+            right => Expr::Negate(Box::new(right)),
+        },
+        Operator::Mul if a == Some(0.0) || b == Some(0.0) => Expr::Number(0.0),
+        Operator::Mul if a == Some(1.0) => right,
+        Operator::Mul | Operator::Div if b == Some(1.0) => left,
+        Operator::Pow if b == Some(1.0) => left,
+        Operator::Pow if b == Some(0.0) || a == Some(1.0) => Expr::Number(1.0),
+        Operator::Mul => match (a, right) {
+            // c1 * (c2 * x) = (c1 * c2) * x
+            (Some(c1), Expr::Binary(Operator::Mul, inner_left, inner_right))
+                if inner_left.value().is_some() =>
+            {
+                let c2 = inner_left.value().unwrap_or_default();
+                binary(Operator::Mul, Expr::Number(c1 * c2), *inner_right)
+            }
+            (_, right) => binary(Operator::Mul, left, right),
+        },
+        _ => binary(operator, left, right),
+    }
+}
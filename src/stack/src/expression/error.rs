@@ -0,0 +1,345 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// The ways an expression can fail to parse.
+///
+/// # Variants
+///
+/// * `UnexpectedCharacter` - A character that can't start a token.
+/// * `InvalidNumber` - A number with more than one decimal point, or only a decimal point.
+/// * `UnexpectedToken` - A token in a place where it can't appear, such as `1 2` or `1 + * 2`.
+/// * `UnexpectedEnd` - The expression ends where an operand is expected, such as `1 +`.
+/// * `UnbalancedParen` - A parenthesis without its matching pair.
+/// * `UnknownFunction` - A call to a function that doesn't exist.
+/// * `WrongArity` - A call with a number of arguments the function doesn't accept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnexpectedCharacter(char),
+    InvalidNumber,
+    UnexpectedToken,
+    UnexpectedEnd,
+    UnbalancedParen,
+    UnknownFunction(String),
+    WrongArity {
+        /// The name of the function.
+        function: &'static str,
+        /// The number of arguments it was called with.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedCharacter(char) => {
+                write!(f, "unexpected character {:?}", char)
+            }
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::UnexpectedToken => write!(f, "unexpected token"),
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            ParseErrorKind::UnbalancedParen => write!(f, "unbalanced parenthesis"),
+            ParseErrorKind::UnknownFunction(name) => write!(f, "unknown function `{}`", name),
+            ParseErrorKind::WrongArity { function, found } => {
+                write!(f, "`{}` can't take {} argument(s)", function, found)
+            }
+        }
+    }
+}
+
+/// An error found while parsing an expression, along with where it is.
+///
+/// # Fields
+///
+/// * `kind` - What went wrong.
+/// * `span` - The byte range of the source string where it went wrong. It is empty at the end
+///   of the source string for [`ParseErrorKind::UnexpectedEnd`].
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::expression::Program;
+/// use crate::stack::expression::error::ParseErrorKind;
+///
+/// let error = Program::compile("(1 + 2").unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::UnbalancedParen);
+/// assert_eq!(error.offset(), 0);
+/// assert_eq!(error.to_string(), "unbalanced parenthesis at byte 0");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+    /// The byte range of the source string where it went wrong.
+    pub span: Range<usize>,
+}
+
+impl ParseError {
+    /// Creates a new parse error.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - What went wrong.
+    /// * `span` - The byte range of the source string where it went wrong.
+    ///
+    /// # Returns
+    ///
+    /// A new parse error.
+    pub fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        ParseError { kind, span }
+    }
+
+    /// Returns the byte offset of the source string where the error starts.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// Renders the error as a message followed by the offending line, with the span underlined.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source string the error was found in.
+    ///
+    /// # Returns
+    ///
+    /// The rendered error, on three lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::expression::Program;
+    ///
+    /// let source = "2 * sqrt(4, 9)";
+    /// let error = Program::compile(source).unwrap_err();
+    /// assert_eq!(
+    ///     error.render(source),
+    ///     "error: `sqrt` can't take 2 argument(s) (line 1, column 5)\n\
+    ///      2 * sqrt(4, 9)\n    \
+    ///      ^^^^^^^^^^"
+    /// );
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        render(source, &self.span, &self.kind)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset())
+    }
+}
+
+impl Error for ParseError {}
+
+/// The ways an expression can fail to evaluate.
+///
+/// # Variants
+///
+/// * `StackUnderflow` - An operator or function has fewer operands than it needs.
+/// * `UnusedOperands` - Operands are left over once every operator has been applied.
+/// * `InvalidToken` - A token that is neither a number nor a known operator.
+/// * `UnboundVariable` - A variable without a value.
+/// * `DivisionByZero` - A division or remainder by zero.
+/// * `Overflow` - A result too large for the number type.
+/// * `NotAnInteger` - A decimal number in an integer evaluation.
+/// * `InvalidOperand` - An operand outside the domain of its operation, such as the square
+///   root of a negative integer or a negative integer exponent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalErrorKind {
+    StackUnderflow,
+    UnusedOperands,
+    InvalidToken,
+    UnboundVariable(String),
+    DivisionByZero,
+    Overflow,
+    NotAnInteger,
+    InvalidOperand,
+}
+
+impl fmt::Display for EvalErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalErrorKind::StackUnderflow => write!(f, "missing operand"),
+            EvalErrorKind::UnusedOperands => write!(f, "unused operands"),
+            EvalErrorKind::InvalidToken => write!(f, "invalid token"),
+            EvalErrorKind::UnboundVariable(name) => write!(f, "unbound variable `{}`", name),
+            EvalErrorKind::DivisionByZero => write!(f, "division by zero"),
+            EvalErrorKind::Overflow => write!(f, "overflow"),
+            EvalErrorKind::NotAnInteger => write!(f, "not an integer"),
+            EvalErrorKind::InvalidOperand => write!(f, "invalid operand"),
+        }
+    }
+}
+
+/// An error found while evaluating an expression, along with where it is.
+///
+/// # Fields
+///
+/// * `kind` - What went wrong.
+/// * `span` - The byte range of the source string holding the operator, function call or
+///   operand that failed.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use crate::stack::expression::Program;
+/// use crate::stack::expression::error::EvalErrorKind;
+///
+/// let source = "10 / (5 - 5)";
+/// let error = Program::compile(source).unwrap().eval::<i64>(&HashMap::new()).unwrap_err();
+/// assert_eq!(error.kind, EvalErrorKind::DivisionByZero);
+/// assert_eq!(error.span, 3..4);
+/// assert_eq!(
+///     error.render(source),
+///     "error: division by zero (line 1, column 4)\n10 / (5 - 5)\n   ^"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalError {
+    /// What went wrong.
+    pub kind: EvalErrorKind,
+    /// The byte range of the source string where it went wrong.
+    pub span: Range<usize>,
+}
+
+impl EvalError {
+    /// Creates a new evaluation error.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - What went wrong.
+    /// * `span` - The byte range of the source string where it went wrong.
+    ///
+    /// # Returns
+    ///
+    /// A new evaluation error.
+    pub fn new(kind: EvalErrorKind, span: Range<usize>) -> Self {
+        EvalError { kind, span }
+    }
+
+    /// Returns the byte offset of the source string where the error starts.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// Renders the error as a message followed by the offending line, with the span underlined.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source string the error was found in.
+    ///
+    /// # Returns
+    ///
+    /// The rendered error, on three lines.
+    pub fn render(&self, source: &str) -> String {
+        render(source, &self.span, &self.kind)
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset())
+    }
+}
+
+impl Error for EvalError {}
+
+/// An error from either parsing or evaluating an expression.
+///
+/// # Variants
+///
+/// * `Parse` - The expression failed to parse.
+/// * `Eval` - The expression parsed but failed to evaluate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionError {
+    Parse(ParseError),
+    Eval(EvalError),
+}
+
+impl ExpressionError {
+    /// Returns the byte range of the source string where the error is.
+    pub fn span(&self) -> &Range<usize> {
+        match self {
+            ExpressionError::Parse(error) => &error.span,
+            ExpressionError::Eval(error) => &error.span,
+        }
+    }
+
+    /// Renders the error as a message followed by the offending line, with the span underlined.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source string the error was found in.
+    ///
+    /// # Returns
+    ///
+    /// The rendered error, on three lines.
+    pub fn render(&self, source: &str) -> String {
+        match self {
+            ExpressionError::Parse(error) => error.render(source),
+            ExpressionError::Eval(error) => error.render(source),
+        }
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpressionError::Parse(error) => write!(f, "{}", error),
+            ExpressionError::Eval(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ExpressionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExpressionError::Parse(error) => Some(error),
+            ExpressionError::Eval(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseError> for ExpressionError {
+    fn from(error: ParseError) -> Self {
+        ExpressionError::Parse(error)
+    }
+}
+
+impl From<EvalError> for ExpressionError {
+    fn from(error: EvalError) -> Self {
+        ExpressionError::Eval(error)
+    }
+}
+
+/// Renders a message followed by the line of `source` holding the start of `span`, with the
+/// part of the span on that line underlined by carets.
+pub(crate) fn render(source: &str, span: &Range<usize>, message: &dyn fmt::Display) -> String {
+    let mut start = span.start.min(source.len());
+    while !source.is_char_boundary(start) {
+        start -= 1;
+    }
+    let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |index| start + index);
+    let mut end = span.end.clamp(start, line_end);
+    while !source.is_char_boundary(end) {
+        end += 1;
+    }
+
+    let line_number = source[..start].matches('\n').count() + 1;
+    let column = source[line_start..start].chars().count();
+    let width = source[start..end].chars().count().max(1);
+
+    format!(
+        "error: {} (line {}, column {})\n{}\n{}{}",
+        message,
+        line_number,
+        column + 1,
+        &source[line_start..line_end],
+        " ".repeat(column),
+        "^".repeat(width)
+    )
+}
@@ -0,0 +1,608 @@
+pub mod ast;
+pub mod error;
+pub mod token;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+use vec::storage::Contiguous;
+
+use crate::stack::Stack;
+use error::{EvalError, EvalErrorKind, ExpressionError, ParseError, ParseErrorKind};
+use token::{tokenize, Operator, TokenKind};
+
+/// A function that can be called in an expression.
+///
+/// # Variants
+///
+/// * `Min` - `min(a, b, ...)`, the smallest of one or more arguments.
+/// * `Max` - `max(a, b, ...)`, the largest of one or more arguments.
+/// * `Sqrt` - `sqrt(a)`, the square root of one argument, rounded down for integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Function {
+    Min,
+    Max,
+    Sqrt,
+}
+
+impl Function {
+    /// Looks up a function by the name used to call it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the function, or None if no function has this name.
+    pub fn from_name(name: &str) -> Option<Function> {
+        match name {
+            "min" => Some(Function::Min),
+            "max" => Some(Function::Max),
+            "sqrt" => Some(Function::Sqrt),
+            _ => None,
+        }
+    }
+
+    /// Returns the name used to call the function.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Function::Min => "min",
+            Function::Max => "max",
+            Function::Sqrt => "sqrt",
+        }
+    }
+
+    /// Checks if the function can be called with a number of arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `arity` - The number of arguments.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the function accepts `arity` arguments, `false` otherwise.
+    pub fn accepts(&self, arity: usize) -> bool {
+        match self {
+            Function::Min | Function::Max => arity >= 1,
+            Function::Sqrt => arity == 1,
+        }
+    }
+}
+
+/// A step of a [`Program`], run against a stack of values.
+///
+/// # Variants
+///
+/// * `Integer` - Pushes an integer literal.
+/// * `Decimal` - Pushes a decimal literal.
+/// * `Variable` - Pushes the value bound to a variable.
+/// * `Negate` - Replaces the top value with its negation.
+/// * `Binary` - Pops the right then the left operand, and pushes the result of the operator.
+/// * `Call` - Pops the given number of arguments and pushes the result of the function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Integer(String),
+    Decimal(f64),
+    Variable(String),
+    Negate,
+    Binary(Operator),
+    Call(Function, usize),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Integer(value) => write!(f, "{}", value),
+            Instruction::Decimal(value) => write!(f, "{:?}", value),
+            Instruction::Variable(name) => write!(f, "{}", name),
+            Instruction::Negate => write!(f, "neg"),
+            Instruction::Binary(operator) => write!(f, "{}", operator),
+            Instruction::Call(function, arity) => write!(f, "{}({})", function.name(), arity),
+        }
+    }
+}
+
+/// A value type an expression can be evaluated to.
+///
+/// Every operation fails with the [`EvalErrorKind`] explaining why when its result can't be
+/// represented, which makes the `i64` implementation fully checked: overflow (including integer
+/// literals above `i64::MAX`), division by zero, negative exponents, square roots of negative
+/// numbers and decimal literals all end the evaluation. The `f64` implementation follows IEEE 754 and never fails.
+pub trait Number: Copy + PartialOrd {
+    /// Converts an integer literal, given as its decimal digits.
+    fn from_integer(digits: &str) -> Result<Self, EvalErrorKind>;
+    /// Converts a decimal literal.
+    fn from_decimal(value: f64) -> Result<Self, EvalErrorKind>;
+    /// Negates a value.
+    fn negate(self) -> Result<Self, EvalErrorKind>;
+    /// Applies a binary operator to two values.
+    fn apply(operator: Operator, left: Self, right: Self) -> Result<Self, EvalErrorKind>;
+    /// Takes the square root of a value.
+    fn sqrt(self) -> Result<Self, EvalErrorKind>;
+}
+
+impl Number for f64 {
+    fn from_integer(digits: &str) -> Result<Self, EvalErrorKind> {
+        digits.parse().map_err(|_| EvalErrorKind::InvalidToken)
+    }
+
+    fn from_decimal(value: f64) -> Result<Self, EvalErrorKind> {
+        Ok(value)
+    }
+
+    fn negate(self) -> Result<Self, EvalErrorKind> {
+        Ok(-self)
+    }
+
+    fn apply(operator: Operator, left: Self, right: Self) -> Result<Self, EvalErrorKind> {
+        Ok(match operator {
+            Operator::Add => left + right,
+            Operator::Sub => left - right,
+            Operator::Mul => left * right,
+            Operator::Div => left / right,
+            Operator::Rem => left % right,
+            Operator::Pow => left.powf(right),
+        })
+    }
+
+    fn sqrt(self) -> Result<Self, EvalErrorKind> {
+        Ok(f64::sqrt(self))
+    }
+}
+
+impl Number for i64 {
+    fn from_integer(digits: &str) -> Result<Self, EvalErrorKind> {
+        digits.parse().map_err(|_| EvalErrorKind::Overflow)
+    }
+
+    fn from_decimal(_value: f64) -> Result<Self, EvalErrorKind> {
+        Err(EvalErrorKind::NotAnInteger)
+    }
+
+    fn negate(self) -> Result<Self, EvalErrorKind> {
+        self.checked_neg().ok_or(EvalErrorKind::Overflow)
+    }
+
+    fn apply(operator: Operator, left: Self, right: Self) -> Result<Self, EvalErrorKind> {
+        let result = match operator {
+            Operator::Add => left.checked_add(right),
+            Operator::Sub => left.checked_sub(right),
+            Operator::Mul => left.checked_mul(right),
+            Operator::Div | Operator::Rem if right == 0 => {
+                return Err(EvalErrorKind::DivisionByZero)
+            }
+            Operator::Div => left.checked_div(right),
+            Operator::Rem => left.checked_rem(right),
+            Operator::Pow => {
+                let exponent = u32::try_from(right).map_err(|_| EvalErrorKind::InvalidOperand)?;
+                left.checked_pow(exponent)
+            }
+        };
+        result.ok_or(EvalErrorKind::Overflow)
+    }
+
+    fn sqrt(self) -> Result<Self, EvalErrorKind> {
+        if self < 0 {
+            return Err(EvalErrorKind::InvalidOperand);
+        }
+        Ok(self.isqrt())
+    }
+}
+
+/// An open parenthesis met while compiling an expression.
+///
+/// # Fields
+///
+/// * `paren` - The byte range of the parenthesis.
+/// * `call` - The called function and the start of its name, if the parenthesis opens the
+///   arguments of a call.
+/// * `commas` - The number of commas met directly inside the parenthesis.
+struct Group {
+    paren: Range<usize>,
+    call: Option<(Function, usize)>,
+    commas: usize,
+}
+
+/// An entry of the operator stack used while compiling an expression, along with the byte range
+/// of its token.
+enum Pending {
+    Operator(Operator, Range<usize>),
+    Negate(Range<usize>),
+    LeftParen,
+}
+
+impl Pending {
+    /// Returns the precedence of the entry, or None for the entries operators can't pop.
+    fn precedence(&self) -> Option<u8> {
+        match self {
+            Pending::Operator(operator, _) => Some(operator.precedence()),
+            Pending::Negate(_) => Some(3),
+            Pending::LeftParen => None,
+        }
+    }
+}
+
+/// A compiled arithmetic expression, stored as a sequence of instructions in reverse Polish
+/// (postfix) order.
+///
+/// Expressions are compiled with Dijkstra's shunting-yard algorithm. They may contain integer
+/// and decimal numbers, variables, the binary operators `+ - * / % ^`, the unary minus,
+/// parentheses and calls to the functions `min`, `max` and `sqrt`. Whitespace is optional.
+///
+/// `^` binds tighter than the unary minus and groups from the right, so `-2^3^2` is
+/// `-(2^(3^2))`. `* / %` come next, then `+ -`, and both group from the left.
+///
+/// Each instruction remembers the byte range of the source string it comes from, so that
+/// evaluation errors can point at the failing operator.
+///
+/// # Fields
+///
+/// * `instructions` - The instructions of the program, in the order they run.
+/// * `spans` - The byte range of the source string each instruction comes from.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use crate::stack::expression::Program;
+///
+/// let program = Program::compile("2*(price-discount)^2 % 7").unwrap();
+/// assert_eq!(program.to_string(), "2 price discount - 2 ^ * 7 %");
+///
+/// let variables = HashMap::from([("price".to_string(), 10), ("discount".to_string(), 4)]);
+/// assert_eq!(program.eval(&variables), Ok(2 * 36 % 7));
+///
+/// let program = Program::compile("max(-x, sqrt(2.25), 1)").unwrap();
+/// let variables = HashMap::from([("x".to_string(), -3.5)]);
+/// assert_eq!(program.eval(&variables), Ok(3.5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+    spans: Vec<Range<usize>>,
+}
+
+impl Program {
+    /// Compiles an infix expression into a program.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The infix expression.
+    ///
+    /// # Returns
+    ///
+    /// The program, or a [`ParseError`] locating the first problem: an unknown character, a
+    /// missing or unexpected operand or operator, an unbalanced parenthesis, an unknown function
+    /// or a call with the wrong number of arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::expression::Program;
+    /// use crate::stack::expression::error::ParseErrorKind;
+    ///
+    /// assert_eq!(Program::compile("2^3^2").unwrap().to_string(), "2 3 2 ^ ^");
+    /// assert_eq!(Program::compile("-2^2").unwrap().to_string(), "2 2 ^ neg");
+    /// assert_eq!(Program::compile("10-4-3").unwrap().to_string(), "10 4 - 3 -");
+    /// assert_eq!(Program::compile("min(1,2,3)").unwrap().to_string(), "1 2 3 min(3)");
+    ///
+    /// let error = Program::compile("1 + * 2").unwrap_err();
+    /// assert_eq!((error.kind, error.span), (ParseErrorKind::UnexpectedToken, 4..5));
+    /// let error = Program::compile("(1 + 2))").unwrap_err();
+    /// assert_eq!((error.kind, error.span), (ParseErrorKind::UnbalancedParen, 7..8));
+    /// let error = Program::compile("foo(1)").unwrap_err();
+    /// assert_eq!(error.kind, ParseErrorKind::UnknownFunction("foo".to_string()));
+    /// let error = Program::compile("").unwrap_err();
+    /// assert_eq!((error.kind, error.span), (ParseErrorKind::UnexpectedEnd, 0..0));
+    /// ```
+    pub fn compile(source: &str) -> Result<Program, ParseError> {
+        let tokens = tokenize(source)?;
+        let mut program = Program {
+            instructions: Vec::new(),
+            spans: Vec::new(),
+        };
+        let mut pending: Stack<Pending, Contiguous> = Stack::with_storage();
+        let mut groups: Stack<Group, Contiguous> = Stack::with_storage();
+        let mut call = None;
+        let mut expect_operand = true;
+
+        for (index, token) in tokens.iter().enumerate() {
+            let span = token.span.clone();
+            let unexpected = || ParseError::new(ParseErrorKind::UnexpectedToken, span.clone());
+            let after_left_paren = index > 0 && tokens[index - 1].kind == TokenKind::LeftParen;
+            match &token.kind {
+                TokenKind::Integer(_) | TokenKind::Decimal(_) | TokenKind::Identifier(_)
+                    if !expect_operand =>
+                {
+                    return Err(unexpected());
+                }
+                TokenKind::Integer(value) => {
+                    program.emit(Instruction::Integer(value.clone()), span);
+                    expect_operand = false;
+                }
+                TokenKind::Decimal(value) => {
+                    program.emit(Instruction::Decimal(*value), span);
+                    expect_operand = false;
+                }
+                TokenKind::Identifier(name) => {
+                    let is_call = tokens
+                        .get(index + 1)
+                        .is_some_and(|next| next.kind == TokenKind::LeftParen);
+                    if is_call {
+                        let function = Function::from_name(name).ok_or_else(|| {
+                            ParseError::new(ParseErrorKind::UnknownFunction(name.clone()), span)
+                        })?;
+                        call = Some((function, token.span.start));
+                    } else {
+                        program.emit(Instruction::Variable(name.clone()), span);
+                        expect_operand = false;
+                    }
+                }
+                TokenKind::Operator(Operator::Sub) if expect_operand => {
+                    pending.push(Pending::Negate(span));
+                }
+                TokenKind::Operator(_) if expect_operand => return Err(unexpected()),
+                TokenKind::Operator(operator) => {
+                    while let Some(top) = pending.try_peek().and_then(Pending::precedence) {
+                        let pops = top > operator.precedence()
+                            || (top == operator.precedence() && !operator.is_right_associative());
+                        if !pops {
+                            break;
+                        }
+                        program.emit_pending(&mut pending);
+                    }
+                    pending.push(Pending::Operator(*operator, span));
+                    expect_operand = true;
+                }
+                TokenKind::LeftParen if !expect_operand => return Err(unexpected()),
+                TokenKind::LeftParen => {
+                    pending.push(Pending::LeftParen);
+                    groups.push(Group {
+                        paren: span,
+                        call: call.take(),
+                        commas: 0,
+                    });
+                }
+                TokenKind::Comma => {
+                    let in_call = groups.try_peek().is_some_and(|group| group.call.is_some());
+                    if expect_operand || !in_call {
+                        return Err(unexpected());
+                    }
+                    program.unwind(&mut pending);
+                    if let Some(group) = groups.try_peek_mut() {
+                        group.commas += 1;
+                    }
+                    expect_operand = true;
+                }
+                TokenKind::RightParen => {
+                    let empty = expect_operand && after_left_paren;
+                    if expect_operand && !empty {
+                        return Err(unexpected());
+                    }
+                    let group = groups.try_pop().ok_or_else(|| {
+                        ParseError::new(ParseErrorKind::UnbalancedParen, span.clone())
+                    })?;
+                    program.unwind(&mut pending);
+                    pending.try_pop();
+                    match group.call {
+                        Some((function, start)) => {
+                            let arity = if empty { 0 } else { group.commas + 1 };
+                            let span = start..token.span.end;
+                            if !function.accepts(arity) {
+                                let kind = ParseErrorKind::WrongArity {
+                                    function: function.name(),
+                                    found: arity,
+                                };
+                                return Err(ParseError::new(kind, span));
+                            }
+                            program.emit(Instruction::Call(function, arity), span);
+                        }
+                        None if empty => return Err(unexpected()),
+                        None => {}
+                    }
+                    expect_operand = false;
+                }
+            }
+        }
+
+        if expect_operand {
+            let end = source.len();
+            return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, end..end));
+        }
+        if let Some(group) = groups.try_pop() {
+            return Err(ParseError::new(
+                ParseErrorKind::UnbalancedParen,
+                group.paren,
+            ));
+        }
+        while !pending.is_empty() {
+            program.emit_pending(&mut pending);
+        }
+
+        Ok(program)
+    }
+
+    /// Appends an instruction coming from a span of the source string.
+    fn emit(&mut self, instruction: Instruction, span: Range<usize>) {
+        self.instructions.push(instruction);
+        self.spans.push(span);
+    }
+
+    /// Pops an operator from the operator stack and appends its instruction.
+    fn emit_pending(&mut self, pending: &mut Stack<Pending, Contiguous>) {
+        match pending.try_pop() {
+            Some(Pending::Operator(operator, span)) => {
+                self.emit(Instruction::Binary(operator), span)
+            }
+            Some(Pending::Negate(span)) => self.emit(Instruction::Negate, span),
+            Some(Pending::LeftParen) | None => {}
+        }
+    }
+
+    /// Moves operators to the output until the innermost open parenthesis, which stays on the
+    /// stack.
+    fn unwind(&mut self, pending: &mut Stack<Pending, Contiguous>) {
+        while pending
+            .try_peek()
+            .is_some_and(|top| top.precedence().is_some())
+        {
+            self.emit_pending(pending);
+        }
+    }
+
+    /// Returns the instructions of the program, in the order they run.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Evaluates the program.
+    ///
+    /// Evaluating to `f64` follows floating point arithmetic. Evaluating to `i64` is checked:
+    /// it fails instead of overflowing or dividing by zero, and `/` and `%` truncate toward
+    /// zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `variables` - The values of the variables used by the program.
+    ///
+    /// # Returns
+    ///
+    /// The value of the expression, or an [`EvalError`] locating the unbound variable or the
+    /// operation without a representable result (see [`Number`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use crate::stack::expression::Program;
+    /// use crate::stack::expression::error::EvalErrorKind;
+    ///
+    /// let program = Program::compile("7 / 2").unwrap();
+    /// assert_eq!(program.eval::<f64>(&HashMap::new()), Ok(3.5));
+    /// assert_eq!(program.eval::<i64>(&HashMap::new()), Ok(3));
+    ///
+    /// let program = Program::compile("1 + 2 ^ 63").unwrap();
+    /// let error = program.eval::<i64>(&HashMap::new()).unwrap_err();
+    /// assert_eq!((error.kind, error.span), (EvalErrorKind::Overflow, 6..7));
+    /// assert_eq!(program.eval::<f64>(&HashMap::new()), Ok(9223372036854775809.0));
+    ///
+    /// let program = Program::compile("-9223372036854775808").unwrap();
+    /// let error = program.eval::<i64>(&HashMap::new()).unwrap_err();
+    /// assert_eq!((error.kind, error.span), (EvalErrorKind::Overflow, 1..20));
+    /// assert_eq!(program.eval::<f64>(&HashMap::new()), Ok(-9223372036854775808.0));
+    ///
+    /// let program = Program::compile("2 * 10000000000000000000000000000000000000000000").unwrap();
+    /// assert_eq!(program.eval::<f64>(&HashMap::new()), Ok(2e43));
+    /// let error = program.eval::<i64>(&HashMap::new()).unwrap_err();
+    /// assert_eq!((error.kind, error.span), (EvalErrorKind::Overflow, 4..48));
+    ///
+    /// let program = Program::compile("x / y").unwrap();
+    /// let variables = HashMap::from([("x".to_string(), 1)]);
+    /// let error = program.eval::<i64>(&variables).unwrap_err();
+    /// assert_eq!(error.kind, EvalErrorKind::UnboundVariable("y".to_string()));
+    /// assert_eq!(error.offset(), 4);
+    /// ```
+    pub fn eval<N: Number>(&self, variables: &HashMap<String, N>) -> Result<N, EvalError> {
+        let mut values: Stack<N, Contiguous> = Stack::with_storage();
+
+        for (instruction, span) in self.instructions.iter().zip(&self.spans) {
+            let fail = |kind| EvalError::new(kind, span.clone());
+            let mut pop = || {
+                values
+                    .try_pop()
+                    .ok_or_else(|| fail(EvalErrorKind::StackUnderflow))
+            };
+            let value = match instruction {
+                Instruction::Integer(digits) => N::from_integer(digits),
+                Instruction::Decimal(value) => N::from_decimal(*value),
+                Instruction::Variable(name) => variables
+                    .get(name)
+                    .copied()
+                    .ok_or_else(|| EvalErrorKind::UnboundVariable(name.clone())),
+                Instruction::Negate => pop()?.negate(),
+                Instruction::Binary(operator) => {
+                    let right = pop()?;
+                    let left = pop()?;
+                    N::apply(*operator, left, right)
+                }
+                Instruction::Call(function, arity) => {
+                    let mut result = pop()?;
+                    for _ in 1..*arity {
+                        let argument = pop()?;
+                        let keep = match function {
+                            Function::Min => argument <= result,
+                            Function::Max => argument >= result,
+                            Function::Sqrt => false,
+                        };
+                        if keep {
+                            result = argument;
+                        }
+                    }
+                    match function {
+                        Function::Sqrt => result.sqrt(),
+                        Function::Min | Function::Max => Ok(result),
+                    }
+                }
+            };
+            values.push(value.map_err(fail)?);
+        }
+
+        let whole = 0..self.spans.iter().map(|span| span.end).max().unwrap_or(0);
+        let result = values
+            .try_pop()
+            .ok_or_else(|| EvalError::new(EvalErrorKind::StackUnderflow, whole.clone()))?;
+        if !values.is_empty() {
+            return Err(EvalError::new(EvalErrorKind::UnusedOperands, whole));
+        }
+        Ok(result)
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", instruction)?;
+        }
+        Ok(())
+    }
+}
+
+/// # Expression Evaluator
+///
+/// Compiles and evaluates an infix expression in one go.
+///
+/// # Arguments
+///
+/// * `source` - The infix expression, as accepted by [`Program::compile`].
+/// * `variables` - The values of the variables used by the expression.
+///
+/// # Returns
+///
+/// The value of the expression, or an [`ExpressionError`] locating why it couldn't be parsed
+/// or evaluated.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use crate::stack::expression::evaluate;
+///
+/// assert_eq!(evaluate::<f64>("(1+2)*3.5", &HashMap::new()), Ok(10.5));
+/// assert_eq!(evaluate::<i64>("17 % 5 - -2", &HashMap::new()), Ok(4));
+///
+/// let source = "1.5 + 1";
+/// let error = evaluate::<i64>(source, &HashMap::new()).unwrap_err();
+/// assert_eq!(error.to_string(), "not an integer at byte 0");
+/// println!("{}", error.render(source));
+/// ```
+pub fn evaluate<N: Number>(
+    source: &str,
+    variables: &HashMap<String, N>,
+) -> Result<N, ExpressionError> {
+    Ok(Program::compile(source)?.eval(variables)?)
+}
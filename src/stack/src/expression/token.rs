@@ -0,0 +1,219 @@
+use std::fmt;
+use std::ops::Range;
+
+use super::error::{ParseError, ParseErrorKind};
+
+/// An arithmetic operator of an expression.
+///
+/// `Sub` is both the binary subtraction and, in front of an operand, the unary minus.
+///
+/// # Variants
+///
+/// * `Add` - Addition, `+`.
+/// * `Sub` - Subtraction or negation, `-`.
+/// * `Mul` - Multiplication, `*`.
+/// * `Div` - Division, `/`.
+/// * `Rem` - Remainder, `%`.
+/// * `Pow` - Exponentiation, `^`, which is right associative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+impl Operator {
+    /// Returns how tightly the operator binds its operands when used as a binary operator.
+    ///
+    /// # Returns
+    ///
+    /// 1 for `+ -`, 2 for `* / %` and 4 for `^`. The unary minus binds with 3, between them.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Operator::Add | Operator::Sub => 1,
+            Operator::Mul | Operator::Div | Operator::Rem => 2,
+            Operator::Pow => 4,
+        }
+    }
+
+    /// Checks if a chain of this operator groups from the right, as `2 ^ 3 ^ 2 = 2 ^ (3 ^ 2)`.
+    ///
+    /// # Returns
+    ///
+    /// `true` for `^`, `false` otherwise.
+    pub fn is_right_associative(&self) -> bool {
+        *self == Operator::Pow
+    }
+
+    /// Returns the character of the operator.
+    pub fn symbol(&self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Sub => '-',
+            Operator::Mul => '*',
+            Operator::Div => '/',
+            Operator::Rem => '%',
+            Operator::Pow => '^',
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// The kind of a [`Token`], carrying its value.
+///
+/// # Variants
+///
+/// * `Integer` - A number without a decimal point, kept as its digits so that each number type
+///   can read it however large it is.
+/// * `Decimal` - A number with a decimal point.
+/// * `Identifier` - A variable or function name, made of letters, digits and underscores.
+/// * `Operator` - One of `+ - * / % ^`.
+/// * `LeftParen` - `(`.
+/// * `RightParen` - `)`.
+/// * `Comma` - `,`, separating the arguments of a function call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Integer(String),
+    Decimal(f64),
+    Identifier(String),
+    Operator(Operator),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+/// A token of an expression along with where it is in the source string.
+///
+/// # Fields
+///
+/// * `kind` - What the token is.
+/// * `span` - The byte range of the token in the source string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    /// What the token is.
+    pub kind: TokenKind,
+    /// The byte range of the token in the source string.
+    pub span: Range<usize>,
+}
+
+/// # Tokenizer
+///
+/// Splits an arithmetic expression into typed tokens.
+///
+/// Whitespace between tokens is optional. Numbers are runs of ASCII digits with at most one
+/// decimal point, such as `42`, `3.14` or `.5`.
+///
+/// # Arguments
+///
+/// * `source` - The expression to split.
+///
+/// # Returns
+///
+/// The tokens in order, or a [`ParseError`] locating the first character that can't start a
+/// token or the first malformed number.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::expression::error::ParseErrorKind;
+/// use crate::stack::expression::token::{tokenize, Operator, TokenKind};
+///
+/// let tokens = tokenize("12*(x-.5)").unwrap();
+/// let kinds: Vec<TokenKind> = tokens.iter().map(|token| token.kind.clone()).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         TokenKind::Integer("12".to_string()),
+///         TokenKind::Operator(Operator::Mul),
+///         TokenKind::LeftParen,
+///         TokenKind::Identifier("x".to_string()),
+///         TokenKind::Operator(Operator::Sub),
+///         TokenKind::Decimal(0.5),
+///         TokenKind::RightParen,
+///     ]
+/// );
+/// assert_eq!(tokens[3].span, 4..5);
+///
+/// let error = tokenize("2 # 3").unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::UnexpectedCharacter('#'));
+/// assert_eq!(error.span, 2..3);
+/// assert_eq!(tokenize("1.2.3").unwrap_err().kind, ParseErrorKind::InvalidNumber);
+/// ```
+pub fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let start = index;
+        let byte = bytes[index];
+        let kind = if byte.is_ascii_whitespace() {
+            index += 1;
+            continue;
+        } else if byte.is_ascii_digit() || byte == b'.' {
+            while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'.') {
+                index += 1;
+            }
+            number(&source[start..index])
+                .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidNumber, start..index))?
+        } else if byte.is_ascii_alphabetic() || byte == b'_' {
+            while index < bytes.len()
+                && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_')
+            {
+                index += 1;
+            }
+            TokenKind::Identifier(source[start..index].to_string())
+        } else {
+            let char = source[start..].chars().next().unwrap_or_default();
+            index += char.len_utf8();
+            match byte {
+                b'+' => TokenKind::Operator(Operator::Add),
+                b'-' => TokenKind::Operator(Operator::Sub),
+                b'*' => TokenKind::Operator(Operator::Mul),
+                b'/' => TokenKind::Operator(Operator::Div),
+                b'%' => TokenKind::Operator(Operator::Rem),
+                b'^' => TokenKind::Operator(Operator::Pow),
+                b'(' => TokenKind::LeftParen,
+                b')' => TokenKind::RightParen,
+                b',' => TokenKind::Comma,
+                _ => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::UnexpectedCharacter(char),
+                        start..index,
+                    ))
+                }
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: start..index,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Reads a run of digits and decimal points as a number.
+fn number(text: &str) -> Option<TokenKind> {
+    match text.bytes().filter(|&byte| byte == b'.').count() {
+        0 => Some(TokenKind::Integer(text.to_string())),
+        1 if text.len() > 1 => Some(TokenKind::Decimal(text.parse::<f64>().ok()?)),
+        _ => None,
+    }
+}
+
+/// Splits a string on whitespace, yielding each word along with its byte range.
+pub(crate) fn words(source: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    source.split_whitespace().map(move |word| {
+        let start = word.as_ptr() as usize - source.as_ptr() as usize;
+        (start..start + word.len(), word)
+    })
+}
@@ -1,4 +1,10 @@
-use crate::{balanced_parenthese::parenthese_checker_2, stack::Stack};
+use crate::{
+    expression::{
+        error::{EvalError, EvalErrorKind, ParseError, ParseErrorKind},
+        token::words,
+    },
+    stack::Stack,
+};
 
 /// # Postfix converter
 ///
@@ -8,59 +14,98 @@ use crate::{balanced_parenthese::parenthese_checker_2, stack::Stack};
 ///
 /// * `infix` - The string to be converted (characters seperated by whitespace)
 ///
+/// # Returns
+///
+/// The postfix string, or a [`ParseError`] locating the first unbalanced parenthesis or
+/// misplaced token.
+///
 /// # Examples
 ///
 /// ```
 /// use crate::stack::infix_to_postfix::infix_to_postfix;
+/// use crate::stack::expression::error::ParseErrorKind;
 ///
 /// let infix = "( 2 + 3 ) * ( 4 + 2 + 1 ) / 5 * 3";
 /// let postfix = infix_to_postfix(infix);
 /// match postfix {
-///     Some(val) => assert_eq!(val, String::from("2 3 + 4 2 + 1 + * 5 / 3 * ")),
-///     None => {
-///        println!("{infix} isn't a correct infix string");
+///     Ok(val) => assert_eq!(val, String::from("2 3 + 4 2 + 1 + * 5 / 3 * ")),
+///     Err(error) => {
+///        println!("{infix} isn't a correct infix string: {error}");
 ///     },
 /// }
+///
+/// let infix = "( 2 + 3 ) ) * 4";
+/// let error = infix_to_postfix(infix).unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::UnbalancedParen);
+/// assert_eq!(error.offset(), 10);
+/// println!("{}", error.render(infix));
 /// ```
-pub fn infix_to_postfix(infix: &str) -> Option<String> {
-    if !parenthese_checker_2(infix) {
-        return None;
-    }
-
+pub fn infix_to_postfix(infix: &str) -> Result<String, ParseError> {
     let mut op_stack = Stack::new();
     let mut postfix = Vec::new();
+    let mut expect_operand = true;
 
-    for char in infix.split_whitespace() {
-        if "0" <= char && char <= "9" {
+    for (span, char) in words(infix) {
+        let unexpected = ParseError::new(ParseErrorKind::UnexpectedToken, span.clone());
+        if char.chars().all(|c| c.is_ascii_digit()) {
+            if !expect_operand {
+                return Err(unexpected);
+            }
             postfix.push(char);
+            expect_operand = false;
         } else if "(" == char {
-            op_stack.push(char);
+            if !expect_operand {
+                return Err(unexpected);
+            }
+            op_stack.push((char, span));
         } else if ")" == char {
-            let mut top = op_stack.pop().unwrap();
-            while top != "(" {
+            if expect_operand {
+                return Err(unexpected);
+            }
+            loop {
+                match op_stack.try_pop() {
+                    Some(("(", _)) => break,
+                    Some((top, _)) => postfix.push(top),
+                    None => return Err(ParseError::new(ParseErrorKind::UnbalancedParen, span)),
+                }
+            }
+        } else if precedence(char) > 0 {
+            if expect_operand {
+                return Err(unexpected);
+            }
+            while let Some(&(top, _)) = op_stack.try_peek() {
+                if precedence(top) < precedence(char) {
+                    break;
+                }
                 postfix.push(top);
-                top = op_stack.pop().unwrap();
+                let _ = op_stack.pop();
             }
+            op_stack.push((char, span));
+            expect_operand = true;
         } else {
-            while !op_stack.is_empty() && (precedence(op_stack.peek().unwrap()) >= precedence(char))
-            {
-                postfix.push(op_stack.pop().unwrap());
-            }
-            op_stack.push(char);
+            return Err(unexpected);
         }
     }
 
-    while !op_stack.is_empty() {
-        postfix.push(op_stack.pop().unwrap());
+    if expect_operand {
+        let end = infix.len();
+        return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, end..end));
+    }
+
+    while let Some((top, span)) = op_stack.try_pop() {
+        if top == "(" {
+            return Err(ParseError::new(ParseErrorKind::UnbalancedParen, span));
+        }
+        postfix.push(top);
     }
 
     let mut postfix_str: String = "".to_string();
     for c in postfix {
-        postfix_str += &c.to_string();
+        postfix_str += c;
         postfix_str += " ";
     }
 
-    return Some(postfix_str);
+    return Ok(postfix_str);
 
     fn precedence(op: &str) -> usize {
         match op {
@@ -79,50 +124,75 @@ pub fn infix_to_postfix(infix: &str) -> Option<String> {
 ///
 /// * `postfix` - The string to be calculated the sum
 ///
+/// # Returns
+///
+/// The value of the expression, or an [`EvalError`] locating the operator missing an operand,
+/// dividing by zero or overflowing, or the invalid token.
+///
 /// # Examples
 ///
 /// ```
 /// use crate::stack::infix_to_postfix::postfix_eval;
+/// use crate::stack::expression::error::EvalErrorKind;
 ///
 /// let postfix = "2 3 + 4 2 + 1 + * 5 / 3 * ";
 /// let res = postfix_eval(postfix).unwrap();
 /// println!("Postfix Eval = {:?}", res);
 /// assert_eq!(res, 21);
 /// // Output: Res = 21
+///
+/// let error = postfix_eval("4 2 2 - /").unwrap_err();
+/// assert_eq!(error.kind, EvalErrorKind::DivisionByZero);
+/// assert_eq!(error.span, 8..9);
+///
+/// let error = postfix_eval("4 +").unwrap_err();
+/// assert_eq!(error.kind, EvalErrorKind::StackUnderflow);
 /// ```
-pub fn postfix_eval(postfix: &str) -> Option<i32> {
-    if postfix.len() < 5 {
-        return None;
-    }
-
+pub fn postfix_eval(postfix: &str) -> Result<i32, EvalError> {
     let mut op_stack = Stack::new();
 
-    for char in postfix.split_whitespace() {
-        if "0" <= char && char <= "9" {
-            op_stack.push(char.parse::<i32>().unwrap());
+    for (span, char) in words(postfix) {
+        if char.chars().all(|c| c.is_ascii_digit()) {
+            let value = char
+                .parse::<i32>()
+                .map_err(|_| EvalError::new(EvalErrorKind::Overflow, span))?;
+            op_stack.push(value);
+        } else if !matches!(char, "+" | "-" | "*" | "/") {
+            return Err(EvalError::new(EvalErrorKind::InvalidToken, span));
         } else {
-            let top1 = op_stack.pop().unwrap();
-            let top2 = op_stack.pop().unwrap();
+            let underflow = || EvalError::new(EvalErrorKind::StackUnderflow, span.clone());
+            let top1 = op_stack.try_pop().ok_or_else(underflow)?;
+            let top2 = op_stack.try_pop().ok_or_else(underflow)?;
 
-            let res = calc(char, top1, top2);
+            let res = calc(char, top1, top2).map_err(|kind| EvalError::new(kind, span))?;
             op_stack.push(res);
         }
     }
 
-    return Some(op_stack.pop().unwrap());
+    let res = op_stack.try_pop().ok_or_else(|| {
+        EvalError::new(EvalErrorKind::StackUnderflow, postfix.len()..postfix.len())
+    })?;
+    if !op_stack.is_empty() {
+        return Err(EvalError::new(
+            EvalErrorKind::UnusedOperands,
+            0..postfix.len(),
+        ));
+    }
+    return Ok(res);
 
-    fn calc(operator: &str, top1: i32, top2: i32) -> i32 {
-        match operator {
-            "+" => top1 + top2,
-            "*" => top1 * top2,
-            "-" => top2 - top1,
+    fn calc(operator: &str, top1: i32, top2: i32) -> Result<i32, EvalErrorKind> {
+        let res = match operator {
+            "+" => top2.checked_add(top1),
+            "*" => top2.checked_mul(top1),
+            "-" => top2.checked_sub(top1),
             "/" => {
                 if top1 == 0 {
-                    panic!("ZeroDivisionError!");
+                    return Err(EvalErrorKind::DivisionByZero);
                 }
-                top2 / top1
+                top2.checked_div(top1)
             }
-            _ => panic!("Invalid Operator"),
-        }
+            _ => return Err(EvalErrorKind::InvalidToken),
+        };
+        res.ok_or(EvalErrorKind::Overflow)
     }
 }
@@ -1,4 +1,10 @@
-use crate::stack::Stack;
+use crate::{
+    expression::{
+        error::{EvalError, EvalErrorKind, ParseError, ParseErrorKind},
+        token::words,
+    },
+    stack::Stack,
+};
 
 /// # Prefix converter
 ///
@@ -8,53 +14,93 @@ use crate::stack::Stack;
 ///
 /// * `infix` - The string to be converted (characters seperated by whitespace)
 ///
+/// # Returns
+///
+/// The prefix string, or a [`ParseError`] locating the last unbalanced parenthesis or
+/// misplaced token, as the string is read from the end.
+///
 /// # Examples
 ///
 /// ```
 /// use crate::stack::infix_to_prefix::infix_to_prefix;
+/// use crate::stack::expression::error::ParseErrorKind;
 ///
 /// let infix = "( 2 + 3 ) * ( 4 + 2 + 1 ) / 5 * 3";
 /// let prefix = infix_to_prefix(infix);
 /// match prefix {
-///     Some(val) => assert_eq!(val, "* / * + 2 3 + + 4 2 1 5 3"),
-///     None => {
-///        println!("{infix} isn't a correct infix string");
+///     Ok(val) => assert_eq!(val, "* / * + 2 3 + + 4 2 1 5 3"),
+///     Err(error) => {
+///        println!("{infix} isn't a correct infix string: {error}");
 ///     },
 /// }
+///
+/// let error = infix_to_prefix("( ( 2 + 3 ) * 4").unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::UnbalancedParen);
+/// assert_eq!(error.offset(), 0);
 /// ```
-pub fn infix_to_prefix(infix: &str) -> Option<String> {
+pub fn infix_to_prefix(infix: &str) -> Result<String, ParseError> {
     let mut op_stack = Stack::new();
     let mut prefix = Vec::new();
+    let mut expect_operand = true;
 
-    let src_str: Vec<&str> = infix.split_whitespace().rev().collect();
+    let src_str: Vec<_> = words(infix).collect();
 
-    for char in src_str {
-        if "0" <= char && char <= "9" {
+    for (span, char) in src_str.into_iter().rev() {
+        let unexpected = ParseError::new(ParseErrorKind::UnexpectedToken, span.clone());
+        if char.chars().all(|c| c.is_ascii_digit()) {
+            if !expect_operand {
+                return Err(unexpected);
+            }
             prefix.push(char);
+            expect_operand = false;
         } else if ")" == char {
-            op_stack.push(char);
+            if !expect_operand {
+                return Err(unexpected);
+            }
+            op_stack.push((char, span));
         } else if "(" == char {
-            let mut top = op_stack.pop().unwrap();
-            while top != ")" {
+            if expect_operand {
+                return Err(unexpected);
+            }
+            loop {
+                match op_stack.try_pop() {
+                    Some((")", _)) => break,
+                    Some((top, _)) => prefix.push(top),
+                    None => return Err(ParseError::new(ParseErrorKind::UnbalancedParen, span)),
+                }
+            }
+        } else if precedence(char) > 0 {
+            if expect_operand {
+                return Err(unexpected);
+            }
+            while let Some(&(top, _)) = op_stack.try_peek() {
+                if precedence(top) <= precedence(char) {
+                    break;
+                }
                 prefix.push(top);
-                top = op_stack.pop().unwrap();
+                let _ = op_stack.pop();
             }
+            op_stack.push((char, span));
+            expect_operand = true;
         } else {
-            while !op_stack.is_empty() && (precedence(op_stack.peek().unwrap()) > precedence(char))
-            {
-                prefix.push(op_stack.pop().unwrap());
-            }
-            op_stack.push(char);
+            return Err(unexpected);
         }
     }
 
-    while !op_stack.is_empty() {
-        prefix.push(op_stack.pop().unwrap());
+    if expect_operand {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, 0..0));
+    }
+
+    while let Some((top, span)) = op_stack.try_pop() {
+        if top == ")" {
+            return Err(ParseError::new(ParseErrorKind::UnbalancedParen, span));
+        }
+        prefix.push(top);
     }
 
     prefix.reverse();
 
-    return Some(prefix.join(" "));
+    return Ok(prefix.join(" "));
 
     // Helper function to determine precedence of operators
     fn precedence(op: &str) -> usize {
@@ -74,57 +120,81 @@ pub fn infix_to_prefix(infix: &str) -> Option<String> {
 ///
 /// * `prefix` - The string representing the prefix expression.
 ///
+/// # Returns
+///
+/// The value of the expression, or an [`EvalError`] locating the operator missing an operand,
+/// dividing by zero or overflowing, or the invalid token.
+///
 /// # Examples
 ///
 /// ```
 /// use crate::stack::infix_to_prefix::prefix_eval;
+/// use crate::stack::expression::error::EvalErrorKind;
 ///
 /// let prefix = "* / * + 2 3 + + 4 2 1 5 3";
 /// let res = prefix_eval(prefix).unwrap();
 /// println!("Prefix Eval = {:?}", res);
 /// assert_eq!(res, 21);
+///
+/// let error = prefix_eval("/ 4 - 2 2").unwrap_err();
+/// assert_eq!(error.kind, EvalErrorKind::DivisionByZero);
+/// assert_eq!(error.span, 0..1);
+///
+/// let error = prefix_eval("* 2147483647 2").unwrap_err();
+/// assert_eq!(error.kind, EvalErrorKind::Overflow);
 /// ```
-pub fn prefix_eval(prefix: &str) -> Option<i32> {
-    // Ensure the input prefix expression is valid
-    if prefix.len() < 5 {
-        return None;
-    }
-
+pub fn prefix_eval(prefix: &str) -> Result<i32, EvalError> {
     let mut op_stack = Stack::new();
 
     // Split the prefix expression into tokens and reverse their order
-    let tokens: Vec<&str> = prefix.split_whitespace().rev().collect();
+    let tokens: Vec<_> = words(prefix).collect();
 
     // Iterate through the tokens
-    for token in tokens {
+    for (span, token) in tokens.into_iter().rev() {
         // Check if the token is a numeric operand
-        if token.chars().all(char::is_numeric) {
-            op_stack.push(token.parse::<i32>().unwrap());
+        if token.chars().all(|c| c.is_ascii_digit()) {
+            let value = token
+                .parse::<i32>()
+                .map_err(|_| EvalError::new(EvalErrorKind::Overflow, span))?;
+            op_stack.push(value);
+        } else if !matches!(token, "+" | "-" | "*" | "/") {
+            return Err(EvalError::new(EvalErrorKind::InvalidToken, span));
         } else {
             // If it's an operator, pop the top two operands and calculate the result
-            let top1 = op_stack.pop().unwrap();
-            let top2 = op_stack.pop().unwrap();
-            let res = calc(token, top1, top2);
+            let underflow = || EvalError::new(EvalErrorKind::StackUnderflow, span.clone());
+            let top1 = op_stack.try_pop().ok_or_else(underflow)?;
+            let top2 = op_stack.try_pop().ok_or_else(underflow)?;
+            let res = calc(token, top1, top2).map_err(|kind| EvalError::new(kind, span))?;
             op_stack.push(res);
         }
     }
 
     // The final result is the top element of the stack
-    return Some(op_stack.pop().unwrap());
+    let res = op_stack
+        .try_pop()
+        .ok_or_else(|| EvalError::new(EvalErrorKind::StackUnderflow, 0..0))?;
+    if !op_stack.is_empty() {
+        return Err(EvalError::new(
+            EvalErrorKind::UnusedOperands,
+            0..prefix.len(),
+        ));
+    }
+    return Ok(res);
 
     // Calculates the result of an operator applied to two operands
-    fn calc(operator: &str, top1: i32, top2: i32) -> i32 {
-        match operator {
-            "+" => top1 + top2,
-            "*" => top1 * top2,
-            "-" => top1 - top2,
+    fn calc(operator: &str, top1: i32, top2: i32) -> Result<i32, EvalErrorKind> {
+        let res = match operator {
+            "+" => top1.checked_add(top2),
+            "*" => top1.checked_mul(top2),
+            "-" => top1.checked_sub(top2),
             "/" => {
-                if top1 == 0 {
-                    panic!("ZeroDivisionError!");
+                if top2 == 0 {
+                    return Err(EvalErrorKind::DivisionByZero);
                 }
-                top1 / top2
+                top1.checked_div(top2)
             }
-            _ => panic!("Invalid Operator"),
-        }
+            _ => return Err(EvalErrorKind::InvalidToken),
+        };
+        res.ok_or(EvalErrorKind::Overflow)
     }
 }
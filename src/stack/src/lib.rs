@@ -4,4 +4,7 @@ pub mod balanced_parenthese;
 pub mod number_converter;
 pub mod infix_to_postfix;
 pub mod infix_to_prefix;
+pub mod expression;
+pub mod radix;
+pub mod delimiter_checker;
 // --- endregion: modules
\ No newline at end of file
@@ -1,18 +1,22 @@
 // --- region: modules
 mod stack;
 mod balanced_parenthese;
-mod number_converter;
-mod infix_to_postfix;
-mod infix_to_prefix;
 // --- endregion: modules
 
 // --- region: imports
 use stack::Stack;
+use stack::bounded_stack::{BoundedStack, OverflowPolicy};
+use stack::min_max_stack::MinMaxStack;
+use ::stack::expression::{evaluate, Program};
+use ::stack::expression::ast::Expr;
+use ::stack::infix_to_postfix::{infix_to_postfix, postfix_eval};
+use ::stack::number_converter::{decimal_to_binary, decimal_to_heximal};
+use ::stack::radix::{convert, Radix, big_int::BigInt};
+use ::stack::delimiter_checker::{DelimiterChecker, markup::TagChecker};
+use ::stack::infix_to_prefix::{infix_to_prefix, prefix_eval};
+use std::collections::HashMap;
 use crate::{
-    balanced_parenthese::{parenthese_checker_1, parenthese_checker_2}, 
-    infix_to_postfix::{infix_to_postfix, postfix_eval}, 
-    infix_to_prefix::{infix_to_prefix, prefix_eval}, 
-    number_converter::{decimal_to_binary, decimal_to_heximal}
+    balanced_parenthese::{parenthese_checker_1, parenthese_checker_2}
 };
 // --- endregion: imports
 
@@ -24,6 +28,47 @@ fn main() {
     stack.push(1); stack.push(2);
     for i in stack.iter() { print!("i'm {}. ", i); }
     println!("so sum = {}", stack.iter().sum::<i32>());
+    println!("peek {:?}, try_peek {:?}", stack.peek(), stack.try_peek());
+    if let Ok(top) = stack.peek_mut() { *top *= 10; }
+    println!("popped {:?}, {:?}", stack.pop(), stack.pop());
+    match stack.pop() {
+        Ok(item) => println!("popped {item}"),
+        Err(error) => println!("can't pop: {error}"),
+    }
+    println!("try_pop on empty stack: {:?}", stack.try_pop());
+
+
+    // BOUNDED STACK
+    println!("\n\n***BOUNDED STACK***");
+    let mut strict = BoundedStack::new(2);
+    for i in 1..=3 {
+        match strict.push(i) {
+            Ok(_) => println!("pushed {i}, size = {}/{}", strict.size(), strict.capacity()),
+            Err(error) => println!("can't push {i}: {error}"),
+        }
+    }
+    println!("full: {}, top: {:?}", strict.is_full(), strict.peek());
+    let mut history = BoundedStack::with_policy(3, OverflowPolicy::DropOldest);
+    for edit in ["type a", "type b", "type c", "type d"] {
+        if let Ok(Some(forgotten)) = history.push(edit) { println!("forgot '{forgotten}'"); }
+    }
+    println!("undo history ({:?}): {:?}", history.policy(), history.iter().collect::<Vec<_>>());
+    while let Some(edit) = history.try_pop() { print!("undo '{edit}'. "); }
+    println!("empty: {}, pop: {:?}", history.is_empty(), history.pop());
+    history.clear();
+    println!("try_peek: {:?}", history.try_peek());
+
+
+    // MIN MAX STACK
+    println!("\n\n***MIN MAX STACK***");
+    let mut prices: MinMaxStack<i32> = [5, 3, 8].into_iter().collect();
+    prices.push(1);
+    println!("{:?} -> min {:?}, max {:?}", prices.iter().collect::<Vec<_>>(), prices.min(), prices.max());
+    println!("popped {:?}, top {:?}", prices.pop(), prices.peek());
+    println!("min {:?}, max {:?}, size {}", prices.min(), prices.max(), prices.size());
+    prices.clear();
+    println!("try_pop on empty stack: {:?}", prices.try_pop());
+    println!("empty: {}, min {:?}, try_peek {:?}", prices.is_empty(), prices.min(), prices.try_peek());
 
 
     // PARENTHESE CHECKER 1
@@ -45,6 +90,24 @@ fn main() {
     println!("{sa} balanced: {res1}, {sb} balanced: {res2}");
     // Output: (2+3){func}[abc] balanced: true, (2+3)*(3-1 balanced: false
 
+
+
+    // DELIMITER CHECKER
+    println!("\n\n***DELIMITER CHECKER***");
+    let config = "[server]\nports = [80, 443 # (http, https)\nname = \"main [eu\"\n";
+    let checker = DelimiterChecker::brackets().with_quote('"').with_escape('\\').with_line_comment("#");
+    if let Err(error) = checker.check(config) {
+        println!("{}", error.render(config));
+        println!("at {:?}, unclosed: {:?}", error.location(config), error.unclosed);
+    }
+    let markup = "<ul>\n  <li>one<br></li>\n  <li>two\n</ul>";
+    match TagChecker::html().check(markup) {
+        Ok(()) => println!("balanced markup"),
+        Err(error) => println!("{}\n{} (byte {}, kind {:?})", error.render(markup), error, error.offset(), error.kind),
+    }
+    println!("xml: {:?}", TagChecker::xml().check("<a><B></b></a>").map_err(|error| error.to_string()));
+    println!("custom: {:?}", DelimiterChecker::new().with_pair("begin", "end").check("begin begin end").map_err(|error| error.span));
+    println!("{:?}", DelimiterChecker::new().with_pair("<", ">").check(">").map_err(|error| (error.kind, error.unclosed)));
     
     // DECIMAL TO BINARY
     println!("\n\n***DECIMAL TO BINARY***");
@@ -62,19 +125,37 @@ fn main() {
     // 43 = x2B
 
 
+    // RADIX
+    println!("\n\n***RADIX***");
+    let base36 = Radix::new(36).unwrap();
+    println!("-255 in base 36 = {}, 0 in binary = {}", base36.format_i128(-255), decimal_to_binary(0));
+    println!("'zz' in base 36 = {:?}, '1021' in binary = {:?}", base36.parse_i128("zz"), Radix::binary().parse_i128("1021"));
+    if let Ok(dna) = Radix::with_alphabet("ACGT") { println!("27 in ACGT = {} (base {})", dna.format_i128(27), dna.base()); }
+    println!("-6 on 8 bits = {:?}", Radix::binary().format_twos_complement(-6, 8));
+    println!("FFFA on 16 bits = {:?}", Radix::hexadecimal().parse_twos_complement("FFFA", 16));
+    println!("10.625 in binary = {:?}, back = {:?}", Radix::binary().format_f64(10.625, 8), Radix::binary().parse_f64("1010.101"));
+    let googol = format!("1{}", "0".repeat(100));
+    println!("googol in hex = {:?}", convert(&googol, &Radix::decimal(), &Radix::hexadecimal()));
+    if let Ok(big) = googol.parse::<BigInt>() {
+        println!("googol fits in i128: {}, negative: {}, zero: {}, >= zero: {}", big.to_i128().is_some(), big.is_negative(), big.is_zero(), big >= BigInt::zero());
+        println!("-googol in base 36 = {}", base36.format_big(&-big));
+    }
+    println!("invalid base: {:?}", Radix::new(37).map(|radix| radix.base()));
+
+
     // INFIX TO POSTFIX
     println!("\n\n***INFIX TO POSTFIX***");
     let infix = "( 2 + 3 ) * ( 4 + 2 + 1 ) / 5 * 3";
     
     let postfix = infix_to_postfix(infix);
     match postfix {
-        Some(val) => { 
+        Ok(val) => { 
             println!("{infix} -> {val}");
 
             let res = postfix_eval(val.as_str()).unwrap();
             println!("Postfix Eval = {:?}", res);
         },
-        None => println!("{infix} isn't a correct infix string"),
+        Err(error) => println!("{infix} isn't a correct infix string: {error}"),
     }
     // Output: ( 2 + 3 ) * ( 4 + 2 + 1 ) / 5 * 3 -> 2 3 + 4 2 + 1 + * 5 / 5 * 
     // Output: Res = 21
@@ -84,15 +165,57 @@ fn main() {
     println!("\n\n***INFIX TO PREFIX***");
     let prefix = infix_to_prefix(infix);
     match prefix {
-        Some(val) => { 
+        Ok(val) => { 
             println!("{infix} -> {val}");
 
             let res = prefix_eval(val.as_str()).unwrap();
             println!("Prefix Eval = {:?}", res);
         },
-        None => println!("{infix} isn't a correct infix string"),
+        Err(error) => println!("{infix} isn't a correct infix string: {error}"),
     }
     // Output: ( 2 + 3 ) * ( 4 + 2 + 1 ) / 5 * 3 -> * + 2 3 / + 4 + 2 1 * 5 3
     // Output: Res = 21
 
+
+    // EXPRESSION ENGINE
+    println!("\n\n***EXPRESSION ENGINE***");
+    let source = "2*(price-discount)^2 % 7 + max(-1, sqrt(tax))";
+    if let Ok(program) = Program::compile(source) {
+        println!("{source} -> {program} ({} instructions)", program.instructions().len());
+        let prices = HashMap::from([("price".to_string(), 10.5), ("discount".to_string(), 4.0), ("tax".to_string(), 2.25)]);
+        println!("as f64 = {:?}", program.eval(&prices));
+        let prices = HashMap::from([("price".to_string(), 10), ("discount".to_string(), 4), ("tax".to_string(), 2)]);
+        println!("as i64 = {:?}", program.eval(&prices));
+    }
+    println!("-2^2 = {:?}, 2^3^2 = {:?}", evaluate::<f64>("-2^2", &HashMap::new()), evaluate::<f64>("2^3^2", &HashMap::new()));
+    println!("i64 overflow: {:?}", evaluate::<i64>("2^63", &HashMap::new()));
+    println!("malformed: {:?}", evaluate::<f64>("(1 +", &HashMap::new()));
+
+
+    // EXPRESSION ERRORS
+    println!("\n\n***EXPRESSION ERRORS***");
+    for source in ["2 * (3 + 4", "max(1, 2) 3", "sqrt(4, 9) + 1", "10 / (x - 5)"] {
+        let variables = HashMap::from([("x".to_string(), 5)]);
+        if let Err(error) = evaluate::<i64>(source, &variables) {
+            println!("{} (byte {})", error.render(source), error.span().start);
+        }
+    }
+    let infix = "( 1 + 2 ) )";
+    if let Err(error) = infix_to_postfix(infix) { println!("{}", error.render(infix)); }
+    let postfix = "1 2 + *";
+    if let Err(error) = postfix_eval(postfix) { println!("{}", error.render(postfix)); }
+
+
+    // EXPRESSION TREE
+    println!("\n\n***EXPRESSION TREE***");
+    if let Ok(expr) = Expr::parse("((x ^ 3)) - 2 * (x * (y + 0)) + 4 * 1") {
+        println!("infix: {} | prefix: {} | postfix: {}", expr.to_infix(), expr.to_prefix(), expr.to_postfix());
+        println!("folded: {}", Expr::parse("x * (2 + 3)").map(|e| e.fold_constants().to_string()).unwrap_or_default());
+        println!("simplified: {}", expr.simplify());
+        if let Some(dx) = expr.derivative("x") { println!("d/dx = {}", dx.simplify()); }
+        println!("depends on z: {}", expr.depends_on("z"));
+        print!("{}", expr.simplify().to_dot());
+    }
+    if let Ok(program) = Program::compile("-a * (b - c)") { println!("from program: {}", Expr::from(&program)); }
+
 }
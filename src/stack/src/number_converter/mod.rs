@@ -1,4 +1,4 @@
-use crate::stack::Stack;
+use crate::radix::Radix;
 
 /// # Binary converter
 ///
@@ -8,6 +8,9 @@ use crate::stack::Stack;
 ///
 /// * `dec_num` - The u32 decimal number to be converted
 ///
+/// Other bases, signed and big integers, fractions and the reverse direction are handled by
+/// [`Radix`].
+///
 /// # Examples
 ///
 /// ```
@@ -17,23 +20,10 @@ use crate::stack::Stack;
 /// let bin_str: String = decimal_to_binary(dec_1);
 ///
 /// assert_eq!(bin_str, String::from("1010"));
+/// assert_eq!(decimal_to_binary(0), String::from("0"));
 /// ```
-pub fn decimal_to_binary(mut dec_num: u32) -> String {
-    let mut rem_stack = Stack::new();
-
-    while dec_num > 0 {
-        let rem = dec_num % 2;
-        rem_stack.push(rem);
-        dec_num /= 2;
-    }
-
-    let mut bin_str = "".to_string();
-    while !rem_stack.is_empty() {
-        let rem = rem_stack.pop().unwrap().to_string();
-        bin_str += &rem;
-    }
-
-    bin_str
+pub fn decimal_to_binary(dec_num: u32) -> String {
+    Radix::binary().format_i128(dec_num as i128)
 }
 
 /// # Heximal converter
@@ -44,6 +34,9 @@ pub fn decimal_to_binary(mut dec_num: u32) -> String {
 ///
 /// * `dec_num` - The u32 decimal number to be converted
 ///
+/// Other bases, signed and big integers, fractions and the reverse direction are handled by
+/// [`Radix`].
+///
 /// # Examples
 ///
 /// ```
@@ -54,25 +47,8 @@ pub fn decimal_to_binary(mut dec_num: u32) -> String {
 /// println!("{dec_2} = x{hex_str}");
 ///
 /// assert_eq!(hex_str, String::from("2B"));
+/// assert_eq!(decimal_to_heximal(0), String::from("0"));
 /// ```
-pub fn decimal_to_heximal(mut dec_num: u32) -> String {
-    let digits = [
-        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "A", "B", "C", "D", "E", "F",
-    ];
-
-    let mut rem_stack = Stack::new();
-
-    while dec_num > 0 {
-        let rem = dec_num % digits.len() as u32;
-        rem_stack.push(rem);
-        dec_num /= digits.len() as u32;
-    }
-
-    let mut hex_str = "".to_string();
-    while !rem_stack.is_empty() {
-        let rem = rem_stack.pop().unwrap() as usize;
-        hex_str += digits[rem];
-    }
-
-    hex_str
+pub fn decimal_to_heximal(dec_num: u32) -> String {
+    Radix::hexadecimal().format_i128(dec_num as i128)
 }
@@ -0,0 +1,186 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Neg;
+use std::str::FromStr;
+
+use super::{Radix, RadixError};
+
+/// An integer of any size.
+///
+/// The magnitude is stored as base 2^32 limbs, least significant first, without trailing zero
+/// limbs, so that zero has no limbs and every value has a single representation. Only what
+/// base conversion needs is provided: building from and converting to `i128`, multiplying by
+/// and adding a small number, and dividing by a small number.
+///
+/// # Fields
+///
+/// * `negative` - Whether the integer is below zero. Always `false` for zero.
+/// * `limbs` - The magnitude of the integer, least significant limb first.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::radix::big_int::BigInt;
+///
+/// let big: BigInt = "-340282366920938463463374607431768211456".parse().unwrap();
+/// assert_eq!(big.to_i128(), None);
+/// assert_eq!(big.to_string(), "-340282366920938463463374607431768211456");
+/// assert!(big < BigInt::from(i128::MIN));
+/// assert_eq!(-BigInt::from(-42), BigInt::from(42));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    /// Creates a new big integer equal to zero.
+    ///
+    /// # Returns
+    ///
+    /// Zero.
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    /// Checks if the integer is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Checks if the integer is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Converts the integer to an `i128`.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the integer, or None if it doesn't fit in an `i128`.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .limbs
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| (acc << 32) | limb as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Multiplies the magnitude by `factor` and adds `term` to it.
+    pub(crate) fn mul_add_small(&mut self, factor: u32, term: u32) {
+        let mut carry = term as u64;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    /// Divides the magnitude by `divisor` in place.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    pub(crate) fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+
+    /// Sets the sign of a non-zero integer.
+    pub(crate) fn set_negative(&mut self, negative: bool) {
+        self.negative = negative && !self.is_zero();
+    }
+
+    /// Drops the trailing zero limbs, and the sign of zero.
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        if self.limbs.is_empty() {
+            self.negative = false;
+        }
+    }
+
+    /// Compares the magnitudes of two integers.
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut magnitude = value.unsigned_abs();
+        let mut limbs = Vec::new();
+        while magnitude > 0 {
+            limbs.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        BigInt {
+            negative: value < 0,
+            limbs,
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(mut self) -> Self::Output {
+        let negative = !self.negative;
+        self.set_negative(negative);
+        self
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.cmp_magnitude(other),
+            (true, true) => other.cmp_magnitude(self),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Radix::decimal().format_big(self))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = RadixError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Radix::decimal().parse_big(text)
+    }
+}
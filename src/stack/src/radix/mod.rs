@@ -0,0 +1,546 @@
+pub mod big_int;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use vec::storage::Contiguous;
+
+use crate::stack::Stack;
+use big_int::BigInt;
+
+/// The digits of the bases 2 to 36, in order.
+const STANDARD_DIGITS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The ways a base conversion can fail.
+///
+/// # Variants
+///
+/// * `InvalidBase` - A base outside 2..=36.
+/// * `InvalidAlphabet` - An alphabet with fewer than two digits, a repeated digit, or a `-` or
+///   `.`, which are reserved for the sign and the radix point.
+/// * `Empty` - A number without any digit.
+/// * `InvalidDigit` - A character that isn't a digit of the base, with its byte offset.
+/// * `Overflow` - A number too large for the target type or width.
+/// * `InvalidWidth` - A two's complement width outside 1..=128 bits.
+/// * `NotFinite` - An infinite or NaN floating point number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RadixError {
+    InvalidBase(u32),
+    InvalidAlphabet,
+    Empty,
+    InvalidDigit {
+        /// The invalid character.
+        digit: char,
+        /// The byte offset of the character in the parsed string.
+        offset: usize,
+    },
+    Overflow,
+    InvalidWidth(u32),
+    NotFinite,
+}
+
+impl fmt::Display for RadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RadixError::InvalidBase(base) => write!(f, "Base {} is not in 2..=36", base),
+            RadixError::InvalidAlphabet => write!(f, "Invalid alphabet"),
+            RadixError::Empty => write!(f, "Number has no digits"),
+            RadixError::InvalidDigit { digit, offset } => {
+                write!(f, "Invalid digit {:?} at byte {}", digit, offset)
+            }
+            RadixError::Overflow => write!(f, "Number is too large"),
+            RadixError::InvalidWidth(bits) => write!(f, "Width of {} bits is not in 1..=128", bits),
+            RadixError::NotFinite => write!(f, "Number is not finite"),
+        }
+    }
+}
+
+impl Error for RadixError {}
+
+/// A positional numeral system, given by its digits.
+///
+/// The standard bases 2 to 36 use the digits `0-9` then `A-Z`, and also accept lowercase
+/// letters when parsing. Custom alphabets use their own digits, matched exactly, so that for
+/// instance `"01"` is binary and `"ab"` a binary system written with `a` and `b`. Negative
+/// numbers start with `-`, and fractional parts follow a `.`.
+///
+/// Digits are produced from the least significant one by repeated division, and pushed onto a
+/// stack to be popped in reading order.
+///
+/// # Fields
+///
+/// * `digits` - The digits of the system, the digit at index `d` having the value `d`.
+/// * `values` - The value of each accepted digit.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::radix::Radix;
+///
+/// let hex = Radix::hexadecimal();
+/// assert_eq!(hex.format_i128(-255), "-FF");
+/// assert_eq!(hex.parse_i128("7fff"), Ok(32767));
+///
+/// let base36 = Radix::new(36).unwrap();
+/// assert_eq!(base36.format_i128(i128::MAX), "7KSYYIZZKUTUDZBV8AQZTECJJ");
+///
+/// let dna = Radix::with_alphabet("ACGT").unwrap();
+/// assert_eq!(dna.format_i128(27), "CGT");
+/// assert_eq!(dna.parse_i128("CGT"), Ok(27));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Radix {
+    digits: Vec<char>,
+    values: HashMap<char, u32>,
+}
+
+impl Radix {
+    /// Creates a standard numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the system, from 2 to 36.
+    ///
+    /// # Returns
+    ///
+    /// The numeral system, or [`RadixError::InvalidBase`] if the base is out of range.
+    pub fn new(base: u32) -> Result<Radix, RadixError> {
+        if !(2..=36).contains(&base) {
+            return Err(RadixError::InvalidBase(base));
+        }
+        let mut radix = Radix::with_alphabet(&STANDARD_DIGITS[..base as usize])?;
+        for (value, digit) in radix.digits.iter().enumerate() {
+            radix
+                .values
+                .insert(digit.to_ascii_lowercase(), value as u32);
+        }
+        Ok(radix)
+    }
+
+    /// Creates a numeral system with custom digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - The digits of the system, in increasing value. Its length is the base.
+    ///
+    /// # Returns
+    ///
+    /// The numeral system, or [`RadixError::InvalidAlphabet`] if the alphabet has fewer than
+    /// two digits, a repeated digit, or a `-` or `.`.
+    pub fn with_alphabet(alphabet: &str) -> Result<Radix, RadixError> {
+        let digits: Vec<char> = alphabet.chars().collect();
+        let mut values = HashMap::new();
+        for (value, &digit) in digits.iter().enumerate() {
+            if digit == '-' || digit == '.' || values.insert(digit, value as u32).is_some() {
+                return Err(RadixError::InvalidAlphabet);
+            }
+        }
+        if digits.len() < 2 {
+            return Err(RadixError::InvalidAlphabet);
+        }
+        Ok(Radix { digits, values })
+    }
+
+    /// Creates the base 2 numeral system.
+    pub fn binary() -> Radix {
+        Radix::standard(2)
+    }
+
+    /// Creates the base 10 numeral system.
+    pub fn decimal() -> Radix {
+        Radix::standard(10)
+    }
+
+    /// Creates the base 16 numeral system.
+    pub fn hexadecimal() -> Radix {
+        Radix::standard(16)
+    }
+
+    /// Creates a standard numeral system for a base known to be valid.
+    fn standard(base: u32) -> Radix {
+        Radix::new(base).expect("base is in 2..=36")
+    }
+
+    /// Returns the base of the numeral system.
+    pub fn base(&self) -> u32 {
+        self.digits.len() as u32
+    }
+
+    /// Writes an integer in the numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The integer to write.
+    ///
+    /// # Returns
+    ///
+    /// The digits of the integer, after a `-` if it is negative.
+    pub fn format_i128(&self, value: i128) -> String {
+        let digits = self.format_u128(value.unsigned_abs(), 1);
+        if value < 0 {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
+
+    /// Reads an integer written in the numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The digits of the integer, optionally after a `-`.
+    ///
+    /// # Returns
+    ///
+    /// The integer, or a [`RadixError`] if there are no digits, a character isn't a digit, or
+    /// the integer doesn't fit in an `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::radix::{Radix, RadixError};
+    ///
+    /// let binary = Radix::binary();
+    /// assert_eq!(binary.parse_i128("-101"), Ok(-5));
+    /// assert_eq!(
+    ///     binary.parse_i128("1021"),
+    ///     Err(RadixError::InvalidDigit { digit: '2', offset: 2 })
+    /// );
+    /// assert_eq!(binary.parse_i128("-"), Err(RadixError::Empty));
+    /// assert_eq!(binary.parse_i128(&"1".repeat(128)), Err(RadixError::Overflow));
+    /// ```
+    pub fn parse_i128(&self, text: &str) -> Result<i128, RadixError> {
+        let (negative, digits, offset) = split_sign(text);
+        let magnitude = self.parse_u128(digits, offset)?;
+        let value = if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        value.ok_or(RadixError::Overflow)
+    }
+
+    /// Writes an integer of any size in the numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The integer to write.
+    ///
+    /// # Returns
+    ///
+    /// The digits of the integer, after a `-` if it is negative.
+    pub fn format_big(&self, value: &BigInt) -> String {
+        let mut magnitude = value.clone();
+        let mut digit_stack: Stack<char, Contiguous> = Stack::with_storage();
+        loop {
+            digit_stack.push(self.digits[magnitude.div_rem_small(self.base()) as usize]);
+            if magnitude.is_zero() {
+                break;
+            }
+        }
+
+        let mut text = String::new();
+        if value.is_negative() {
+            text.push('-');
+        }
+        text.extend(digit_stack.into_iter());
+        text
+    }
+
+    /// Reads an integer of any size written in the numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The digits of the integer, optionally after a `-`.
+    ///
+    /// # Returns
+    ///
+    /// The integer, or a [`RadixError`] if there are no digits or a character isn't a digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::radix::Radix;
+    ///
+    /// let hex = Radix::hexadecimal();
+    /// let big = hex.parse_big("-1000000000000000000000000000000000").unwrap();
+    /// assert_eq!(big.to_string(), "-5444517870735015415413993718908291383296");
+    /// assert_eq!(Radix::new(32).unwrap().format_big(&big), "-400000000000000000000000000");
+    /// ```
+    pub fn parse_big(&self, text: &str) -> Result<BigInt, RadixError> {
+        let (negative, digits, offset) = split_sign(text);
+        if digits.is_empty() {
+            return Err(RadixError::Empty);
+        }
+
+        let mut value = BigInt::zero();
+        for (index, digit) in digits.char_indices() {
+            value.mul_add_small(self.base(), self.value_of(digit, offset + index)?);
+        }
+        value.set_negative(negative);
+        Ok(value)
+    }
+
+    /// Writes a floating point number in the numeral system, with a fractional part.
+    ///
+    /// The fractional digits are produced by repeatedly multiplying the fractional part by the
+    /// base, and are truncated, not rounded, after `max_fraction_digits` digits. They are exact
+    /// in the bases that are powers of two.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The number to write.
+    /// * `max_fraction_digits` - The maximum number of digits after the radix point.
+    ///
+    /// # Returns
+    ///
+    /// The digits of the number, after a `-` if it is negative and with a `.` before its
+    /// fractional part if it has one, or a [`RadixError`] if the number isn't finite or its
+    /// integer part doesn't fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::radix::Radix;
+    ///
+    /// assert_eq!(Radix::binary().format_f64(-10.625, 10), Ok("-1010.101".to_string()));
+    /// assert_eq!(Radix::new(3).unwrap().format_f64(0.5, 6), Ok("0.111111".to_string()));
+    /// assert_eq!(Radix::hexadecimal().format_f64(255.0, 4), Ok("FF".to_string()));
+    /// ```
+    pub fn format_f64(&self, value: f64, max_fraction_digits: usize) -> Result<String, RadixError> {
+        if !value.is_finite() {
+            return Err(RadixError::NotFinite);
+        }
+        let magnitude = value.abs();
+        if magnitude >= 2f64.powi(128) {
+            return Err(RadixError::Overflow);
+        }
+
+        let integer = magnitude.trunc();
+        let mut text = self.format_u128(integer as u128, 1);
+        let mut fraction = magnitude - integer;
+        if fraction > 0.0 && max_fraction_digits > 0 {
+            text.push('.');
+            for _ in 0..max_fraction_digits {
+                fraction *= self.base() as f64;
+                let digit = fraction.trunc();
+                text.push(self.digits[digit as usize]);
+                fraction -= digit;
+                if fraction == 0.0 {
+                    break;
+                }
+            }
+        }
+
+        if value < 0.0 {
+            text.insert(0, '-');
+        }
+        Ok(text)
+    }
+
+    /// Reads a floating point number written in the numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The digits of the number, optionally after a `-`, with an optional `.` before
+    ///   the fractional part.
+    ///
+    /// # Returns
+    ///
+    /// The number, or a [`RadixError`] if there are no digits or a character isn't a digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::radix::Radix;
+    ///
+    /// assert_eq!(Radix::binary().parse_f64("-1010.101"), Ok(-10.625));
+    /// assert_eq!(Radix::hexadecimal().parse_f64(".8"), Ok(0.5));
+    /// assert!(Radix::binary().parse_f64("1.0.1").is_err());
+    /// ```
+    pub fn parse_f64(&self, text: &str) -> Result<f64, RadixError> {
+        let (negative, digits, offset) = split_sign(text);
+        let (integer, fraction) = match digits.find('.') {
+            Some(point) => (&digits[..point], &digits[point + 1..]),
+            None => (digits, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(RadixError::Empty);
+        }
+
+        let base = self.base() as f64;
+        let mut value = 0.0;
+        for (index, digit) in integer.char_indices() {
+            value = value * base + self.value_of(digit, offset + index)? as f64;
+        }
+        let fraction_offset = offset + integer.len() + 1;
+        let mut scale = 1.0;
+        for (index, digit) in fraction.char_indices() {
+            scale /= base;
+            value += self.value_of(digit, fraction_offset + index)? as f64 * scale;
+        }
+
+        Ok(if negative { -value } else { value })
+    }
+
+    /// Writes the two's complement representation of an integer on a number of bits, as an
+    /// unsigned number in the numeral system.
+    ///
+    /// The digits are padded with the zero digit to the length of the largest number on that
+    /// many bits, so that in base 2 there is one digit per bit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The integer to write.
+    /// * `bits` - The width of the representation, from 1 to 128 bits.
+    ///
+    /// # Returns
+    ///
+    /// The digits of the representation, or a [`RadixError`] if the width is out of range or
+    /// the integer doesn't fit in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::radix::{Radix, RadixError};
+    ///
+    /// let binary = Radix::binary();
+    /// assert_eq!(binary.format_twos_complement(-6, 8), Ok("11111010".to_string()));
+    /// assert_eq!(binary.format_twos_complement(5, 8), Ok("00000101".to_string()));
+    /// assert_eq!(binary.format_twos_complement(128, 8), Err(RadixError::Overflow));
+    ///
+    /// let hex = Radix::hexadecimal();
+    /// assert_eq!(hex.format_twos_complement(-1, 32), Ok("FFFFFFFF".to_string()));
+    /// assert_eq!(hex.parse_twos_complement("FFFFFFFA", 32), Ok(-6));
+    /// ```
+    pub fn format_twos_complement(&self, value: i128, bits: u32) -> Result<String, RadixError> {
+        let mask = width_mask(bits)?;
+        let min = -1i128 << (bits - 1);
+        let max = !min;
+        if value < min || value > max {
+            return Err(RadixError::Overflow);
+        }
+        let width = self.format_u128(mask, 1).chars().count();
+        Ok(self.format_u128(value as u128 & mask, width))
+    }
+
+    /// Reads the two's complement representation of an integer on a number of bits, written
+    /// as an unsigned number in the numeral system.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The digits of the representation.
+    /// * `bits` - The width of the representation, from 1 to 128 bits.
+    ///
+    /// # Returns
+    ///
+    /// The integer, or a [`RadixError`] if the width is out of range, there are no digits, a
+    /// character isn't a digit, or the number doesn't fit in the width.
+    pub fn parse_twos_complement(&self, text: &str, bits: u32) -> Result<i128, RadixError> {
+        let mask = width_mask(bits)?;
+        let unsigned = self.parse_u128(text, 0)?;
+        if unsigned > mask {
+            return Err(RadixError::Overflow);
+        }
+        let sign_bit = 1u128 << (bits - 1);
+        // Extend the sign bit to the upper bits.
+        Ok(if unsigned & sign_bit != 0 {
+            (unsigned | !mask) as i128
+        } else {
+            unsigned as i128
+        })
+    }
+
+    /// Writes an unsigned integer, padded with the zero digit to at least `width` digits.
+    fn format_u128(&self, mut value: u128, width: usize) -> String {
+        let base = self.base() as u128;
+        let mut digit_stack: Stack<char, Contiguous> = Stack::with_storage();
+        loop {
+            digit_stack.push(self.digits[(value % base) as usize]);
+            value /= base;
+            if value == 0 && digit_stack.size() >= width {
+                break;
+            }
+        }
+        digit_stack.into_iter().collect()
+    }
+
+    /// Reads the digits of an unsigned integer starting at byte `offset` of the parsed string.
+    fn parse_u128(&self, digits: &str, offset: usize) -> Result<u128, RadixError> {
+        if digits.is_empty() {
+            return Err(RadixError::Empty);
+        }
+
+        let mut value: u128 = 0;
+        for (index, digit) in digits.char_indices() {
+            let digit = self.value_of(digit, offset + index)?;
+            value = value
+                .checked_mul(self.base() as u128)
+                .and_then(|value| value.checked_add(digit as u128))
+                .ok_or(RadixError::Overflow)?;
+        }
+        Ok(value)
+    }
+
+    /// Returns the value of a digit found at byte `offset` of the parsed string.
+    fn value_of(&self, digit: char, offset: usize) -> Result<u32, RadixError> {
+        self.values
+            .get(&digit)
+            .copied()
+            .ok_or(RadixError::InvalidDigit { digit, offset })
+    }
+}
+
+/// # Base converter
+///
+/// Converts an integer of any size from one numeral system to another.
+///
+/// # Arguments
+///
+/// * `text` - The digits of the integer in the source system, optionally after a `-`.
+/// * `from` - The source numeral system.
+/// * `to` - The target numeral system.
+///
+/// # Returns
+///
+/// The digits of the integer in the target system, or a [`RadixError`] if there are no digits
+/// or a character isn't a digit of the source system.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::radix::{convert, Radix};
+///
+/// let binary = Radix::binary();
+/// let hex = Radix::hexadecimal();
+/// assert_eq!(convert("-11111111", &binary, &hex), Ok("-FF".to_string()));
+///
+/// let googol = format!("1{}", "0".repeat(100));
+/// let base36 = convert(&googol, &Radix::decimal(), &Radix::new(36).unwrap()).unwrap();
+/// assert_eq!(convert(&base36, &Radix::new(36).unwrap(), &Radix::decimal()), Ok(googol));
+/// ```
+pub fn convert(text: &str, from: &Radix, to: &Radix) -> Result<String, RadixError> {
+    Ok(to.format_big(&from.parse_big(text)?))
+}
+
+/// Splits a leading `-` from a number.
+///
+/// # Returns
+///
+/// Whether there was a `-`, the rest of the number, and the byte offset of the rest.
+fn split_sign(text: &str) -> (bool, &str, usize) {
+    match text.strip_prefix('-') {
+        Some(digits) => (true, digits, 1),
+        None => (false, text, 0),
+    }
+}
+
+/// Returns the mask of the lowest `bits` bits, or [`RadixError::InvalidWidth`] if `bits` isn't
+/// in 1..=128.
+fn width_mask(bits: u32) -> Result<u128, RadixError> {
+    if !(1..=128).contains(&bits) {
+        return Err(RadixError::InvalidWidth(bits));
+    }
+    Ok(u128::MAX >> (128 - bits))
+}
@@ -0,0 +1,238 @@
+use deque::deque::Deque;
+use vec::error::CollectionError;
+
+/// What a [`BoundedStack`] does when an element is pushed while it is full.
+///
+/// # Variants
+///
+/// * `Reject` - The new element is refused with [`CollectionError::Full`].
+/// * `DropOldest` - The bottom (oldest) element is removed to make room for the new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// The new element is refused with [`CollectionError::Full`].
+    #[default]
+    Reject,
+    /// The bottom (oldest) element is removed to make room for the new one.
+    DropOldest,
+}
+
+/// A stack holding at most a fixed number of elements.
+///
+/// Elements are kept in a fixed capacity [`Deque`], so dropping the oldest element on overflow
+/// takes O(1) time like every other operation. This makes a `DropOldest` bounded stack a natural
+/// undo history that only remembers the latest steps.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the stack.
+///
+/// # Fields
+///
+/// * `data` - Deque storing the elements, bottom first.
+/// * `capacity` - Maximum number of elements in the stack.
+/// * `policy` - What to do when pushing onto a full stack.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::stack::bounded_stack::{BoundedStack, OverflowPolicy};
+/// use vec::error::CollectionError;
+///
+/// let mut strict = BoundedStack::new(2);
+/// strict.push(1).unwrap();
+/// strict.push(2).unwrap();
+/// assert_eq!(strict.push(3), Err(CollectionError::Full { capacity: 2 }));
+/// assert_eq!(strict.pop(), Ok(2));
+///
+/// // An undo history remembering the last three edits.
+/// let mut history = BoundedStack::with_policy(3, OverflowPolicy::DropOldest);
+/// for edit in ["type a", "type b", "type c", "type d"] {
+///     history.push(edit).unwrap();
+/// }
+/// assert_eq!(history.iter().collect::<Vec<_>>(), vec![&"type b", &"type c", &"type d"]);
+/// assert_eq!(history.pop(), Ok("type d"));
+/// ```
+#[derive(Debug)]
+pub struct BoundedStack<T> {
+    data: Deque<T>,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T> BoundedStack<T> {
+    /// Creates a new empty bounded stack that rejects elements once full.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of elements in the stack.
+    ///
+    /// # Returns
+    ///
+    /// A new empty bounded stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero, as nothing could ever be pushed.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_policy(capacity, OverflowPolicy::Reject)
+    }
+
+    /// Creates a new empty bounded stack with the given overflow policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of elements in the stack.
+    /// * `policy` - What to do when pushing onto a full stack.
+    ///
+    /// # Returns
+    ///
+    /// A new empty bounded stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero, as nothing could ever be pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use crate::stack::stack::bounded_stack::{BoundedStack, OverflowPolicy};
+    ///
+    /// let _ = BoundedStack::<i32>::with_policy(0, OverflowPolicy::DropOldest);
+    /// ```
+    pub fn with_policy(capacity: usize, policy: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        BoundedStack {
+            data: Deque::new(capacity),
+            capacity,
+            policy,
+        }
+    }
+
+    /// Pushes an element onto the stack, applying the overflow policy if the stack is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to push onto the stack.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if the element was pushed, `Ok(Some(oldest))` if it was pushed after dropping
+    /// the oldest element, or [`CollectionError::Full`] if it was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::stack::stack::bounded_stack::{BoundedStack, OverflowPolicy};
+    ///
+    /// let mut stack = BoundedStack::with_policy(2, OverflowPolicy::DropOldest);
+    /// assert_eq!(stack.push('a'), Ok(None));
+    /// assert_eq!(stack.push('b'), Ok(None));
+    /// assert_eq!(stack.push('c'), Ok(Some('a')));
+    /// ```
+    pub fn push(&mut self, item: T) -> Result<Option<T>, CollectionError> {
+        let oldest = match self.policy {
+            OverflowPolicy::DropOldest if self.is_full() => self.data.try_remove_front(),
+            _ => None,
+        };
+        self.data.add_rear(item)?;
+        Ok(oldest)
+    }
+
+    /// Pops an element from the stack.
+    ///
+    /// # Returns
+    ///
+    /// The popped element, or [`CollectionError::Empty`] if the stack is empty.
+    pub fn pop(&mut self) -> Result<T, CollectionError> {
+        self.try_pop().ok_or(CollectionError::Empty)
+    }
+
+    /// Pops an element from the stack.
+    ///
+    /// # Returns
+    ///
+    /// The popped element, or None if the stack is empty.
+    pub fn try_pop(&mut self) -> Option<T> {
+        self.data.try_remove_rear()
+    }
+
+    /// Returns a reference to the top element of the stack without removing it.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the top element of the stack, or [`CollectionError::Empty`] if the stack
+    /// is empty.
+    pub fn peek(&self) -> Result<&T, CollectionError> {
+        self.try_peek().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the top element of the stack without removing it.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the top element of the stack, or None if the stack is empty.
+    pub fn try_peek(&self) -> Option<&T> {
+        self.data.try_back()
+    }
+
+    /// Returns the maximum number of elements in the stack.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns what the stack does when pushing onto it while it is full.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Clears the stack, removing all elements.
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Checks if the stack is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the stack is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Checks if the stack is full.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the stack holds `capacity` elements, `false` otherwise.
+    pub fn is_full(&self) -> bool {
+        self.data.is_full()
+    }
+
+    /// Returns the current size of the stack.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements currently in the stack.
+    pub fn size(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Returns an iterator over the elements of the stack.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements of the stack, from the bottom to the top.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+}
+
+impl<T: Clone> Clone for BoundedStack<T> {
+    fn clone(&self) -> Self {
+        let mut stack = BoundedStack::with_policy(self.capacity, self.policy);
+        for item in self.iter() {
+            let _ = stack.data.add_rear(item.clone());
+        }
+        stack
+    }
+}
@@ -0,0 +1,219 @@
+use deque::deque::Deque;
+use vec::error::CollectionError;
+
+/// An element of a [`MinMaxStack`], along with where the extremes of the stack were when it
+/// was pushed.
+///
+/// # Fields
+///
+/// * `item` - The element.
+/// * `min` - Index of the smallest element from the bottom up to this one.
+/// * `max` - Index of the largest element from the bottom up to this one.
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    item: T,
+    min: usize,
+    max: usize,
+}
+
+/// A stack that also tracks its smallest and largest elements.
+///
+/// Each element remembers the positions of the minimum and maximum of the stack up to and
+/// including itself. Popping an element therefore restores the previous extremes for free, and
+/// `min` and `max` take O(1) time, like `push` and `pop`.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the stack.
+///
+/// # Fields
+///
+/// * `data` - Growable [`Deque`] storing the elements of the stack, bottom first, so the
+///   extremes can be looked up by index.
+///
+/// # Examples
+///
+/// ```
+/// use crate::stack::stack::min_max_stack::MinMaxStack;
+///
+/// let mut stack = MinMaxStack::new();
+/// for price in [5, 3, 8, 1] {
+///     stack.push(price);
+/// }
+/// assert_eq!(stack.min(), Some(&1));
+/// assert_eq!(stack.max(), Some(&8));
+///
+/// stack.pop().unwrap();
+/// assert_eq!(stack.min(), Some(&3));
+///
+/// stack.pop().unwrap();
+/// assert_eq!(stack.max(), Some(&5));
+/// ```
+#[derive(Debug)]
+pub struct MinMaxStack<T: Ord> {
+    data: Deque<Entry<T>>,
+}
+
+impl<T: Ord> MinMaxStack<T> {
+    /// Creates a new empty min/max stack.
+    ///
+    /// # Returns
+    ///
+    /// A new empty min/max stack.
+    pub fn new() -> Self {
+        MinMaxStack {
+            data: Deque::growable(),
+        }
+    }
+
+    /// Pushes an element onto the stack.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to push onto the stack.
+    pub fn push(&mut self, item: T) {
+        let index = self.data.size();
+        let (min, max) = match self.data.try_back() {
+            Some(top) => {
+                let min = if &item < self.item(top.min) {
+                    index
+                } else {
+                    top.min
+                };
+                let max = if &item > self.item(top.max) {
+                    index
+                } else {
+                    top.max
+                };
+                (min, max)
+            }
+            None => (index, index),
+        };
+        let _ = self.data.add_rear(Entry { item, min, max });
+    }
+
+    /// Pops an element from the stack.
+    ///
+    /// # Returns
+    ///
+    /// The popped element, or [`CollectionError::Empty`] if the stack is empty.
+    pub fn pop(&mut self) -> Result<T, CollectionError> {
+        self.try_pop().ok_or(CollectionError::Empty)
+    }
+
+    /// Pops an element from the stack.
+    ///
+    /// # Returns
+    ///
+    /// The popped element, or None if the stack is empty.
+    pub fn try_pop(&mut self) -> Option<T> {
+        self.data.try_remove_rear().map(|entry| entry.item)
+    }
+
+    /// Returns a reference to the top element of the stack without removing it.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the top element of the stack, or [`CollectionError::Empty`] if the stack
+    /// is empty.
+    pub fn peek(&self) -> Result<&T, CollectionError> {
+        self.try_peek().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the top element of the stack without removing it.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the top element of the stack, or None if the stack is empty.
+    pub fn try_peek(&self) -> Option<&T> {
+        self.data.try_back().map(|entry| &entry.item)
+    }
+
+    /// Returns the smallest element of the stack in O(1) time.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the smallest element (the oldest one on ties), or None if the stack is
+    /// empty.
+    pub fn min(&self) -> Option<&T> {
+        self.data.try_back().map(|top| self.item(top.min))
+    }
+
+    /// Returns the largest element of the stack in O(1) time.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the largest element (the oldest one on ties), or None if the stack is
+    /// empty.
+    pub fn max(&self) -> Option<&T> {
+        self.data.try_back().map(|top| self.item(top.max))
+    }
+
+    /// Clears the stack, removing all elements.
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Checks if the stack is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the stack is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the current size of the stack.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements currently in the stack.
+    pub fn size(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Returns an iterator over the elements of the stack.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements of the stack, from the bottom to the top.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter().map(|entry| &entry.item)
+    }
+
+    /// Returns a reference to the element at `index`, counted from the bottom of the stack.
+    ///
+    /// Entries only record indices of elements below them, so `index` is always in bounds.
+    fn item(&self, index: usize) -> &T {
+        let entry = self.data.get(index).expect("extremes are below the top");
+        &entry.item
+    }
+}
+
+impl<T: Ord + Clone> Clone for MinMaxStack<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: Ord> Default for MinMaxStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for MinMaxStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinMaxStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = MinMaxStack::new();
+        stack.extend(iter);
+        stack
+    }
+}
@@ -1,12 +1,15 @@
-use std::fmt::Debug;
+pub mod bounded_stack;
+pub mod min_max_stack;
 
-use vec::vec::LinkedVec;
+use vec::error::CollectionError;
+use vec::storage::{Linked, Sequence, Storage};
 
 /// A generic stack data structure implemented using a vector.
 ///
 /// # Generic Parameters
 ///
 /// * `T` - Type of elements stored in the stack.
+/// * `S` - The [`Storage`] backing the stack, [`Linked`] by default.
 ///
 /// # Fields
 ///
@@ -28,20 +31,68 @@ use vec::vec::LinkedVec;
 ///
 /// assert_eq!(popped, Ok(2));
 /// ```
+///
+/// Handling an empty stack:
+///
+/// ```
+/// use crate::stack::stack::Stack;
+/// use vec::error::CollectionError;
+///
+/// fn add_top_two(stack: &mut Stack<i32>) -> Result<i32, CollectionError> {
+///     Ok(stack.pop()? + stack.pop()?)
+/// }
+///
+/// let mut stack = Stack::new();
+/// stack.push(1);
+/// assert_eq!(add_top_two(&mut stack), Err(CollectionError::Empty));
+///
+/// assert_eq!(stack.try_pop(), None);
+/// assert_eq!(stack.try_peek(), None);
+/// ```
+///
+/// Using contiguous storage instead of a linked vector:
+///
+/// ```
+/// use crate::stack::stack::Stack;
+/// use vec::storage::Contiguous;
+///
+/// let mut stack: Stack<i32, Contiguous> = Stack::with_storage();
+/// stack.push(1);
+/// stack.push(2);
+///
+/// assert_eq!(stack.peek(), Ok(&2));
+/// ```
 #[derive(Debug)]
-pub struct Stack<T> {
-    data: LinkedVec<T>,
+pub struct Stack<T, S: Storage = Linked> {
+    data: S::Vec<T>,
 }
 
-impl<T: Debug + Clone + PartialEq> Stack<T> {
-    /// Creates a new empty stack.
+impl<T> Stack<T> {
+    /// Creates a new empty stack backed by a linked vector.
     ///
     /// # Returns
     ///
     /// A new empty stack.
     pub fn new() -> Self {
+        Self::with_storage()
+    }
+}
+
+impl<T, S: Storage> Default for Stack<T, S> {
+    fn default() -> Self {
+        Self::with_storage()
+    }
+}
+
+impl<T, S: Storage> Stack<T, S> {
+    /// Creates a new empty stack backed by the storage `S`.
+    ///
+    /// # Returns
+    ///
+    /// A new empty stack.
+    pub fn with_storage() -> Self {
         Stack {
-            data: LinkedVec::new(),
+            data: S::Vec::new(),
         }
     }
 
@@ -58,31 +109,56 @@ impl<T: Debug + Clone + PartialEq> Stack<T> {
     ///
     /// # Returns
     ///
-    /// The popped element, or an error if the stack is empty.
-    pub fn pop(&mut self) -> Result<T, &'static str> {
-        self.data.pop().ok_or("Stack is empty")
+    /// The popped element, or [`CollectionError::Empty`] if the stack is empty.
+    pub fn pop(&mut self) -> Result<T, CollectionError> {
+        self.try_pop().ok_or(CollectionError::Empty)
+    }
+
+    /// Pops an element from the stack.
+    ///
+    /// # Returns
+    ///
+    /// The popped element, or None if the stack is empty.
+    pub fn try_pop(&mut self) -> Option<T> {
+        self.data.pop()
     }
 
     /// Returns a reference to the top element of the stack without removing it.
     ///
     /// # Returns
     ///
-    /// A reference to the top element of the stack, or an error if the stack is empty.
-    pub fn peek(&self) -> Result<&T, &'static str> {
-        self.data
-            .peek()
-            .map_or(Err("Stack is empty"), |item| Ok(item))
+    /// A reference to the top element of the stack, or [`CollectionError::Empty`] if the stack
+    /// is empty.
+    pub fn peek(&self) -> Result<&T, CollectionError> {
+        self.try_peek().ok_or(CollectionError::Empty)
+    }
+
+    /// Returns a reference to the top element of the stack without removing it.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the top element of the stack, or None if the stack is empty.
+    pub fn try_peek(&self) -> Option<&T> {
+        self.data.peek()
+    }
+
+    /// Returns a mutable reference to the top element of the stack without removing it.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the top element of the stack, or [`CollectionError::Empty`] if the
+    /// stack is empty.
+    pub fn peek_mut(&mut self) -> Result<&mut T, CollectionError> {
+        self.try_peek_mut().ok_or(CollectionError::Empty)
     }
 
     /// Returns a mutable reference to the top element of the stack without removing it.
     ///
     /// # Returns
     ///
-    /// A mutable reference to the top element of the stack, or an error if the stack is empty.
-    pub fn peek_mut(&mut self) -> Result<&mut T, &'static str> {
-        self.data
-            .peek_mut()
-            .map_or(Err("Stack is empty"), |item| Ok(item))
+    /// A mutable reference to the top element of the stack, or None if the stack is empty.
+    pub fn try_peek_mut(&mut self) -> Option<&mut T> {
+        self.data.peek_mut()
     }
 
     /// Clears the stack, removing all elements.
@@ -131,7 +207,7 @@ impl<T: Debug + Clone + PartialEq> Stack<T> {
     /// # Returns
     ///
     /// An iterator consuming the stack and yielding its elements in reverse order.
-    pub fn into_iter(self) -> impl Iterator<Item = T> {
-        self.data.into_iter()
+    pub fn into_iter(mut self) -> impl Iterator<Item = T> {
+        std::iter::from_fn(move || self.try_pop())
     }
 }
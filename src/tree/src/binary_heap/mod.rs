@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
-use vec::{linked_vec, vec::LinkedVec};
+use vec::storage::{Linked, Sequence, Storage};
+use vec::vec::LinkedVec;
 
 macro_rules! parent {
     ($index:expr) => {
@@ -25,6 +26,7 @@ macro_rules! right_child {
 /// # Generic Parameters
 ///
 /// * `T` - Type of elements stored in the binary heap.
+/// * `S` - The [`Storage`] holding the heap array, [`Linked`] by default.
 ///
 /// # Examples
 /// ```
@@ -55,14 +57,29 @@ macro_rules! right_child {
 /// assert_eq!(heap.size(), 9);
 ///
 /// ```
+///
+/// Using contiguous storage, which makes every parent/child access O(1):
+///
+/// ```
+/// use crate::tree::binary_heap::BinaryHeap;
+/// use vec::storage::Contiguous;
+///
+/// let mut heap: BinaryHeap<i32, Contiguous> = BinaryHeap::with_storage();
+/// heap.push(10);
+/// heap.push(4);
+/// heap.push(15);
+///
+/// assert_eq!(heap.pop(), Some(4));
+/// assert_eq!(heap.min(), Some(&10));
+/// ```
 #[derive(Debug)]
-pub struct BinaryHeap<T> {
-    data: LinkedVec<T>,
+pub struct BinaryHeap<T, S: Storage = Linked> {
+    data: S::Vec<T>,
     size: usize,
 }
 
 impl<T: Debug + Default + Ord + Clone> BinaryHeap<T> {
-    /// Creates a new empty binary heap.
+    /// Creates a new empty binary heap backed by a linked vector.
     ///
     /// # Examples
     ///
@@ -73,10 +90,33 @@ impl<T: Debug + Default + Ord + Clone> BinaryHeap<T> {
     /// let heap: BinaryHeap<i32> = BinaryHeap::new();
     /// ```
     pub fn new() -> Self {
-        BinaryHeap {
-            data: linked_vec![T::default()],
-            size: 0,
-        }
+        Self::with_storage()
+    }
+}
+
+impl<T: Debug + Default + Ord + Clone, S: Storage> Default for BinaryHeap<T, S> {
+    fn default() -> Self {
+        Self::with_storage()
+    }
+}
+
+impl<T: Debug + Default + Ord + Clone, S: Storage> BinaryHeap<T, S> {
+    /// Creates a new empty binary heap backed by the storage `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::tree::binary_heap::BinaryHeap;
+    /// use vec::storage::Contiguous;
+    ///
+    /// let heap: BinaryHeap<i32, Contiguous> = BinaryHeap::with_storage();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn with_storage() -> Self {
+        // Index 0 holds a placeholder so that children of `i` live at `2i` and `2i + 1`.
+        let mut data = S::Vec::new();
+        data.push(T::default());
+        BinaryHeap { data, size: 0 }
     }
 
     /// Returns the size of the binary heap.
@@ -0,0 +1,660 @@
+use std::fmt::Debug;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+
+use raw_vec::RawVec;
+
+pub mod raw_vec;
+pub mod ring_buf;
+
+/// Creates an [`ArrayVec`] from a list of elements, or from an element repeated `size` times.
+///
+/// Like `vec!`, the repeated element is evaluated once and cloned.
+///
+/// # Examples
+///
+/// ```
+/// use vec::array_vec;
+///
+/// let vec = array_vec![1, 2, 3];
+/// assert_eq!(&vec[..], &[1, 2, 3]);
+///
+/// let mut calls = 0;
+/// let vec = array_vec![{ calls += 1; String::from("a") }; 3];
+/// assert_eq!(&vec[..], &["a", "a", "a"]);
+/// assert_eq!(calls, 1);
+/// ```
+#[macro_export]
+macro_rules! array_vec {
+    ($($elem:expr),* $(,)?) => {{
+        let mut vec = $crate::array_vec::ArrayVec::new();
+        $(
+            vec.push($elem);
+        )*
+        vec
+    }};
+    ($elem:expr; $size:expr) => {{
+        // The element and the size are evaluated once, the element being cloned for all but
+        // the last position, which takes it.
+        let elem = $elem;
+        let size: usize = $size;
+        let mut vec = $crate::array_vec::ArrayVec::with_capacity(size);
+        for _ in 1..size {
+            vec.push(elem.clone());
+        }
+        if size > 0 {
+            vec.push(elem);
+        }
+        vec
+    }};
+}
+
+/// Represents a growable vector storing its elements contiguously in a [`RawVec`] buffer.
+///
+/// Unlike [`LinkedVec`](crate::vec::LinkedVec), indexing is O(1), pushing to the end is
+/// amortized O(1) and the elements share cache lines. `ArrayVec` dereferences to `[T]`,
+/// so every slice method (`iter`, `sort`, `binary_search_by`, ...) is available on it.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the vector.
+///
+/// # Fields
+///
+/// * `buf` - The raw buffer holding the elements.
+/// * `len` - The number of initialized elements at the start of `buf`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::array_vec::ArrayVec;
+///
+/// let mut vec: ArrayVec<i32> = ArrayVec::new();
+/// vec.push(1);
+/// vec.push(2);
+/// vec.push(3);
+/// let last = vec.pop();
+///
+/// assert_eq!(vec.len(), 2);
+/// assert_eq!(last, Some(3));
+/// assert_eq!(vec[1], 2);
+/// assert_eq!(&vec[..], &[1, 2]);
+/// ```
+pub struct ArrayVec<T> {
+    /// The raw buffer holding the elements.
+    buf: RawVec<T>,
+    /// The number of initialized elements at the start of `buf`.
+    len: usize,
+}
+
+impl<T> ArrayVec<T> {
+    /// Creates a new empty array vector without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let vec: ArrayVec<i32> = ArrayVec::new();
+    /// assert_eq!(vec.len(), 0);
+    /// assert_eq!(vec.capacity(), 0);
+    /// ```
+    pub fn new() -> Self {
+        ArrayVec {
+            buf: RawVec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates a new empty array vector with room for at least `capacity` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of elements that can be pushed without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::with_capacity(10);
+    /// for i in 0..10 {
+    ///     vec.push(i);
+    /// }
+    /// assert_eq!(vec.capacity(), 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArrayVec {
+            buf: RawVec::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements the vector can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Reserves room for at least `additional` more elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - The number of extra elements that must fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// vec.reserve(10);
+    /// assert!(vec.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    /// Shrinks the capacity of the vector to match its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::with_capacity(10);
+    /// vec.push(1);
+    /// vec.shrink_to_fit();
+    /// assert_eq!(vec.capacity(), 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.shrink_to(self.len);
+    }
+
+    /// Pushes an element onto the end of the vector in amortized O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to be pushed onto the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    pub fn push(&mut self, data: T) {
+        if self.len == self.buf.capacity() {
+            self.buf.reserve(self.len, 1);
+        }
+        // SAFETY: the slot at `len` is within capacity and uninitialized.
+        unsafe { ptr::write(self.buf.ptr().add(self.len), data) };
+        self.len += 1;
+    }
+
+    /// Pops the last element of the vector.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the popped data, or None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// assert_eq!(vec.pop(), Some(1));
+    /// assert_eq!(vec.is_empty(), true);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            // SAFETY: the slot at the old `len - 1` was initialized and is now outside `len`.
+            Some(unsafe { ptr::read(self.buf.ptr().add(self.len)) })
+        }
+    }
+
+    /// Inserts an element at the specified index, shifting the following elements to the right.
+    ///
+    /// If the specified index is greater than the length of the vector, the element will be inserted at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index at which to insert the element.
+    /// * `data` - The element to be inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// vec.push(3);
+    ///
+    /// vec.insert(1, 2);
+    /// vec.insert(10, 4);
+    ///
+    /// assert_eq!(&vec[..], &[1, 2, 3, 4]);
+    /// ```
+    pub fn insert(&mut self, mut index: usize, data: T) {
+        if self.len < index {
+            index = self.len;
+        }
+        if self.len == self.buf.capacity() {
+            self.buf.reserve(self.len, 1);
+        }
+
+        // SAFETY: `index <= len < capacity`, so shifting `len - index` elements by one stays in bounds.
+        unsafe {
+            let slot = self.buf.ptr().add(index);
+            ptr::copy(slot, slot.add(1), self.len - index);
+            ptr::write(slot, data);
+        }
+        self.len += 1;
+    }
+
+    /// Removes the element at the specified index, shifting the following elements to the left.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be removed.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    ///
+    /// assert_eq!(vec.remove(1), Some(2));
+    /// assert_eq!(vec.remove(5), None);
+    /// assert_eq!(&vec[..], &[1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if self.len <= index {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: `index < old len`, so the slot is initialized and the tail copy stays in bounds.
+        unsafe {
+            let slot = self.buf.ptr().add(index);
+            let data = ptr::read(slot);
+            ptr::copy(slot.add(1), slot, self.len - index);
+            Some(data)
+        }
+    }
+
+    /// Returns a reference to the last element in the vector, or None if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// assert_eq!(vec.peek(), None);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.peek(), Some(&2));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns a mutable reference to the last element in the vector, or None if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// if let Some(data) = vec.peek_mut() {
+    ///     *data = 2;
+    /// }
+    /// assert_eq!(vec.peek(), Some(&2));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// Returns a reference to the element at the specified index in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be found.
+    ///
+    /// # Returns
+    ///
+    /// The element at the specified index, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    ///
+    /// assert_eq!(vec.find(1), Some(&2));
+    /// assert_eq!(vec.find(2), None);
+    /// ```
+    pub fn find(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at the specified index in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be found.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the element at the specified index, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// if let Some(data) = vec.find_mut(0) {
+    ///     *data = 4;
+    /// }
+    /// assert_eq!(vec.find(0), Some(&4));
+    /// ```
+    pub fn find_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Checks if the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the current length.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of elements to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    /// use crate::vec::array_vec;
+    ///
+    /// let mut vec = array_vec![1, 2, 3, 4];
+    /// vec.truncate(2);
+    /// assert_eq!(&vec[..], &[1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail = ptr::slice_from_raw_parts_mut(
+            // SAFETY: `len < self.len <= capacity`.
+            unsafe { self.buf.ptr().add(len) },
+            self.len - len,
+        );
+        // Shrink first so a panicking destructor cannot cause a double drop.
+        self.len = len;
+        // SAFETY: the tail slots were initialized and are no longer reachable through `len`.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Clears the vector, removing all elements but keeping the allocated capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::new();
+    /// vec.push(1);
+    /// vec.clear();
+    /// assert_eq!(vec.is_empty(), true);
+    /// assert!(vec.capacity() >= 1);
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Moves all the elements of `other` to the end of this vector, leaving `other` empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector whose elements will be moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec;
+    ///
+    /// let mut vec1 = array_vec![1, 2];
+    /// let mut vec2 = array_vec![3, 4];
+    /// vec1.append(&mut vec2);
+    ///
+    /// assert_eq!(&vec1[..], &[1, 2, 3, 4]);
+    /// assert_eq!(vec2.len(), 0);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.buf.reserve(self.len, other.len);
+        // SAFETY: room for `other.len` elements was reserved and the two buffers never overlap.
+        // `other` forgets its elements afterwards, so each value keeps exactly one owner.
+        unsafe {
+            ptr::copy_nonoverlapping(other.buf.ptr(), self.buf.ptr().add(self.len), other.len);
+        }
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Extracts a slice containing the entire vector.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` slots are initialized and the pointer is aligned and non-null.
+        unsafe { slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    /// Extracts a mutable slice containing the entire vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` slots are initialized and uniquely borrowed through `self`.
+        unsafe { slice::from_raw_parts_mut(self.buf.ptr(), self.len) }
+    }
+}
+
+impl<T> Default for ArrayVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for ArrayVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for ArrayVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone> Clone for ArrayVec<T> {
+    fn clone(&self) -> Self {
+        let mut vec = ArrayVec::with_capacity(self.len);
+        for data in self.iter() {
+            vec.push(data.clone());
+        }
+        vec
+    }
+}
+
+impl<T: Debug> Debug for ArrayVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArrayVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for ArrayVec<T> {}
+
+impl<T> FromIterator<T> for ArrayVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = ArrayVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T> Extend<T> for ArrayVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for data in iter {
+            self.push(data);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArrayVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArrayVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for ArrayVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the array vector and returns an iterator over its elements, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec;
+    ///
+    /// let vec = array_vec![1, 2, 3];
+    /// let mut iter = vec.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let vec = ManuallyDrop::new(self);
+        // SAFETY: `vec` is never dropped, so the buffer moves into the iterator exactly once.
+        let buf = unsafe { ptr::read(&vec.buf) };
+        IntoIter {
+            buf,
+            start: 0,
+            end: vec.len,
+        }
+    }
+}
+
+/// Consuming iterator over the elements of an array vector.
+pub struct IntoIter<T> {
+    buf: RawVec<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            // SAFETY: slots in `start..end` are initialized and each is read exactly once.
+            let data = unsafe { ptr::read(self.buf.ptr().add(self.start)) };
+            self.start += 1;
+            Some(data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            // SAFETY: slots in `start..end` are initialized and each is read exactly once.
+            Some(unsafe { ptr::read(self.buf.ptr().add(self.end)) })
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// Custom drop implementation for releasing the elements not yet yielded.
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+/// Custom drop implementation for dropping the stored elements before the buffer is freed.
+impl<T> Drop for ArrayVec<T> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
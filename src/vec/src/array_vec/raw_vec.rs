@@ -0,0 +1,221 @@
+use std::alloc::{self, Layout};
+use std::mem;
+use std::ptr::NonNull;
+
+/// Represents a raw, uninitialized heap buffer able to hold `cap` values of type `T`.
+///
+/// `RawVec` only manages the allocation: it never reads, writes or drops the values
+/// living in the buffer. Tracking which slots are initialized is up to the owner
+/// (see [`ArrayVec`](super::ArrayVec)).
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements the buffer is sized for.
+///
+/// # Fields
+///
+/// * `ptr` - Pointer to the start of the buffer (dangling while nothing is allocated).
+/// * `cap` - The number of elements the buffer can hold.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::array_vec::raw_vec::RawVec;
+///
+/// let mut buf: RawVec<i32> = RawVec::new();
+/// assert_eq!(buf.capacity(), 0);
+///
+/// buf.reserve(0, 10);
+/// assert!(buf.capacity() >= 10);
+/// ```
+#[derive(Debug)]
+pub struct RawVec<T> {
+    /// Pointer to the start of the buffer.
+    ptr: NonNull<T>,
+    /// The number of elements the buffer can hold.
+    cap: usize,
+}
+
+// `RawVec` owns its buffer, so it is as thread safe as the values it stores.
+unsafe impl<T: Send> Send for RawVec<T> {}
+unsafe impl<T: Sync> Sync for RawVec<T> {}
+
+impl<T> RawVec<T> {
+    /// Creates an empty buffer without allocating.
+    ///
+    /// Zero-sized types never need memory, so their capacity is `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::raw_vec::RawVec;
+    ///
+    /// let buf: RawVec<u8> = RawVec::new();
+    /// assert_eq!(buf.capacity(), 0);
+    ///
+    /// let zst: RawVec<()> = RawVec::new();
+    /// assert_eq!(zst.capacity(), usize::MAX);
+    /// ```
+    pub fn new() -> Self {
+        let cap = if mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            0
+        };
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap,
+        }
+    }
+
+    /// Creates a buffer able to hold at least `capacity` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of elements to allocate room for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::array_vec::raw_vec::RawVec;
+    ///
+    /// let buf: RawVec<u64> = RawVec::with_capacity(8);
+    /// assert_eq!(buf.capacity(), 8);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut buf = RawVec::new();
+        buf.reserve_exact(0, capacity);
+        buf
+    }
+
+    /// Returns the number of elements the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns a raw pointer to the start of the buffer.
+    ///
+    /// The pointer is dangling (but well aligned) while nothing is allocated.
+    pub fn ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Ensures the buffer can hold `len + additional` elements, growing it
+    /// geometrically so that repeated calls are amortized O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of initialized elements currently stored in the buffer.
+    /// * `additional` - The number of extra elements that must fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `isize::MAX` bytes.
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        let required = len.checked_add(additional).expect("Capacity overflow");
+        if required <= self.cap {
+            return;
+        }
+
+        let doubled = self.cap.saturating_mul(2);
+        self.grow_to(required.max(doubled).max(4));
+    }
+
+    /// Ensures the buffer can hold exactly `len + additional` elements,
+    /// without over-allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of initialized elements currently stored in the buffer.
+    /// * `additional` - The number of extra elements that must fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `isize::MAX` bytes.
+    pub fn reserve_exact(&mut self, len: usize, additional: usize) {
+        let required = len.checked_add(additional).expect("Capacity overflow");
+        if required > self.cap {
+            self.grow_to(required);
+        }
+    }
+
+    /// Shrinks the buffer so it holds exactly `capacity` elements.
+    ///
+    /// Values stored beyond `capacity` must already have been moved out or dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The new capacity, which must not exceed the current one.
+    pub fn shrink_to(&mut self, capacity: usize) {
+        if mem::size_of::<T>() == 0 || capacity >= self.cap {
+            return;
+        }
+
+        if capacity == 0 {
+            // SAFETY: `cap > 0`, so `ptr` was allocated with this exact layout.
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Self::layout(self.cap)) };
+            self.ptr = NonNull::dangling();
+            self.cap = 0;
+            return;
+        }
+
+        let new_layout = Self::layout(capacity);
+        // SAFETY: `ptr` was allocated with `layout(cap)` and the new size is non-zero.
+        let new_ptr = unsafe {
+            alloc::realloc(
+                self.ptr.as_ptr() as *mut u8,
+                Self::layout(self.cap),
+                new_layout.size(),
+            )
+        };
+        self.ptr = NonNull::new(new_ptr as *mut T)
+            .unwrap_or_else(|| alloc::handle_alloc_error(new_layout));
+        self.cap = capacity;
+    }
+
+    fn grow_to(&mut self, capacity: usize) {
+        // Zero-sized types already report a capacity of `usize::MAX`.
+        assert!(mem::size_of::<T>() != 0, "Capacity overflow");
+
+        let new_layout = Self::layout(capacity);
+        let new_ptr = if self.cap == 0 {
+            // SAFETY: `capacity > cap >= 0` and `T` is not zero-sized, so the layout is non-zero.
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            // SAFETY: `ptr` was allocated with `layout(cap)` and the new size is non-zero.
+            unsafe {
+                alloc::realloc(
+                    self.ptr.as_ptr() as *mut u8,
+                    Self::layout(self.cap),
+                    new_layout.size(),
+                )
+            }
+        };
+
+        self.ptr = NonNull::new(new_ptr as *mut T)
+            .unwrap_or_else(|| alloc::handle_alloc_error(new_layout));
+        self.cap = capacity;
+    }
+
+    fn layout(capacity: usize) -> Layout {
+        let layout = Layout::array::<T>(capacity).expect("Capacity overflow");
+        assert!(layout.size() <= isize::MAX as usize, "Capacity overflow");
+        layout
+    }
+}
+
+impl<T> Default for RawVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Custom drop implementation for releasing the buffer (but not its contents).
+impl<T> Drop for RawVec<T> {
+    fn drop(&mut self) {
+        if self.cap != 0 && mem::size_of::<T>() != 0 {
+            // SAFETY: a non-zero capacity for a sized `T` means `ptr` was allocated with this layout.
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Self::layout(self.cap)) };
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+use super::raw_vec::RawVec;
+use crate::error::CollectionError;
+
+/// Represents a circular buffer of elements, the storage shared by the queue and the deque of
+/// this workspace.
+///
+/// The elements live in a single [`RawVec`], from the slot at `head` on, wrapping around to the
+/// start of the buffer when they reach its end, so that adding and removing elements at either
+/// end is O(1), as is reading any element by its position from the front. The buffer is only
+/// allocated once an element is added, and doubles whenever it is full: a fixed ring buffer
+/// stops growing at the capacity it was created with, while a growable one has no limit.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the ring buffer.
+///
+/// # Fields
+///
+/// * `buf` - The buffer storing the elements.
+/// * `head` - The index of the slot holding the front element.
+/// * `len` - The number of elements in the ring buffer.
+/// * `bound` - The maximum capacity of a fixed ring buffer, or None for a growable one.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::array_vec::ring_buf::RingBuf;
+/// use vec::error::CollectionError;
+///
+/// let mut ring = RingBuf::new(3);
+/// ring.push_back(2).unwrap();
+/// ring.push_back(3).unwrap();
+/// ring.push_front(1).unwrap();
+/// assert_eq!(ring.push_back(4), Err(CollectionError::Full { capacity: 3 }));
+///
+/// // The front element wrapped around to the end of the buffer.
+/// assert_eq!(ring.as_slices(), (&[1][..], &[2, 3][..]));
+/// assert_eq!(ring.pop_front(), Some(1));
+/// assert_eq!((ring.get(0), ring.get(2)), (Some(&2), None));
+/// ```
+pub struct RingBuf<T> {
+    buf: RawVec<T>,
+    head: usize,
+    len: usize,
+    bound: Option<usize>,
+}
+
+impl<T> RingBuf<T> {
+    /// Creates a new empty ring buffer with the given maximum capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Maximum capacity of the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// A new empty ring buffer with the specified capacity, which doesn't allocate until an
+    /// element is added.
+    pub fn new(size: usize) -> Self {
+        RingBuf {
+            buf: RawVec::new(),
+            head: 0,
+            len: 0,
+            bound: Some(size),
+        }
+    }
+
+    /// Creates a new empty ring buffer without maximum capacity, which grows as elements are
+    /// added.
+    ///
+    /// # Returns
+    ///
+    /// A new empty growable ring buffer, which doesn't allocate until an element is added.
+    pub fn growable() -> Self {
+        RingBuf {
+            buf: RawVec::new(),
+            head: 0,
+            len: 0,
+            bound: None,
+        }
+    }
+
+    /// Adds an element after the back element.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to add.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if successful, otherwise [`CollectionError::Full`] if the ring buffer is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing the buffer would make it larger than `isize::MAX` bytes.
+    pub fn push_back(&mut self, item: T) -> Result<(), CollectionError> {
+        self.make_room()?;
+
+        // SAFETY: `len < capacity`, so the slot after the back element is within the buffer and
+        // uninitialized.
+        unsafe { ptr::write(self.slot(self.len), item) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Adds an element before the front element.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The element to add.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if successful, otherwise [`CollectionError::Full`] if the ring buffer is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing the buffer would make it larger than `isize::MAX` bytes.
+    pub fn push_front(&mut self, item: T) -> Result<(), CollectionError> {
+        self.make_room()?;
+
+        self.head = self.index(self.buf.capacity() - 1);
+        // SAFETY: `len < capacity`, so the slot before the front element is within the buffer
+        // and uninitialized.
+        unsafe { ptr::write(self.slot(0), item) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the front element.
+    ///
+    /// # Returns
+    ///
+    /// An optional value containing the removed element, or `None` if the ring buffer is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: the ring buffer isn't empty, so the slot at `head` holds the front element,
+        // which is no longer reachable once `head` moves past it.
+        let item = unsafe { ptr::read(self.slot(0)) };
+        self.head = self.index(1);
+        self.len -= 1;
+
+        Some(item)
+    }
+
+    /// Removes the back element.
+    ///
+    /// # Returns
+    ///
+    /// An optional value containing the removed element, or `None` if the ring buffer is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: the slot after the `len` remaining elements holds the back element, which is
+        // no longer reachable once `len` is lowered.
+        Some(unsafe { ptr::read(self.slot(self.len)) })
+    }
+
+    /// Returns a reference to the element at a position from the front.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the element, the front one being at 0.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the element, or `None` if the position is out of bounds.
+    pub fn get(&self, position: usize) -> Option<&T> {
+        // SAFETY: `position < len`, so the slot holds an element borrowed through `self`.
+        (position < self.len).then(|| unsafe { &*self.slot(position) })
+    }
+
+    /// Returns a mutable reference to the element at a position from the front.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the element, the front one being at 0.
+    ///
+    /// # Returns
+    ///
+    /// An optional mutable reference to the element, or `None` if the position is out of
+    /// bounds.
+    pub fn get_mut(&mut self, position: usize) -> Option<&mut T> {
+        // SAFETY: `position < len`, so the slot holds an element uniquely borrowed through
+        // `self`.
+        (position < self.len).then(|| unsafe { &mut *self.slot(position) })
+    }
+
+    /// Rotates the elements `count` positions to the left, so that the element at position
+    /// `count` becomes the front one.
+    ///
+    /// Moves `min(count, len - count)` elements, one at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of elements moving from the front to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the number of elements.
+    pub fn rotate_left(&mut self, count: usize) {
+        assert!(count <= self.len, "rotation is greater than the size");
+        if count <= self.len - count {
+            self.move_front_to_back(count);
+        } else {
+            self.move_back_to_front(self.len - count);
+        }
+    }
+
+    /// Rotates the elements `count` positions to the right, so that the element at position
+    /// `len - count` becomes the front one.
+    ///
+    /// Moves `min(count, len - count)` elements, one at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of elements moving from the back to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the number of elements.
+    pub fn rotate_right(&mut self, count: usize) {
+        assert!(count <= self.len, "rotation is greater than the size");
+        if count <= self.len - count {
+            self.move_back_to_front(count);
+        } else {
+            self.move_front_to_back(self.len - count);
+        }
+    }
+
+    /// Moves the elements so that they no longer wrap around the end of the buffer.
+    ///
+    /// Takes O(capacity) when the elements wrap around, and O(1) otherwise.
+    ///
+    /// # Returns
+    ///
+    /// A mutable slice of the elements, from front to back.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let capacity = self.buf.capacity();
+        if self.len > capacity - self.head {
+            if mem::size_of::<T>() != 0 {
+                // SAFETY: the buffer holds `capacity` slots, which may be uninitialized as
+                // `MaybeUninit`; rotating them keeps the elements in order from `head` on.
+                let slots = unsafe {
+                    slice::from_raw_parts_mut(self.buf.ptr() as *mut MaybeUninit<T>, capacity)
+                };
+                slots.rotate_left(self.head);
+            }
+            self.head = 0;
+        }
+        // SAFETY: the `len` elements from `head` are initialized and don't wrap around, and
+        // they are uniquely borrowed through `self`.
+        unsafe { slice::from_raw_parts_mut(self.slot(0), self.len) }
+    }
+
+    /// Returns the elements as two slices.
+    ///
+    /// # Returns
+    ///
+    /// The elements from the front up to the end of the buffer, then the elements wrapped
+    /// around to its start, which is empty when the elements are contiguous.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.split();
+        // SAFETY: both ranges hold initialized elements, borrowed through `self`.
+        unsafe {
+            (
+                slice::from_raw_parts(self.slot(0), front),
+                slice::from_raw_parts(self.buf.ptr(), back),
+            )
+        }
+    }
+
+    /// Returns the elements as two mutable slices.
+    ///
+    /// # Returns
+    ///
+    /// The elements from the front up to the end of the buffer, then the elements wrapped
+    /// around to its start, which is empty when the elements are contiguous.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.split();
+        // SAFETY: both ranges hold initialized elements, which don't overlap, uniquely borrowed
+        // through `self`.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(self.slot(0), front),
+                slice::from_raw_parts_mut(self.buf.ptr(), back),
+            )
+        }
+    }
+
+    /// Checks if the ring buffer is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks if the ring buffer is full.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer is fixed and holds as many elements as its capacity, `false`
+    /// otherwise. A growable ring buffer is never full.
+    pub fn is_full(&self) -> bool {
+        self.bound == Some(self.len)
+    }
+
+    /// Checks if the ring buffer grows as elements are added.
+    pub fn is_growable(&self) -> bool {
+        self.bound.is_none()
+    }
+
+    /// Returns the capacity of the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// The maximum capacity of a fixed ring buffer, or the number of elements a growable ring
+    /// buffer can hold before it grows.
+    pub fn capacity(&self) -> usize {
+        self.bound.unwrap_or(self.buf.capacity())
+    }
+
+    /// Returns the number of elements in the ring buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Clears the ring buffer, removing all elements.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+        self.head = 0;
+    }
+
+    /// Makes room for one more element, growing the buffer if it is full.
+    fn make_room(&mut self) -> Result<(), CollectionError> {
+        if let Some(capacity) = self.bound.filter(|&capacity| capacity == self.len) {
+            return Err(CollectionError::Full { capacity });
+        }
+        if self.len == self.buf.capacity() {
+            self.grow();
+        }
+        Ok(())
+    }
+
+    /// Moves `count` elements, one at a time, from the front to the back.
+    fn move_front_to_back(&mut self, count: usize) {
+        for _ in 0..count {
+            let source = self.slot(0);
+            self.head = self.index(1);
+            // SAFETY: the front element leaves its slot for the slot after the back element,
+            // which is either uninitialized or the same slot when the buffer is full.
+            unsafe { ptr::copy(source, self.slot(self.len - 1), 1) };
+        }
+    }
+
+    /// Moves `count` elements, one at a time, from the back to the front.
+    fn move_back_to_front(&mut self, count: usize) {
+        for _ in 0..count {
+            let source = self.slot(self.len - 1);
+            self.head = self.index(self.buf.capacity() - 1);
+            // SAFETY: the back element leaves its slot for the slot before the front element,
+            // which is either uninitialized or the same slot when the buffer is full.
+            unsafe { ptr::copy(source, self.slot(0), 1) };
+        }
+    }
+
+    /// Returns the number of elements before the end of the buffer, and the number of
+    /// elements wrapped around to its start.
+    fn split(&self) -> (usize, usize) {
+        let front = self.len.min(self.buf.capacity() - self.head);
+        (front, self.len - front)
+    }
+
+    /// Returns the index in the buffer of the element at `position` from the front.
+    fn index(&self, position: usize) -> usize {
+        let to_end = self.buf.capacity() - self.head;
+        if position < to_end {
+            self.head + position
+        } else {
+            position - to_end
+        }
+    }
+
+    /// Returns a pointer to the slot of the element at `position` from the front.
+    fn slot(&self, position: usize) -> *mut T {
+        // SAFETY: `index` is always below the capacity of the buffer.
+        unsafe { self.buf.ptr().add(self.index(position)) }
+    }
+
+    /// Grows the buffer of a full ring buffer, up to the capacity of a fixed one, moving the
+    /// elements before the end of the old buffer to the end of the new one so that they stay
+    /// in order.
+    fn grow(&mut self) {
+        let old_capacity = self.buf.capacity();
+        match self.bound {
+            Some(bound) => {
+                let capacity = old_capacity.saturating_mul(2).max(4).min(bound);
+                self.buf.reserve_exact(self.len, capacity - self.len);
+            }
+            None => self.buf.reserve(self.len, 1),
+        }
+        let wrapped = self.head + self.len > old_capacity;
+        if wrapped && mem::size_of::<T>() != 0 {
+            let moved = old_capacity - self.head;
+            let new_head = self.buf.capacity() - moved;
+            // SAFETY: the `moved` elements from `head` are initialized, and both ranges are
+            // within the new buffer; `ptr::copy` handles their overlap.
+            unsafe {
+                ptr::copy(
+                    self.buf.ptr().add(self.head),
+                    self.buf.ptr().add(new_head),
+                    moved,
+                )
+            };
+            self.head = new_head;
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBuf<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (front, back) = self.as_slices();
+        f.debug_list().entries(front).entries(back).finish()
+    }
+}
+
+/// Custom drop implementation for dropping the stored elements before the buffer is freed.
+impl<T> Drop for RingBuf<T> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt;
+
+/// The ways an operation on a container of this workspace (stack, queue, deque) can fail.
+///
+/// # Variants
+///
+/// * `Empty` - The container has no element to remove or look at.
+/// * `Full` - The container has no room for another element.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::error::CollectionError;
+///
+/// let error = CollectionError::Full { capacity: 4 };
+/// assert_eq!(error.to_string(), "Container is full (capacity 4)");
+///
+/// match error {
+///     CollectionError::Empty => unreachable!(),
+///     CollectionError::Full { capacity } => assert_eq!(capacity, 4),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollectionError {
+    /// The container has no element to remove or look at.
+    Empty,
+    /// The container has no room for another element.
+    Full {
+        /// The maximum number of elements the container can hold.
+        capacity: usize,
+    },
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectionError::Empty => write!(f, "Container is empty"),
+            CollectionError::Full { capacity } => {
+                write!(f, "Container is full (capacity {})", capacity)
+            }
+        }
+    }
+}
+
+impl Error for CollectionError {}
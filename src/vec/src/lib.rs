@@ -1,3 +1,6 @@
 // --- region: modules
+pub mod array_vec;
+pub mod error;
+pub mod storage;
 pub mod vec;
-// --- endregion: modules
\ No newline at end of file
+// --- endregion: modules
@@ -1,4 +1,7 @@
 // --- region: imports
+use ::vec::array_vec::ArrayVec;
+use ::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+
 use crate::vec::LinkedVec;
 // --- endregion: imports
 
@@ -22,6 +25,9 @@ fn main() {
     print!("\nlen: {} - ", lvec2.len());
     print!("pop {:?} - ", lvec2.pop().unwrap());
     print!("remove {:?}\n", lvec2.remove(0).unwrap());
+    lvec2.push_front(7);
+    print!("push_front 7 - pop_front {:?} - ", lvec2.pop_front().unwrap());
+    println!("last: {:?}", lvec2.peek());
     print!("vec 2: "); lvec2.print();
 
     let sum1 = lvec2.iter().sum::<i32>();
@@ -32,4 +38,47 @@ fn main() {
     }
     let sum2 = lvec2.iter().sum::<i32>();
     println!("\nsum = {sum1} + {addend} = {sum2}");
+
+    let mut lvec3: LinkedVec<i32> = [5, 3, 3, 8, 1, 8, 2].into_iter().collect();
+    lvec3.sort(); lvec3.dedup();
+    print!("sorted & deduped: "); lvec3.print();
+    let upper = lvec3.split_off(2);
+    print!("- split: {:?} | {:?}", lvec3, upper);
+    let windows: Vec<i32> = upper.windows(2).map(|w| w.sum()).collect();
+    print!(" - pair sums: {:?}", windows);
+    let chunks = upper.chunks(2).count();
+    let mut parts = LinkedVec::new();
+    parts.push(lvec3); parts.push(upper);
+    println!(" - {} chunks - joined: {:?}", chunks, parts.join(&0));
+
+    let mut lvec4: LinkedVec<i32> = (1..=5).collect();
+    let mut cursor = lvec4.cursor_front_mut();
+    cursor.move_next(); cursor.remove_current();
+    cursor.insert_after(35);
+    cursor.splice_after(linked_vec![31, 32]);
+    let rest = cursor.split_after();
+    println!("cursor edits: {:?} | {:?}", lvec4, rest);
+
+    // ARRAY VEC DATA TYPE
+    println!("\n***ARRAY VEC DATA TYPE***");
+    let mut avec = ArrayVec::with_capacity(2);
+    avec.push(10); avec.push(11);
+    avec.push(12); avec.push(13);
+    avec.insert(0, 9);
+    println!("vec: {:?} - len: {} - capacity: {}", avec, avec.len(), avec.capacity());
+    print!("Element at 3: {:?} - ", avec[3]);
+    print!("pop {:?} - ", avec.pop().unwrap());
+    println!("remove {:?}", avec.remove(0).unwrap());
+    avec.sort_by(|a, b| b.cmp(a));
+    println!("sorted descending: {:?}", avec);
+
+    // UNROLLED LINKED VEC DATA TYPE
+    println!("\n***UNROLLED LINKED VEC DATA TYPE***");
+    let mut uvec: UnrolledLinkedVec<i32, 4> = UnrolledLinkedVec::default();
+    for i in 0..10 { uvec.push(i); }
+    uvec.insert(5, 100);
+    print!("vec: "); uvec.print();
+    println!("- len: {} - nodes: {}", uvec.len(), uvec.node_count());
+    print!("remove {:?} - ", uvec.remove(5).unwrap());
+    println!("element at 7: {:?}", uvec[7]);
 }
@@ -0,0 +1,326 @@
+use crate::array_vec::ArrayVec;
+use crate::vec::unrolled_linked_vec::UnrolledLinkedVec;
+use crate::vec::LinkedVec;
+
+/// The operations a growable sequence must provide to back the containers of this workspace
+/// (stacks, hash maps and heaps).
+///
+/// Indices are zero based; `push`, `pop` and `peek` work on the last element.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the sequence.
+pub trait Sequence<T> {
+    /// Iterator over references to the elements, front to back.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Iterator over mutable references to the elements, front to back.
+    type IterMut<'a>: Iterator<Item = &'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Creates a new empty sequence.
+    fn new() -> Self;
+
+    /// Returns the number of elements in the sequence.
+    fn len(&self) -> usize;
+
+    /// Checks if the sequence is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pushes an element onto the end of the sequence.
+    fn push(&mut self, data: T);
+
+    /// Pops the last element of the sequence, or None if it is empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Inserts an element at `index`, or at the end if `index` is past the end.
+    fn insert(&mut self, index: usize, data: T);
+
+    /// Removes the element at `index`, or returns None if `index` is out of bounds.
+    fn remove(&mut self, index: usize) -> Option<T>;
+
+    /// Returns a reference to the element at `index`, or None if it is out of bounds.
+    fn find(&self, index: usize) -> Option<&T>;
+
+    /// Returns a mutable reference to the element at `index`, or None if it is out of bounds.
+    fn find_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    /// Returns a reference to the last element, or None if the sequence is empty.
+    fn peek(&self) -> Option<&T>;
+
+    /// Returns a mutable reference to the last element, or None if the sequence is empty.
+    fn peek_mut(&mut self) -> Option<&mut T>;
+
+    /// Swaps the elements at two indices; does nothing if either is out of bounds.
+    fn swap(&mut self, index1: usize, index2: usize);
+
+    /// Removes all elements.
+    fn clear(&mut self);
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Returns a mutable iterator over the elements, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+/// Selects which [`Sequence`] a container stores its elements in.
+///
+/// Containers take a `Storage` type parameter (defaulting to [`Linked`]) and keep their
+/// elements in `S::Vec<T>`, so the backing store can be swapped without touching the
+/// container's code.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::storage::{Contiguous, Linked, Sequence, Storage, Unrolled};
+///
+/// fn filled<S: Storage>() -> S::Vec<i32> {
+///     let mut seq = S::Vec::new();
+///     seq.push(1);
+///     seq.push(2);
+///     seq
+/// }
+///
+/// assert_eq!(filled::<Linked>().find(1), Some(&2));
+/// assert_eq!(filled::<Contiguous>().find(1), Some(&2));
+/// assert_eq!(filled::<Unrolled>().find(1), Some(&2));
+/// ```
+pub trait Storage {
+    /// The sequence type holding elements of type `T`.
+    type Vec<T>: Sequence<T>;
+}
+
+/// Stores elements in a [`LinkedVec`]: one heap node per element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Linked;
+
+/// Stores elements in an [`ArrayVec`]: one contiguous buffer with O(1) indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Contiguous;
+
+/// Stores elements in an [`UnrolledLinkedVec`]: one heap node per few elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unrolled;
+
+impl Storage for Linked {
+    type Vec<T> = LinkedVec<T>;
+}
+
+impl Storage for Contiguous {
+    type Vec<T> = ArrayVec<T>;
+}
+
+impl Storage for Unrolled {
+    type Vec<T> = UnrolledLinkedVec<T>;
+}
+
+impl<T> Sequence<T> for LinkedVec<T> {
+    type Iter<'a>
+        = crate::vec::Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = crate::vec::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn new() -> Self {
+        LinkedVec::new()
+    }
+
+    fn len(&self) -> usize {
+        LinkedVec::len(self)
+    }
+
+    fn push(&mut self, data: T) {
+        LinkedVec::push(self, data)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        LinkedVec::pop(self)
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        LinkedVec::insert(self, index, data)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        LinkedVec::remove(self, index)
+    }
+
+    fn find(&self, index: usize) -> Option<&T> {
+        LinkedVec::find(self, index)
+    }
+
+    fn find_mut(&mut self, index: usize) -> Option<&mut T> {
+        LinkedVec::find_mut(self, index)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        LinkedVec::peek(self)
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        LinkedVec::peek_mut(self)
+    }
+
+    fn swap(&mut self, index1: usize, index2: usize) {
+        LinkedVec::swap(self, index1, index2)
+    }
+
+    fn clear(&mut self) {
+        LinkedVec::clear(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        LinkedVec::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        LinkedVec::iter_mut(self)
+    }
+}
+
+impl<T> Sequence<T> for ArrayVec<T> {
+    type Iter<'a>
+        = std::slice::Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = std::slice::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn new() -> Self {
+        ArrayVec::new()
+    }
+
+    fn len(&self) -> usize {
+        ArrayVec::len(self)
+    }
+
+    fn push(&mut self, data: T) {
+        ArrayVec::push(self, data)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        ArrayVec::pop(self)
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        ArrayVec::insert(self, index, data)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        ArrayVec::remove(self, index)
+    }
+
+    fn find(&self, index: usize) -> Option<&T> {
+        ArrayVec::find(self, index)
+    }
+
+    fn find_mut(&mut self, index: usize) -> Option<&mut T> {
+        ArrayVec::find_mut(self, index)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        ArrayVec::peek(self)
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        ArrayVec::peek_mut(self)
+    }
+
+    fn swap(&mut self, index1: usize, index2: usize) {
+        if index1 < self.len() && index2 < self.len() {
+            self.as_mut_slice().swap(index1, index2);
+        }
+    }
+
+    fn clear(&mut self) {
+        ArrayVec::clear(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, const N: usize> Sequence<T> for UnrolledLinkedVec<T, N> {
+    type Iter<'a>
+        = crate::vec::unrolled_linked_vec::Iter<'a, T, N>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = crate::vec::unrolled_linked_vec::IterMut<'a, T, N>
+    where
+        T: 'a;
+
+    fn new() -> Self {
+        UnrolledLinkedVec::default()
+    }
+
+    fn len(&self) -> usize {
+        UnrolledLinkedVec::len(self)
+    }
+
+    fn push(&mut self, data: T) {
+        UnrolledLinkedVec::push(self, data)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        UnrolledLinkedVec::pop(self)
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        UnrolledLinkedVec::insert(self, index, data)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        UnrolledLinkedVec::remove(self, index)
+    }
+
+    fn find(&self, index: usize) -> Option<&T> {
+        UnrolledLinkedVec::find(self, index)
+    }
+
+    fn find_mut(&mut self, index: usize) -> Option<&mut T> {
+        UnrolledLinkedVec::find_mut(self, index)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        UnrolledLinkedVec::peek(self)
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        UnrolledLinkedVec::peek_mut(self)
+    }
+
+    fn swap(&mut self, index1: usize, index2: usize) {
+        UnrolledLinkedVec::swap(self, index1, index2)
+    }
+
+    fn clear(&mut self) {
+        UnrolledLinkedVec::clear(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        UnrolledLinkedVec::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        UnrolledLinkedVec::iter_mut(self)
+    }
+}
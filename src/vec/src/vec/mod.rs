@@ -1,25 +1,122 @@
-use std::ops::{Index, IndexMut, Range, RangeFrom, RangeTo};
-use std::{cmp::Ordering, fmt::Debug};
+pub mod unrolled_linked_vec;
 
-/// Type alias for a singly linked list node with an optional next node.
-pub type Next<T> = Option<Box<Node<T>>>;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeTo};
+use std::ptr::NonNull;
+use std::{cmp::Ordering, fmt, fmt::Debug, fmt::Display};
+
+/// Type alias for an optional link to the next node of a singly linked list.
+///
+/// Nodes are heap allocated and owned by the linked vector holding them. Links are raw
+/// pointers so that the vector can also point at its last node.
+pub(crate) type Next<T> = Option<NonNull<Node<T>>>;
 
 /// Represents a node in a singly linked list.
-#[derive(Debug, Clone, PartialEq)]
-pub struct Node<T> {
+///
+/// Nodes are only reachable through their linked vector, which keeps the links valid.
+#[derive(Debug)]
+pub(crate) struct Node<T> {
     /// The data stored in the node.
-    pub data: T,
+    data: T,
     /// Pointer to the next node in the linked list.
-    pub next: Next<T>,
+    next: Next<T>,
 }
 
 impl<T> Node<T> {
     /// Creates a new node with the given data and next node pointer.
-    pub fn new(data: T, next: Next<T>) -> Self {
+    fn new(data: T, next: Next<T>) -> Self {
         Node { data, next }
     }
 }
 
+/// A chain of nodes detached from any linked vector, which the merge sort relinks.
+///
+/// The chain owns its nodes and frees them when dropped, so that the nodes being sorted are
+/// not leaked if the comparator panics.
+///
+/// # Fields
+///
+/// * `head` - The first node of the chain.
+/// * `tail` - The last node of the chain, whose link is None.
+struct Chain<T> {
+    head: Next<T>,
+    tail: Next<T>,
+}
+
+impl<T> Chain<T> {
+    /// Creates a new empty chain.
+    fn new() -> Self {
+        Chain {
+            head: None,
+            tail: None,
+        }
+    }
+
+    /// Returns the data of the first node, or None if the chain is empty.
+    fn first(&self) -> Option<&T> {
+        // SAFETY: the head is a live node owned by this chain.
+        self.head.map(|head| unsafe { &(*head.as_ptr()).data })
+    }
+
+    /// Takes all nodes out of the chain.
+    ///
+    /// # Returns
+    ///
+    /// A chain holding the nodes, or None if the chain is empty.
+    fn take(&mut self) -> Option<Chain<T>> {
+        self.head?;
+        Some(std::mem::replace(self, Chain::new()))
+    }
+
+    /// Detaches the first node of the chain.
+    ///
+    /// # Returns
+    ///
+    /// A chain holding only the first node, or None if the chain is empty.
+    fn pop_front(&mut self) -> Option<Chain<T>> {
+        let head = self.head?;
+        // SAFETY: the head is a live node owned by this chain.
+        self.head = unsafe { (*head.as_ptr()).next.take() };
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        Some(Chain {
+            head: Some(head),
+            tail: Some(head),
+        })
+    }
+
+    /// Links the nodes of another chain at the end of this one.
+    fn append(&mut self, mut other: Chain<T>) {
+        let (Some(head), Some(tail)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+        match self.tail {
+            // SAFETY: the tail is a live node owned by this chain.
+            Some(last) => unsafe { (*last.as_ptr()).next = Some(head) },
+            None => self.head = Some(head),
+        }
+        self.tail = Some(tail);
+    }
+
+    /// Gives up the ownership of the nodes, returning the first and the last of them.
+    fn into_raw(self) -> (Next<T>, Next<T>) {
+        let chain = std::mem::ManuallyDrop::new(self);
+        (chain.head, chain.tail)
+    }
+}
+
+impl<T> Drop for Chain<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(node) = link {
+            // SAFETY: every node of the chain is owned by it and freed only once.
+            link = unsafe { Box::from_raw(node.as_ptr()) }.next;
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! linked_vec {
     ($($elem:expr),* $(,)?) => {{
@@ -30,9 +127,16 @@ macro_rules! linked_vec {
         vec
     }};
     ($elem:expr; $size:expr) => {{
+        // The element and the size are evaluated once, the element being cloned for all but
+        // the last position, which takes it.
+        let elem = $elem;
+        let size: usize = $size;
         let mut vec = $crate::vec::LinkedVec::new();
-        for _ in 0..$size {
-            vec.push($elem.clone());
+        for _ in 1..size {
+            vec.push(elem.clone());
+        }
+        if size > 0 {
+            vec.push(elem);
         }
         vec
     }};
@@ -48,6 +152,20 @@ macro_rules! linked_vec {
 ///
 /// * `size` - The size of the linked vector.
 /// * `head` - Pointer to the head node of the linked vector.
+/// * `tail` - Pointer to the tail node of the linked vector.
+///
+/// # Complexity
+///
+/// Thanks to the tail pointer, `push`, `push_front`, `pop_front`, `peek` and `append` run
+/// in O(1), and `extend` in O(1) per element. Indexed operations (`find`, `insert`, `remove`, and `pop`, which
+/// has to find the node before the last one) walk the list from the head in O(n).
+///
+/// # Iteration
+///
+/// All iterators yield the elements from front to back, including the consuming iterator
+/// returned by `into_iter`, which used to pop them from the back instead. Nodes only link
+/// forward, so [`Iter`] and [`IterMut`] do not implement `DoubleEndedIterator`; only
+/// [`IntoIter`] does, and each `next_back` call pops the last element in O(n).
 ///
 /// # Examples
 ///
@@ -63,14 +181,22 @@ macro_rules! linked_vec {
 /// assert_eq!(vec.len(), 2);
 /// assert_eq!(last, Some(3));
 /// ```
-#[derive(Debug, Clone, PartialEq)]
 pub struct LinkedVec<T> {
     /// The size of the linked vector.
     size: usize,
     /// Pointer to the head node of the linked vector.
     head: Next<T>,
+    /// Pointer to the tail node of the linked vector.
+    tail: Next<T>,
+    /// Marks that the linked vector owns its nodes.
+    marker: PhantomData<Box<Node<T>>>,
 }
-impl<T: Clone + Debug + PartialEq> std::ops::Index<usize> for LinkedVec<T> {
+
+// The linked vector owns all of its nodes, so it is as thread safe as the values it stores.
+unsafe impl<T: Send> Send for LinkedVec<T> {}
+unsafe impl<T: Sync> Sync for LinkedVec<T> {}
+
+impl<T> std::ops::Index<usize> for LinkedVec<T> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -78,13 +204,13 @@ impl<T: Clone + Debug + PartialEq> std::ops::Index<usize> for LinkedVec<T> {
     }
 }
 
-impl<T: Clone + Debug + PartialEq> std::ops::IndexMut<usize> for LinkedVec<T> {
+impl<T> std::ops::IndexMut<usize> for LinkedVec<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         self.find_mut(index).expect("Index out of bounds")
     }
 }
 
-impl<T: Clone + Debug + PartialEq> Index<Range<usize>> for LinkedVec<T> {
+impl<T: Clone> Index<Range<usize>> for LinkedVec<T> {
     type Output = [T];
 
     fn index(&self, range: Range<usize>) -> &Self::Output {
@@ -95,7 +221,7 @@ impl<T: Clone + Debug + PartialEq> Index<Range<usize>> for LinkedVec<T> {
     }
 }
 
-impl<T: Clone + Debug + PartialEq> IndexMut<Range<usize>> for LinkedVec<T> {
+impl<T: Clone> IndexMut<Range<usize>> for LinkedVec<T> {
     fn index_mut(&mut self, range: Range<usize>) -> &mut Self::Output {
         let mut result = LinkedVec::new();
         for i in range {
@@ -105,7 +231,7 @@ impl<T: Clone + Debug + PartialEq> IndexMut<Range<usize>> for LinkedVec<T> {
     }
 }
 
-impl<T: Clone + Debug + PartialEq> Index<RangeTo<usize>> for LinkedVec<T> {
+impl<T: Clone> Index<RangeTo<usize>> for LinkedVec<T> {
     type Output = LinkedVec<T>;
 
     fn index(&self, range: RangeTo<usize>) -> &Self::Output {
@@ -117,7 +243,7 @@ impl<T: Clone + Debug + PartialEq> Index<RangeTo<usize>> for LinkedVec<T> {
     }
 }
 
-impl<T: Clone + Debug + PartialEq> IndexMut<RangeTo<usize>> for LinkedVec<T> {
+impl<T: Clone> IndexMut<RangeTo<usize>> for LinkedVec<T> {
     fn index_mut(&mut self, range: RangeTo<usize>) -> &mut Self::Output {
         let mut result = LinkedVec::new();
         for i in 0..range.end {
@@ -127,7 +253,7 @@ impl<T: Clone + Debug + PartialEq> IndexMut<RangeTo<usize>> for LinkedVec<T> {
     }
 }
 
-impl<T: Clone + Debug + PartialEq> Index<RangeFrom<usize>> for LinkedVec<T> {
+impl<T: Clone> Index<RangeFrom<usize>> for LinkedVec<T> {
     type Output = LinkedVec<T>;
 
     fn index(&self, range: RangeFrom<usize>) -> &Self::Output {
@@ -138,7 +264,7 @@ impl<T: Clone + Debug + PartialEq> Index<RangeFrom<usize>> for LinkedVec<T> {
         Box::leak(Box::new(result))
     }
 }
-impl<T: Clone + Debug + PartialEq> IndexMut<RangeFrom<usize>> for LinkedVec<T> {
+impl<T: Clone> IndexMut<RangeFrom<usize>> for LinkedVec<T> {
     fn index_mut(&mut self, range: RangeFrom<usize>) -> &mut Self::Output {
         let mut result = LinkedVec::new();
         for i in range.start..self.size {
@@ -148,7 +274,7 @@ impl<T: Clone + Debug + PartialEq> IndexMut<RangeFrom<usize>> for LinkedVec<T> {
     }
 }
 
-impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
+impl<T> LinkedVec<T> {
     /// Creates a new empty linked vector with the specified size.
     ///
     /// # Returns
@@ -167,10 +293,12 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
         LinkedVec {
             size: 0,
             head: None,
+            tail: None,
+            marker: PhantomData,
         }
     }
 
-    /// Pushes an element onto the front of the linked vector.
+    /// Pushes an element onto the end of the linked vector in O(1) time.
     ///
     /// # Arguments
     ///
@@ -184,23 +312,99 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// let mut vec = LinkedVec::new();
     /// vec.push(1);
     /// assert_eq!(vec.len(), 1);
+    ///
+    /// // Pushing does not walk the list, so building a large vector takes linear time.
+    /// let mut large = LinkedVec::new();
+    /// for i in 0..200_000 {
+    ///     large.push(i);
+    /// }
+    /// assert_eq!(large.len(), 200_000);
+    /// assert_eq!(large.peek(), Some(&199_999));
     /// ```
     pub fn push(&mut self, data: T) {
-        let node = Node::new(data, None);
+        let node = Self::allocate(Node::new(data, None));
+        match self.tail {
+            // SAFETY: the tail is a live node owned by this linked vector.
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.size += 1;
+    }
+
+    /// Pushes an element onto the front of the linked vector in O(1) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to be pushed onto the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec = LinkedVec::new();
+    /// vec.push(2);
+    /// vec.push_front(1);
+    /// assert_eq!(vec.find(0), Some(&1));
+    /// assert_eq!(vec.peek(), Some(&2));
+    ///
+    /// let mut large = LinkedVec::new();
+    /// for i in 0..200_000 {
+    ///     large.push_front(i);
+    /// }
+    /// assert_eq!(large.find(0), Some(&199_999));
+    /// assert_eq!(large.peek(), Some(&0));
+    /// ```
+    pub fn push_front(&mut self, data: T) {
+        let node = Self::allocate(Node::new(data, self.head));
+        if self.tail.is_none() {
+            self.tail = Some(node);
+        }
+        self.head = Some(node);
+        self.size += 1;
+    }
+
+    /// Removes the first element of the linked vector in O(1) time.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the removed data, or None if the linked vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec = LinkedVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.pop_front(), Some(1));
+    /// assert_eq!(vec.pop_front(), Some(2));
+    /// assert_eq!(vec.pop_front(), None);
+    ///
+    /// let mut large: LinkedVec<usize> = (0..200_000).collect();
+    /// let mut expected = 0;
+    /// while let Some(data) = large.pop_front() {
+    ///     assert_eq!(data, expected);
+    ///     expected += 1;
+    /// }
+    /// assert_eq!(expected, 200_000);
+    /// assert_eq!(large.peek(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
         if self.is_empty() {
-            self.head = Some(Box::new(node));
+            None
         } else {
-            let mut current_node = self.head.as_mut().unwrap();
-            for _i in 0..self.size - 1 {
-                current_node = current_node.next.as_mut().unwrap();
-            }
-            current_node.next = Some(Box::new(node));
+            // SAFETY: the linked vector is not empty, so the head exists.
+            Some(unsafe { self.unlink_next(None) }.data)
         }
-        self.size += 1;
     }
 
     /// Appends the elements of another LinkedVec to the end of this LinkedVec, clearing the other LinkedVec.
     ///
+    /// The nodes of `other` are relinked rather than copied, so this runs in O(1) time.
+    ///
     /// # Arguments
     ///
     /// * `other` - A mutable reference to another LinkedVec whose elements will be appended to this LinkedVec.
@@ -221,14 +425,29 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// linked_vec1.append(&mut linked_vec2);
     ///
     /// assert_eq!(linked_vec1.len(), 4);
+    /// assert_eq!(linked_vec1.peek(), Some(&4));
     /// assert_eq!(linked_vec2.len(), 0);
+    ///
+    /// // Appending many single elements stays linear in total.
+    /// let mut large = LinkedVec::new();
+    /// for i in 0..200_000 {
+    ///     let mut single = LinkedVec::new();
+    ///     single.push(i);
+    ///     large.append(&mut single);
+    /// }
+    /// assert_eq!(large.len(), 200_000);
+    /// assert_eq!(large.peek(), Some(&199_999));
     /// ```
     pub fn append(&mut self, other: &mut Self) {
-        while let Some(node) = other.head.as_mut().take() {
-            self.push(node.data.clone());
-            other.head = node.next.take();
+        if let Some(other_head) = other.head.take() {
+            match self.tail {
+                // SAFETY: the tail is a live node owned by this linked vector.
+                Some(mut tail) => unsafe { tail.as_mut().next = Some(other_head) },
+                None => self.head = Some(other_head),
+            }
+            self.tail = other.tail.take();
+            self.size += std::mem::replace(&mut other.size, 0);
         }
-        other.clear();
     }
 
     /// Inserts an element at the specified index in the LinkedVec.
@@ -256,30 +475,24 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// assert_eq!(linked_vec.pop(), Some(2));
     /// assert_eq!(linked_vec.pop(), Some(1));
     /// ```
-    pub fn insert(&mut self, mut index: usize, data: T) {
-        if self.size < index {
-            index = self.size;
-        }
-
-        let mut node = Node::new(data, None);
-        if self.is_empty() {
-            self.head = Some(Box::new(node));
-        } else if index == 0 {
-            node.next = self.head.take();
-            self.head = Some(Box::new(node));
+    pub fn insert(&mut self, index: usize, data: T) {
+        if index == 0 {
+            self.push_front(data);
+        } else if self.size <= index {
+            self.push(data);
         } else {
-            let mut node_before = self.head.as_mut().unwrap();
-            for _i in 0..index - 1 {
-                node_before = node_before.next.as_mut().unwrap();
+            let mut node_before = self.node_at(index - 1).unwrap();
+            // SAFETY: `node_before` is a live node owned by this linked vector, and it is not
+            // the tail because `index < size`.
+            unsafe {
+                let node = Self::allocate(Node::new(data, node_before.as_ref().next));
+                node_before.as_mut().next = Some(node);
             }
-            node.next = node_before.next.take();
-            node_before.next = Some(Box::new(node));
+            self.size += 1;
         }
-
-        self.size += 1;
     }
 
-    /// Pops an element from the front of the linked vector.
+    /// Pops an element from the end of the linked vector.
     ///
     /// # Returns
     ///
@@ -316,20 +529,17 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     ///
     /// let mut vec = LinkedVec::new();
     /// vec.push(1);
-    /// assert_eq!(vec.peek(), Some(1));
+    /// assert_eq!(vec.peek(), Some(&1));
     /// vec.push(2);
-    /// assert_eq!(vec.peek(), Some(2));
+    /// assert_eq!(vec.peek(), Some(&2));
     /// vec.pop();
-    /// assert_eq!(vec.peek(), Some(1));
+    /// assert_eq!(vec.peek(), Some(&1));
     /// vec.pop();
     /// assert_eq!(vec.peek(), None);
     /// ```
     pub fn peek(&self) -> Option<&T> {
-        if self.is_empty() {
-            None
-        } else {
-            self.find(self.size - 1)
-        }
+        // SAFETY: the tail is a live node owned by this linked vector.
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).data })
     }
 
     /// Returns a mutable reference to the last element in the linked vector, or None if it is empty.
@@ -348,14 +558,11 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// if let Some(data) = vec.peek_mut() {
     ///     *data = 2;
     /// }
-    /// assert_eq!(vec.peek(), Some(2));
+    /// assert_eq!(vec.peek(), Some(&2));
     /// ```
     pub fn peek_mut(&mut self) -> Option<&mut T> {
-        if self.is_empty() {
-            None
-        } else {
-            self.find_mut(self.size - 1)
-        }
+        // SAFETY: the tail is a live node owned by this linked vector.
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).data })
     }
 
     /// Removes the element at the specified index from the LinkedVec, returning it.
@@ -384,25 +591,17 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// assert_eq!(linked_vec.len(), 1);
     /// ```
     pub fn remove(&mut self, index: usize) -> Option<T> {
-        if self.size < index {
+        if self.size <= index {
             return None;
         }
 
-        let mut node;
-        if 0 == index {
-            node = self.head.take().unwrap();
-            self.head = node.next.take();
+        let node_before = if index == 0 {
+            None
         } else {
-            let mut node_before = self.head.as_mut().unwrap();
-            for _i in 0..index - 1 {
-                node_before = node_before.next.as_mut().unwrap();
-            }
-            node = node_before.next.take().unwrap();
-            node_before.next = node.next.take();
-        }
-        self.size -= 1;
-
-        Some(node.data)
+            self.node_at(index - 1)
+        };
+        // SAFETY: `index < size`, so the node after `node_before` exists.
+        Some(unsafe { self.unlink_next(node_before) }.data)
     }
 
     /// Checks if the linked vector is empty.
@@ -458,8 +657,7 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// assert_eq!(vec.is_empty(), true);
     /// ```
     pub fn clear(&mut self) {
-        self.size = 0;
-        self.head = None;
+        while self.pop_front().is_some() {}
     }
 
     /// Finds and returns the element at the specified index in the LinkedVec.
@@ -482,30 +680,13 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// linked_vec.push(2);
     /// linked_vec.push(3);
     ///
-    /// assert_eq!(linked_vec.find(1), Some(2));
+    /// assert_eq!(linked_vec.find(1), Some(&2));
     /// assert_eq!(linked_vec.find(3), None);
     /// ```
     pub fn find(&self, index: usize) -> Option<&T> {
-        if self.size <= index || self.is_empty() {
-            None
-        } else {
-            let mut node = self.head.as_ref().unwrap();
-            for _i in 0..index {
-                node = node.next.as_ref().unwrap();
-            }
-            Some(&node.data)
-        }
-    }
-
-    pub fn contains(&self, data: &T) -> bool {
-        let mut node = self.head.as_ref();
-        while let Some(n) = node {
-            if n.data == *data {
-                return true;
-            }
-            node = n.next.as_ref();
-        }
-        false
+        // SAFETY: `node_at` only returns live nodes owned by this linked vector.
+        self.node_at(index)
+            .map(|node| unsafe { &(*node.as_ptr()).data })
     }
 
     /// Finds and returns a mutable reference to the element at the specified index in the LinkedVec.
@@ -531,18 +712,12 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// if let Some(data) = linked_vec.find_mut(1) {
     ///     *data = 4;
     /// }
-    /// assert_eq!(linked_vec.find(1), Some(4));
+    /// assert_eq!(linked_vec.find(1), Some(&4));
     /// ```
     pub fn find_mut(&mut self, index: usize) -> Option<&mut T> {
-        if self.size <= index || self.is_empty() {
-            None
-        } else {
-            let mut node = self.head.as_mut().unwrap();
-            for _i in 0..index {
-                node = node.next.as_mut().unwrap();
-            }
-            Some(&mut node.data)
-        }
+        // SAFETY: `node_at` only returns live nodes owned by this linked vector.
+        self.node_at(index)
+            .map(|node| unsafe { &mut (*node.as_ptr()).data })
     }
 
     /// Returns an iterator over the elements of the linked vector.
@@ -565,9 +740,11 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// assert_eq!(iter.next(), Some(&2));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
-            next: self.head.as_deref(),
+            // SAFETY: the head is a live node owned by this linked vector.
+            next: self.head.map(|node| unsafe { &*node.as_ptr() }),
+            len: self.size,
         }
     }
 
@@ -593,17 +770,53 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// assert_eq!(iter.next(), Some(&mut 2));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
-            next: self.head.as_deref_mut(),
+            // SAFETY: the head is a live node owned by this linked vector.
+            next: self.head.map(|node| unsafe { &mut *node.as_ptr() }),
+            len: self.size,
         }
     }
 
-    /// Consumes the linked vector and returns an iterator over its elements.
+    /// Swaps the elements at the specified indices in the LinkedVec.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// An iterator consuming the linked vector and yielding its elements.
+    /// * `index1` - The index of the first element.
+    /// * `index2` - The index of the second element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut linked_vec = LinkedVec::new();
+    /// linked_vec.push(1);
+    /// linked_vec.push(2);
+    /// linked_vec.push(3);
+    ///
+    /// linked_vec.swap(0, 2);
+    ///
+    /// assert_eq!(linked_vec.find(0), Some(&3));
+    /// assert_eq!(linked_vec.find(2), Some(&1));
+    /// ```
+    pub fn swap(&mut self, index1: usize, index2: usize) {
+        if index1 >= self.size || index2 >= self.size {
+            return;
+        }
+
+        if index1 == index2 {
+            return;
+        }
+
+        let node1 = self.node_at(index1).unwrap();
+        let node2 = self.node_at(index2).unwrap();
+        // SAFETY: the indices differ, so these are two distinct live nodes owned by this
+        // linked vector.
+        unsafe { std::mem::swap(&mut (*node1.as_ptr()).data, &mut (*node2.as_ptr()).data) };
+    }
+
+    /// Returns a reference to the first element of the linked vector, or None if it is empty.
     ///
     /// # Examples
     ///
@@ -611,276 +824,1555 @@ impl<T: Debug + Clone + PartialEq> LinkedVec<T> {
     /// use crate::vec::vec::LinkedVec;
     ///
     /// let mut vec = LinkedVec::new();
+    /// assert_eq!(vec.first(), None);
     /// vec.push(1);
     /// vec.push(2);
-    /// let mut iter = vec.into_iter();
-    /// assert_eq!(iter.next(), Some(2));
-    /// assert_eq!(iter.next(), Some(1));
-    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(vec.first(), Some(&1));
     /// ```
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn first(&self) -> Option<&T> {
+        // SAFETY: the head is a live node owned by this linked vector.
+        self.head.map(|node| unsafe { &(*node.as_ptr()).data })
     }
 
-    /// Prints the elements of the linked vector.
+    /// Returns a reference to the last element of the linked vector, or None if it is empty.
+    ///
+    /// This is the same as [`peek`](LinkedVec::peek) and runs in O(1) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use crate::vec::vec::LinkedVec;
     ///
-    /// let mut linked_vec = LinkedVec::new();
-    /// linked_vec.push(1);
-    /// linked_vec.push(2);
-    /// linked_vec.print();
+    /// let mut vec = LinkedVec::new();
+    /// assert_eq!(vec.last(), None);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.last(), Some(&2));
     /// ```
-    pub fn print(&self) {
-        for data in self.iter() {
-            print!("{:?} ", data);
-        }
+    pub fn last(&self) -> Option<&T> {
+        self.peek()
     }
 
-    /// Converts the linked vector into a standard Vec.
+    /// Returns the index of the first element matching the predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function deciding whether an element matches.
     ///
     /// # Returns
     ///
-    /// A Vec containing all elements of the linked vector.
+    /// The index of the first matching element, or None if no element matches.
     ///
     /// # Examples
     ///
     /// ```
     /// use crate::vec::vec::LinkedVec;
     ///
-    /// let mut linked_vec = LinkedVec::new();
-    /// linked_vec.push(1);
-    /// linked_vec.push(2);
-    /// let vec = linked_vec.to_vec();
-    /// assert_eq!(vec, vec![1, 2]);
+    /// let vec: LinkedVec<i32> = (1..=5).collect();
+    /// assert_eq!(vec.position(|&x| x > 2), Some(2));
+    /// assert_eq!(vec.position(|&x| x > 5), None);
     /// ```
-    pub fn to_vec(&self) -> Vec<T> {
-        let mut vec = Vec::with_capacity(self.size);
-        for data in self.iter() {
-            vec.push(data.clone());
-        }
-        vec
+    pub fn position<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<usize> {
+        self.iter().position(predicate)
     }
 
-    /// Swaps the elements at the specified indices in the LinkedVec.
-    ///
-    /// # Arguments
+    /// Sorts the linked vector in ascending order.
     ///
-    /// * `index1` - The index of the first element.
-    /// * `index2` - The index of the second element.
+    /// See [`sort_by`](LinkedVec::sort_by) for details.
     ///
     /// # Examples
     ///
     /// ```
     /// use crate::vec::vec::LinkedVec;
     ///
-    /// let mut linked_vec = LinkedVec::new();
-    /// linked_vec.push(1);
-    /// linked_vec.push(2);
-    /// linked_vec.push(3);
+    /// let mut vec: LinkedVec<i32> = [5, 1, 4, 2, 3].into_iter().collect();
+    /// vec.sort();
+    /// assert_eq!(vec.to_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the linked vector with a comparator function.
     ///
-    /// linked_vec.swap(0, 2);
+    /// This is a bottom-up merge sort that relinks the nodes instead of moving the elements,
+    /// so it needs no random access. It is stable, runs in O(n log n) time and only allocates
+    /// O(log n) extra space. If `compare` panics, the linked vector is left empty and its
+    /// elements are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - The function defining the order of two elements.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(linked_vec.find(0), Some(&3));
-    /// assert_eq!(linked_vec.find(2), Some(&1));
     /// ```
-    pub fn swap(&mut self, index1: usize, index2: usize) {
-        if index1 >= self.size || index2 >= self.size {
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<(i32, char)> =
+    ///     [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')].into_iter().collect();
+    /// vec.sort_by(|a, b| a.0.cmp(&b.0));
+    /// assert_eq!(vec.to_vec(), vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    ///
+    /// // Sorting a large vector in descending order.
+    /// let mut large: LinkedVec<u64> = (0..200_000).map(|i| i * 7919 % 200_000).collect();
+    /// large.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(large.first(), Some(&199_999));
+    /// assert_eq!(large.last(), Some(&0));
+    /// assert!(large.windows(2).all(|mut pair| pair.next() >= pair.next()));
+    /// ```
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        if self.size < 2 {
             return;
         }
 
-        if index1 == index2 {
-            return;
+        // Detach the nodes first, so a panicking comparator leaves a valid (empty) vector.
+        let size = std::mem::replace(&mut self.size, 0);
+        let mut rest = Chain {
+            head: self.head.take(),
+            tail: self.tail.take(),
+        };
+
+        // `runs[i]` is either empty or a sorted run of 2^i nodes, made of elements that come
+        // before the elements of every run in `runs[..i]`.
+        let mut runs: Vec<Chain<T>> = Vec::new();
+        while let Some(mut run) = rest.pop_front() {
+            let mut level = 0;
+            while let Some(earlier) = runs.get_mut(level).and_then(Chain::take) {
+                run = Self::merge(earlier, run, &mut compare);
+                level += 1;
+            }
+            if level == runs.len() {
+                runs.push(run);
+            } else {
+                runs[level] = run;
+            }
         }
 
-        let data1 = self.find(index1).unwrap().clone();
-        let data2 = self.find(index2).unwrap().clone();
+        let mut sorted = Chain::new();
+        for run in runs {
+            sorted = Self::merge(run, sorted, &mut compare);
+        }
 
-        self.remove(index1);
-        self.insert(index1, data2);
-        self.remove(index2);
-        self.insert(index2, data1);
+        (self.head, self.tail) = sorted.into_raw();
+        self.size = size;
     }
 
-    /// Reverses the order of elements in the LinkedVec.
-    ///
-    /// # Returns
-    ///
-    /// A new LinkedVec with the elements in reverse order.
+    /// Removes consecutive repeated elements, keeping the first of each run.
     ///
     /// # Examples
     ///
     /// ```
     /// use crate::vec::vec::LinkedVec;
     ///
-    /// let mut linked_vec = LinkedVec::new();
-    /// linked_vec.push(1);
-    /// linked_vec.push(2);
-    /// linked_vec.push(3);
-    ///
-    /// let reversed_vec = linked_vec.reverse();
-    ///
-    /// assert_eq!(reversed_vec.find(0), Some(&3));
-    /// assert_eq!(reversed_vec.find(1), Some(&2));
-    /// assert_eq!(reversed_vec.find(2), Some(&1));
+    /// let mut vec: LinkedVec<i32> = [1, 1, 2, 3, 3, 3, 1].into_iter().collect();
+    /// vec.dedup();
+    /// assert_eq!(vec.to_vec(), vec![1, 2, 3, 1]);
+    /// assert_eq!(vec.last(), Some(&1));
     /// ```
-    pub fn reverse(&self) -> Self {
-        let mut reversed_vec = LinkedVec::new();
-        let mut current = self.head.as_ref();
-
-        while let Some(node) = current {
-            reversed_vec.push_front(node.data.clone());
-            current = node.next.as_ref();
-        }
-
-        reversed_vec
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
     }
 
-    fn push_front(&mut self, value: T) {
-        let new_node = Box::new(Node {
-            data: value,
-            next: self.head.take(),
-        });
-        self.head = Some(new_node);
-        self.size += 1;
-    }
-
-    /// Drains the elements from the linked vector, returning an iterator over the removed elements.
+    /// Removes consecutive elements that map to the same key, keeping the first of each run.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// An iterator yielding the removed elements.
+    /// * `key` - The function computing the key of an element.
     ///
     /// # Examples
     ///
     /// ```
     /// use crate::vec::vec::LinkedVec;
     ///
-    /// let mut vec = LinkedVec::new();
-    /// vec.push(1);
-    /// vec.push(2);
-    /// vec.push(3);
-    ///
-    /// let mut drain_iter = vec.drain();
-    /// assert_eq!(drain_iter.next(), Some(1));
-    /// assert_eq!(drain_iter.next(), Some(2));
-    /// assert_eq!(drain_iter.next(), Some(3));
-    /// assert_eq!(drain_iter.next(), None);
-    /// assert_eq!(vec.len(), 0);
+    /// let mut vec: LinkedVec<i32> = [10, 11, 20, 21, 22, 30].into_iter().collect();
+    /// vec.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(vec.to_vec(), vec![10, 20, 30]);
     /// ```
-    pub fn drain(&mut self) -> Drain<T> {
-        Drain { linked_vec: self }
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
     }
 
-    pub fn extend(&mut self, other: LinkedVec<T>) {
-        for data in other.iter() {
+    /// Removes consecutive elements for which `same_bucket` returns true.
+    ///
+    /// `same_bucket(a, b)` is called with an element `a` and the last kept element `b`
+    /// before it; `a` is removed when it returns true.
+    ///
+    /// # Arguments
+    ///
+    /// * `same_bucket` - The function deciding whether two elements are duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<&str> = ["a", "A", "b", "B", "b", "c"].into_iter().collect();
+    /// vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(vec.to_vec(), vec!["a", "b", "c"]);
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let Some(mut kept) = self.head else {
+            return;
+        };
+
+        // SAFETY: `kept` and `next` are distinct live nodes owned by this linked vector.
+        unsafe {
+            while let Some(next) = (*kept.as_ptr()).next {
+                if same_bucket(&mut (*next.as_ptr()).data, &mut (*kept.as_ptr()).data) {
+                    drop(self.unlink_next(Some(kept)));
+                } else {
+                    kept = next;
+                }
+            }
+        }
+    }
+
+    /// Retains only the elements for which the predicate returns true, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function deciding whether an element is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=10).collect();
+    /// vec.retain(|&x| x % 3 == 0);
+    /// assert_eq!(vec.to_vec(), vec![3, 6, 9]);
+    /// assert_eq!(vec.last(), Some(&9));
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        let mut node_before = None;
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: `node` is a live node owned by this linked vector, and `node_before` is
+            // the node linking to it (or None if it is the head).
+            unsafe {
+                current = (*node.as_ptr()).next;
+                if predicate(&(*node.as_ptr()).data) {
+                    node_before = Some(node);
+                } else {
+                    drop(self.unlink_next(node_before));
+                }
+            }
+        }
+    }
+
+    /// Splits the linked vector in two at the given index.
+    ///
+    /// The nodes are relinked, not copied: this walks to `at` and then runs in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index of the first element moved to the returned linked vector.
+    ///
+    /// # Returns
+    ///
+    /// A linked vector holding the elements `[at, len)`; `self` keeps `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=5).collect();
+    /// let tail = vec.split_off(3);
+    /// assert_eq!(vec.to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(tail.to_vec(), vec![4, 5]);
+    /// assert_eq!(vec.last(), Some(&3));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.size, "Index out of bounds");
+        if at == 0 {
+            return std::mem::take(self);
+        }
+
+        let node_before = self.node_at(at - 1).unwrap();
+        let mut other = LinkedVec::new();
+        // SAFETY: `node_before` is a live node owned by this linked vector.
+        other.head = unsafe { (*node_before.as_ptr()).next.take() };
+        if other.head.is_some() {
+            other.tail = self.tail;
+            self.tail = Some(node_before);
+        }
+        other.size = self.size - at;
+        self.size = at;
+        other
+    }
+
+    /// Splits the linked vector into two at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - The index of the first element of the second linked vector.
+    ///
+    /// # Returns
+    ///
+    /// A pair holding the elements `[0, mid)` and `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let vec: LinkedVec<i32> = (1..=4).collect();
+    /// let (left, right) = vec.split_at(1);
+    /// assert_eq!(left.to_vec(), vec![1]);
+    /// assert_eq!(right.to_vec(), vec![2, 3, 4]);
+    /// ```
+    pub fn split_at(mut self, mid: usize) -> (Self, Self) {
+        let right = self.split_off(mid);
+        (self, right)
+    }
+
+    /// Shortens the linked vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// Does nothing if `len` is greater than or equal to the current length.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of elements to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=5).collect();
+    /// vec.truncate(2);
+    /// assert_eq!(vec.to_vec(), vec![1, 2]);
+    /// vec.truncate(10);
+    /// assert_eq!(vec.len(), 2);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.size {
+            drop(self.split_off(len));
+        }
+    }
+
+    /// Rotates the linked vector in place so that the element at `mid` becomes the first.
+    ///
+    /// The nodes are relinked, so this walks to `mid` and then runs in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - The number of elements moved from the front to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=5).collect();
+    /// vec.rotate_left(2);
+    /// assert_eq!(vec.to_vec(), vec![3, 4, 5, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.size, "Index out of bounds");
+        if mid == 0 || mid == self.size {
+            return;
+        }
+
+        let mut rotated = self.split_off(mid);
+        rotated.append(self);
+        *self = rotated;
+    }
+
+    /// Rotates the linked vector in place so that the last `k` elements come first.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of elements moved from the back to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=5).collect();
+    /// vec.rotate_right(2);
+    /// assert_eq!(vec.to_vec(), vec![4, 5, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.size, "Index out of bounds");
+        self.rotate_left(self.size - k);
+    }
+
+    /// Returns an iterator over all contiguous windows of `size` elements.
+    ///
+    /// Each window is itself an iterator over its elements; the windows overlap and
+    /// there are none if the linked vector is shorter than `size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of elements in each window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let vec: LinkedVec<i32> = (1..=4).collect();
+    /// let sums: Vec<i32> = vec.windows(2).map(|window| window.sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// assert_eq!(vec.windows(5).count(), 0);
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "Window size must be non-zero");
+        Windows {
+            start: self.iter(),
+            size,
+        }
+    }
+
+    /// Returns an iterator over the elements in chunks of `chunk_size` elements.
+    ///
+    /// Each chunk is itself an iterator over its elements; the last chunk is shorter if
+    /// the length is not a multiple of `chunk_size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - The number of elements in each chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let vec: LinkedVec<i32> = (1..=5).collect();
+    /// let chunks: Vec<Vec<i32>> = vec.chunks(2).map(|chunk| chunk.copied().collect()).collect();
+    /// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        assert!(chunk_size != 0, "Chunk size must be non-zero");
+        Chunks {
+            start: self.iter(),
+            size: chunk_size,
+        }
+    }
+
+    /// Returns an iterator over a range of positions of the linked vector.
+    ///
+    /// Unlike range indexing, the elements are borrowed rather than copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The positions of the elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements in the range, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let vec: LinkedVec<i32> = (0..10).collect();
+    /// assert_eq!(vec.range(3..6).copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    /// assert_eq!(vec.range(..2).len(), 2);
+    /// assert_eq!(vec.range(8..).copied().collect::<Vec<_>>(), vec![8, 9]);
+    /// ```
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+        let (start, end) = bounds(range, self.size);
+        Iter {
+            // SAFETY: the node is a live node owned by this linked vector.
+            next: self.node_at(start).map(|node| unsafe { &*node.as_ptr() }),
+            len: end - start,
+        }
+    }
+
+    /// Returns a mutable iterator over a range of positions of the linked vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The positions of the elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding mutable references to the elements in the range, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (0..6).collect();
+    /// vec.range_mut(2..=4).for_each(|data| *data *= 10);
+    /// assert_eq!(vec.to_vec(), vec![0, 1, 20, 30, 40, 5]);
+    /// ```
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+        let (start, end) = bounds(range, self.size);
+        IterMut {
+            // SAFETY: the node is a live node owned by this linked vector.
+            next: self.node_at(start).map(|node| unsafe { &mut *node.as_ptr() }),
+            len: end - start,
+        }
+    }
+
+    /// Returns a cursor pointing at the first element of the linked vector.
+    ///
+    /// The cursor points at the end if the linked vector is empty.
+    ///
+    /// # Returns
+    ///
+    /// A [`CursorMut`] to edit the linked vector in O(1) time at the cursor position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=3).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// cursor.move_next();
+    /// *cursor.current().unwrap() = 20;
+    /// assert_eq!(vec.to_vec(), vec![1, 20, 3]);
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            prev: None,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Moves `node` to the heap and returns an owning pointer to it.
+    fn allocate(node: Node<T>) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(node)))
+    }
+
+    /// Returns the node at the specified index, or None if the index is out of bounds.
+    fn node_at(&self, index: usize) -> Next<T> {
+        if self.size <= index {
+            return None;
+        }
+        if index == self.size - 1 {
+            return self.tail;
+        }
+
+        let mut node = self.head;
+        for _i in 0..index {
+            // SAFETY: `index < size`, so every node on the way is a live node of this vector.
+            node = unsafe { node?.as_ref().next };
+        }
+        node
+    }
+
+    /// Unlinks and returns the node after `node_before`, or the head if `node_before` is None.
+    ///
+    /// # Safety
+    ///
+    /// `node_before` must be None or a live node of this linked vector, and the node to
+    /// unlink must exist.
+    unsafe fn unlink_next(&mut self, node_before: Next<T>) -> Box<Node<T>> {
+        let link = match node_before {
+            Some(node_before) => &mut (*node_before.as_ptr()).next,
+            None => &mut self.head,
+        };
+        let node = Box::from_raw(link.unwrap().as_ptr());
+        *link = node.next;
+        if node.next.is_none() {
+            self.tail = node_before;
+        }
+        self.size -= 1;
+        node
+    }
+
+    /// Merges two sorted chains of detached nodes into one, preferring `first` on ties.
+    fn merge<F: FnMut(&T, &T) -> Ordering>(
+        mut first: Chain<T>,
+        mut second: Chain<T>,
+        compare: &mut F,
+    ) -> Chain<T> {
+        let mut merged = Chain::new();
+        while let (Some(a), Some(b)) = (first.first(), second.first()) {
+            let source = if compare(b, a) == Ordering::Less {
+                &mut second
+            } else {
+                &mut first
+            };
+            if let Some(node) = source.pop_front() {
+                merged.append(node);
+            }
+        }
+
+        merged.append(first);
+        merged.append(second);
+        merged
+    }
+}
+
+impl<T> LinkedVec<T> {
+    /// Checks if the linked vector contains an element equal to `data`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The element to look for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let vec = LinkedVec::from([1, 2, 3]);
+    /// assert!(vec.contains(&2));
+    /// assert!(!vec.contains(&4));
+    /// ```
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+
+    /// Prints the elements of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut linked_vec = LinkedVec::new();
+    /// linked_vec.push(1);
+    /// linked_vec.push(2);
+    /// linked_vec.print();
+    /// ```
+    pub fn print(&self)
+    where
+        T: Debug,
+    {
+        for data in self.iter() {
+            print!("{:?} ", data);
+        }
+    }
+
+    /// Converts the linked vector into a standard Vec.
+    ///
+    /// # Returns
+    ///
+    /// A Vec containing all elements of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut linked_vec = LinkedVec::new();
+    /// linked_vec.push(1);
+    /// linked_vec.push(2);
+    /// let vec = linked_vec.to_vec();
+    /// assert_eq!(vec, vec![1, 2]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.size);
+        for data in self.iter() {
+            vec.push(data.clone());
+        }
+        vec
+    }
+
+    /// Reverses the order of elements in the LinkedVec.
+    ///
+    /// # Returns
+    ///
+    /// A new LinkedVec with the elements in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut linked_vec = LinkedVec::new();
+    /// linked_vec.push(1);
+    /// linked_vec.push(2);
+    /// linked_vec.push(3);
+    ///
+    /// let reversed_vec = linked_vec.reverse();
+    ///
+    /// assert_eq!(reversed_vec.find(0), Some(&3));
+    /// assert_eq!(reversed_vec.find(1), Some(&2));
+    /// assert_eq!(reversed_vec.find(2), Some(&1));
+    /// ```
+    pub fn reverse(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut reversed_vec = LinkedVec::new();
+        for data in self.iter() {
+            reversed_vec.push_front(data.clone());
+        }
+        reversed_vec
+    }
+
+    /// Drains the elements from the linked vector, returning an iterator over the removed elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding the removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec = LinkedVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    ///
+    /// let mut drain_iter = vec.drain();
+    /// assert_eq!(drain_iter.next(), Some(1));
+    /// assert_eq!(drain_iter.next(), Some(2));
+    /// assert_eq!(drain_iter.next(), Some(3));
+    /// assert_eq!(drain_iter.next(), None);
+    /// assert_eq!(vec.len(), 0);
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { linked_vec: self }
+    }
+
+    pub fn binary_search_by(&self, f: impl Fn(&T) -> Ordering) -> Result<usize, usize> {
+        let mut low = 0;
+        let mut high = self.size;
+
+        while low < high {
+            let mid = (low + high) / 2;
+            match f(self.find(mid).unwrap()) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    pub fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    pub fn copy_from_slice(&mut self, other: &LinkedVec<T>)
+    where
+        T: Clone,
+    {
+        self.clear();
+        for data in other.iter() {
             self.push(data.clone());
         }
     }
+}
+
+/// Iterator over the drained elements of a linked vector.
+pub struct Drain<'a, T: 'a> {
+    pub linked_vec: &'a mut LinkedVec<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.linked_vec.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.linked_vec.len(), Some(self.linked_vec.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+/// Returns the start and the end of a range of positions of a sequence.
+///
+/// # Panics
+///
+/// Panics if the range starts after it ends or ends after the length of the sequence.
+fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflows"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflows"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range starts at {} but ends at {}", start, end);
+    assert!(end <= len, "range ends at {} but the length is {}", end, len);
+    (start, end)
+}
+
+/// Iterator over the elements of a linked vector.
+///
+/// Nodes only link forward, so the iterator only goes from front to back.
+pub struct Iter<'a, T: 'a> {
+    next: Option<&'a Node<T>>,
+    /// The number of elements left to yield.
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.next.map(|node| {
+            // SAFETY: the next node is owned by the linked vector borrowed for `'a`.
+            self.next = node.next.map(|next| unsafe { &*next.as_ptr() });
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            next: self.next,
+            len: self.len,
+        }
+    }
+}
+
+/// Iterator over overlapping windows of a linked vector, created by [`LinkedVec::windows`].
+pub struct Windows<'a, T: 'a> {
+    /// The elements from the start of the next window onwards.
+    start: Iter<'a, T>,
+    /// The number of elements in each window.
+    size: usize,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start.len < self.size {
+            return None;
+        }
+        let window = Iter {
+            next: self.start.next,
+            len: self.size,
+        };
+        self.start.next();
+        Some(window)
+    }
+}
+
+/// Iterator over non-overlapping chunks of a linked vector, created by [`LinkedVec::chunks`].
+pub struct Chunks<'a, T: 'a> {
+    /// The elements from the start of the next chunk onwards.
+    start: Iter<'a, T>,
+    /// The maximum number of elements in each chunk.
+    size: usize,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start.len == 0 {
+            return None;
+        }
+        let chunk = Iter {
+            next: self.start.next,
+            len: self.size.min(self.start.len),
+        };
+        for _i in 0..chunk.len {
+            self.start.next();
+        }
+        Some(chunk)
+    }
+}
+
+/// Mutable iterator over the elements of a linked vector.
+///
+/// Like [`Iter`], it only goes from front to back.
+pub struct IterMut<'a, T: 'a> {
+    next: Option<&'a mut Node<T>>,
+    /// The number of elements left to yield.
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.next.take().map(|node| {
+            // SAFETY: the next node is owned by the linked vector mutably borrowed for `'a`,
+            // and each node is handed out only once.
+            self.next = node.next.map(|next| unsafe { &mut *next.as_ptr() });
+            self.len -= 1;
+            &mut node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consuming iterator over the elements of a linked vector.
+///
+/// It goes from front to back, and also from the back with `next_back`, which has to find the
+/// node before the last one and so takes O(n) time per element.
+pub struct IntoIter<T> {
+    /// The elements not yet yielded.
+    linked_vec: LinkedVec<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.linked_vec.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.linked_vec.len(), Some(self.linked_vec.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.linked_vec.pop()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// Consumes the linked vector into an iterator yielding its elements from front to back.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::LinkedVec;
+///
+/// let vec = LinkedVec::from([1, 2, 3]);
+/// let mut iter = vec.into_iter();
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+///
+/// let vec = LinkedVec::from([1, 2, 3, 4]);
+/// let mut iter = vec.into_iter();
+/// assert_eq!(iter.next_back(), Some(4));
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.rev().collect::<Vec<_>>(), vec![3, 2]);
+/// ```
+impl<T> IntoIterator for LinkedVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { linked_vec: self }
+    }
+}
+
+/// Iterates over references to the elements, so a linked vector can be used in `for` loops.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::LinkedVec;
+///
+/// let mut vec = LinkedVec::from([1, 2, 3]);
+/// for data in &mut vec {
+///     *data *= 10;
+/// }
+///
+/// let mut sum = 0;
+/// for data in &vec {
+///     sum += data;
+/// }
+/// assert_eq!(sum, 60);
+/// assert_eq!(vec.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30]);
+/// ```
+impl<'a, T> IntoIterator for &'a LinkedVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
 
-    pub fn binary_search_by(&self, f: impl Fn(&T) -> Ordering) -> Result<usize, usize> {
-        let mut low = 0;
-        let mut high = self.size;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
-        while low < high {
-            let mid = (low + high) / 2;
-            match f(self.find(mid).unwrap()) {
-                Ordering::Less => low = mid + 1,
-                Ordering::Greater => high = mid,
-                Ordering::Equal => return Ok(mid),
-            }
-        }
+impl<'a, T> IntoIterator for &'a mut LinkedVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
-        Err(low)
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
+}
 
-    pub fn as_mut(&mut self) -> &mut Self {
-        self
+impl<T> FromIterator<T> for LinkedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut linked_vec = LinkedVec::new();
+        linked_vec.extend(iter);
+        linked_vec
     }
+}
 
-    pub fn copy_from_slice(&mut self, other: &LinkedVec<T>) {
-        self.clear();
-        for data in other.iter() {
-            self.push(data.clone());
+/// Pushes every element of an iterator onto the end of the linked vector, in O(1) time each.
+///
+/// To move the nodes of another linked vector in O(1) overall, use
+/// [`append`](LinkedVec::append) instead.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::LinkedVec;
+///
+/// let mut vec = LinkedVec::from([1, 2]);
+/// vec.extend(vec![3, 4]);
+/// vec.extend(&[5, 6]);
+/// assert_eq!(vec, LinkedVec::from([1, 2, 3, 4, 5, 6]));
+/// ```
+impl<T> Extend<T> for LinkedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push(data);
         }
     }
 }
 
-/// Iterator over the drained elements of a linked vector.
-pub struct Drain<'a, T: 'a> {
-    pub linked_vec: &'a mut LinkedVec<T>,
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
 }
 
-impl<'a, T: Clone + Debug + PartialEq> Iterator for Drain<'a, T> {
-    type Item = T;
+impl<T> From<Vec<T>> for LinkedVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.linked_vec.pop()
+impl<T, const N: usize> From<[T; N]> for LinkedVec<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
     }
 }
 
-/// Iterator over the elements of a linked vector.
-pub struct Iter<'a, T: 'a> {
-    next: Option<&'a Node<T>>,
+/// A cursor over a linked vector with editing operations, created by
+/// [`LinkedVec::cursor_front_mut`].
+///
+/// The cursor points at an element or at the end of the linked vector, where `current` returns
+/// None. As the linked vector is singly linked, the cursor can only move forward, and it stays
+/// at the end once there rather than wrapping around to the first element.
+///
+/// Every operation runs in O(1) time.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the linked vector.
+///
+/// # Fields
+///
+/// * `list` - The linked vector being edited.
+/// * `current` - The node the cursor points at, or None at the end.
+/// * `prev` - The node before `current`, or None if `current` is the head.
+/// * `index` - The index of `current`, equal to the length at the end.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::LinkedVec;
+///
+/// let mut vec: LinkedVec<i32> = (1..=5).collect();
+/// let mut cursor = vec.cursor_front_mut();
+/// while let Some(&mut data) = cursor.current() {
+///     if data % 2 == 0 {
+///         cursor.remove_current();
+///     } else {
+///         cursor.insert_after(data * 10);
+///         cursor.move_next();
+///         cursor.move_next();
+///     }
+/// }
+/// assert_eq!(vec.to_vec(), vec![1, 10, 3, 30, 5, 50]);
+/// assert_eq!(vec.last(), Some(&50));
+/// ```
+pub struct CursorMut<'a, T: 'a> {
+    /// The linked vector being edited.
+    list: &'a mut LinkedVec<T>,
+    /// The node the cursor points at, or None at the end.
+    current: Next<T>,
+    /// The node before `current`, or None if `current` is the head.
+    prev: Next<T>,
+    /// The index of `current`, equal to the length at the end.
+    index: usize,
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+impl<T> CursorMut<'_, T> {
+    /// Returns the index of the element the cursor points at, or None at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=2).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// assert_eq!(cursor.index(), Some(0));
+    /// cursor.move_next();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), None);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), None);
+    /// ```
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.map(|node| {
-            self.next = node.next.as_deref();
-            &node.data
-        })
+    /// Moves the cursor to the next element, or to the end after the last element.
+    ///
+    /// Does nothing if the cursor is already at the end.
+    pub fn move_next(&mut self) {
+        if let Some(current) = self.current {
+            self.prev = Some(current);
+            // SAFETY: `current` is a live node owned by the linked vector.
+            self.current = unsafe { (*current.as_ptr()).next };
+            self.index += 1;
+        }
+    }
+
+    /// Returns a mutable reference to the element the cursor points at, or None at the end.
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: `current` is a live node owned by the mutably borrowed linked vector.
+        self.current
+            .map(|current| unsafe { &mut (*current.as_ptr()).data })
+    }
+
+    /// Returns a mutable reference to the element after the cursor, or None if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=2).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// assert_eq!(cursor.peek_next(), Some(&mut 2));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_next(), None);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_next(), None);
+    /// ```
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let current = self.current?;
+        // SAFETY: `current` and the node after it are live nodes owned by the mutably borrowed
+        // linked vector.
+        unsafe { (*current.as_ptr()).next.map(|next| &mut (*next.as_ptr()).data) }
+    }
+
+    /// Inserts an element after the cursor; the cursor keeps pointing at the same element.
+    ///
+    /// At the end of the linked vector, the element is appended and the cursor points at it.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The element to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=2).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// cursor.insert_after(10);
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(vec.to_vec(), vec![1, 10, 2]);
+    /// ```
+    pub fn insert_after(&mut self, data: T) {
+        let node = LinkedVec::allocate(Node::new(data, None));
+        // SAFETY: `node` is a single detached node.
+        unsafe { self.link_after(node, node) };
+        self.list.size += 1;
+    }
+
+    /// Inserts an element before the cursor, or appends it at the end of the linked vector.
+    ///
+    /// The cursor keeps pointing at the same element, whose index grows by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The element to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=2).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// cursor.insert_before(0);
+    /// assert_eq!(cursor.index(), Some(1));
+    /// cursor.move_next();
+    /// cursor.move_next();
+    /// cursor.insert_before(3);
+    /// assert_eq!(vec.to_vec(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn insert_before(&mut self, data: T) {
+        let node = LinkedVec::allocate(Node::new(data, self.current));
+        match self.prev {
+            // SAFETY: `prev` is a live node owned by the linked vector.
+            Some(prev) => unsafe { (*prev.as_ptr()).next = Some(node) },
+            None => self.list.head = Some(node),
+        }
+        if self.current.is_none() {
+            self.list.tail = Some(node);
+        }
+        self.prev = Some(node);
+        self.index += 1;
+        self.list.size += 1;
+    }
+
+    /// Removes the element the cursor points at and moves the cursor to the next element.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or None at the end of the linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=3).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// assert_eq!(cursor.current(), Some(&mut 3));
+    /// assert_eq!(vec.to_vec(), vec![1, 3]);
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.current?;
+        // SAFETY: `prev` links to `current`, which exists.
+        let node = unsafe { self.list.unlink_next(self.prev) };
+        self.current = node.next;
+        Some(node.data)
+    }
+
+    /// Moves all elements of `other` after the cursor; the cursor keeps pointing at the same
+    /// element.
+    ///
+    /// At the end of the linked vector, the elements are appended and the cursor points at the
+    /// first of them. The nodes are relinked, not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The linked vector whose elements are inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = [1, 4].into_iter().collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// cursor.splice_after((2..=3).collect());
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(vec.to_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn splice_after(&mut self, mut other: LinkedVec<T>) {
+        let (Some(head), Some(tail)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+        // SAFETY: `other` no longer links to its nodes, which are a chain from `head` to
+        // `tail`.
+        unsafe { self.link_after(head, tail) };
+        self.list.size += std::mem::replace(&mut other.size, 0);
+    }
+
+    /// Splits the linked vector after the cursor.
+    ///
+    /// # Returns
+    ///
+    /// A linked vector holding the elements after the cursor, which is empty at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut vec: LinkedVec<i32> = (1..=4).collect();
+    /// let mut cursor = vec.cursor_front_mut();
+    /// cursor.move_next();
+    /// let rest = cursor.split_after();
+    /// assert_eq!(rest.to_vec(), vec![3, 4]);
+    /// assert_eq!(vec.to_vec(), vec![1, 2]);
+    /// ```
+    pub fn split_after(&mut self) -> LinkedVec<T> {
+        let mut other = LinkedVec::new();
+        let Some(current) = self.current else {
+            return other;
+        };
+
+        // SAFETY: `current` is a live node owned by the linked vector.
+        other.head = unsafe { (*current.as_ptr()).next.take() };
+        if other.head.is_some() {
+            other.tail = self.list.tail;
+            self.list.tail = Some(current);
+        }
+        other.size = self.list.size - self.index - 1;
+        self.list.size = self.index + 1;
+        other
+    }
+
+    /// Links a chain of nodes after the cursor, or at the end of the linked vector, where the
+    /// cursor then points at the first of them.
+    ///
+    /// # Safety
+    ///
+    /// `head` must be the first node of a chain of live detached nodes ending at `tail`.
+    unsafe fn link_after(&mut self, head: NonNull<Node<T>>, tail: NonNull<Node<T>>) {
+        match self.current {
+            Some(current) => {
+                (*tail.as_ptr()).next = (*current.as_ptr()).next;
+                (*current.as_ptr()).next = Some(head);
+                if self.list.tail == Some(current) {
+                    self.list.tail = Some(tail);
+                }
+            }
+            None => {
+                match self.list.tail {
+                    Some(last) => (*last.as_ptr()).next = Some(head),
+                    None => self.list.head = Some(head),
+                }
+                self.prev = self.list.tail;
+                self.list.tail = Some(tail);
+                self.current = Some(head);
+            }
+        }
     }
 }
 
-/// Mutable iterator over the elements of a linked vector.
-pub struct IterMut<'a, T: 'a> {
-    next: Option<&'a mut Node<T>>,
+impl<T: Clone> LinkedVec<LinkedVec<T>> {
+    /// Flattens a linked vector of linked vectors into a single linked vector.
+    ///
+    /// # Returns
+    ///
+    /// A new linked vector holding clones of all inner elements, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut parts = LinkedVec::new();
+    /// parts.push((1..=2).collect::<LinkedVec<i32>>());
+    /// parts.push(LinkedVec::new());
+    /// parts.push((3..=4).collect::<LinkedVec<i32>>());
+    /// assert_eq!(parts.concat().to_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn concat(&self) -> LinkedVec<T> {
+        let mut result = LinkedVec::new();
+        for vec in self.iter() {
+            for data in vec.iter() {
+                result.push(data.clone());
+            }
+        }
+        result
+    }
+
+    /// Flattens a linked vector of linked vectors, placing a separator between each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The element inserted between consecutive inner linked vectors.
+    ///
+    /// # Returns
+    ///
+    /// A new linked vector holding clones of all inner elements and separators, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::LinkedVec;
+    ///
+    /// let mut parts = LinkedVec::new();
+    /// parts.push((1..=2).collect::<LinkedVec<i32>>());
+    /// parts.push((3..=4).collect::<LinkedVec<i32>>());
+    /// parts.push((5..=5).collect::<LinkedVec<i32>>());
+    /// assert_eq!(parts.join(&0).to_vec(), vec![1, 2, 0, 3, 4, 0, 5]);
+    /// ```
+    pub fn join(&self, separator: &T) -> LinkedVec<T> {
+        let mut result = LinkedVec::new();
+        for (i, vec) in self.iter().enumerate() {
+            if i > 0 {
+                result.push(separator.clone());
+            }
+            for data in vec.iter() {
+                result.push(data.clone());
+            }
+        }
+        result
+    }
 }
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+impl<T: Clone> Clone for LinkedVec<T> {
+    fn clone(&self) -> Self {
+        let mut linked_vec = LinkedVec::new();
+        for data in self.iter() {
+            linked_vec.push(data.clone());
+        }
+        linked_vec
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.take().map(|node| {
-            self.next = node.next.as_deref_mut();
-            &mut node.data
-        })
+impl<T: Debug> Debug for LinkedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
     }
 }
 
-/// Consuming iterator over the elements of a linked vector.
-pub struct IntoIter<T: Clone + Debug + PartialEq>(LinkedVec<T>);
+impl<T: PartialEq> PartialEq for LinkedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
 
-impl<T: Clone + Debug + PartialEq> Iterator for IntoIter<T> {
-    type Item = T;
+impl<T: Eq> Eq for LinkedVec<T> {}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop()
+/// Compares linked vectors lexicographically, like slices.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::LinkedVec;
+///
+/// assert!(LinkedVec::from([1, 2]) < LinkedVec::from([1, 3]));
+/// assert!(LinkedVec::from([1, 2]) < LinkedVec::from([1, 2, 0]));
+/// assert_eq!(LinkedVec::from([2]).max(LinkedVec::from([1, 9])), LinkedVec::from([2]));
+/// ```
+impl<T: PartialOrd> PartialOrd for LinkedVec<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
     }
 }
 
-impl<T: Debug + Clone + PartialEq> FromIterator<T> for LinkedVec<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut linked_vec = LinkedVec::new();
-        for item in iter {
-            linked_vec.push(item);
+impl<T: Ord> Ord for LinkedVec<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length and the elements in order, so equal linked vectors hash equally.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use crate::vec::vec::LinkedVec;
+///
+/// let mut paths = HashMap::new();
+/// paths.insert(LinkedVec::from(["a", "b"]), 1);
+/// assert_eq!(paths.get(&LinkedVec::from(["a", "b"])), Some(&1));
+/// ```
+impl<T: Hash> Hash for LinkedVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        for data in self.iter() {
+            data.hash(state);
         }
-        linked_vec
+    }
+}
+
+/// Formats the elements as a bracketed, comma separated list.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::LinkedVec;
+///
+/// assert_eq!(LinkedVec::from([1, 2, 3]).to_string(), "[1, 2, 3]");
+/// assert_eq!(LinkedVec::<i32>::new().to_string(), "[]");
+/// ```
+impl<T: Display> Display for LinkedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, data) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", data)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T> Default for LinkedVec<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 /// Custom drop implementation for releasing linked vector memory.
 impl<T> Drop for LinkedVec<T> {
     fn drop(&mut self) {
-        let mut link = self.head.take();
-        while let Some(mut node) = link {
-            link = node.next.take();
-        }
+        self.clear();
     }
 }
@@ -0,0 +1,944 @@
+use std::fmt::Debug;
+use std::iter::Take;
+use std::ops::{Index, IndexMut, RangeBounds};
+use std::slice;
+
+/// Type alias for an unrolled node with an optional next node.
+type Link<T, const N: usize> = Option<Box<UnrolledNode<T, N>>>;
+
+/// Represents a node of an unrolled linked vector, holding up to `N` elements.
+///
+/// Only the first `len` slots are occupied; the rest are `None`.
+struct UnrolledNode<T, const N: usize> {
+    /// The elements stored in the node.
+    items: [Option<T>; N],
+    /// The number of occupied slots.
+    len: usize,
+    /// Pointer to the next node in the unrolled linked vector.
+    next: Link<T, N>,
+}
+
+impl<T, const N: usize> UnrolledNode<T, N> {
+    /// Creates a new empty node.
+    fn new() -> Self {
+        UnrolledNode {
+            items: std::array::from_fn(|_| None),
+            len: 0,
+            next: None,
+        }
+    }
+
+    fn get(&self, index: usize) -> &T {
+        self.items[index].as_ref().unwrap()
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        self.items[index].as_mut().unwrap()
+    }
+
+    /// Inserts `data` at `index`, shifting the following elements right. The node must not be full.
+    fn insert(&mut self, index: usize, data: T) {
+        self.items[index..=self.len].rotate_right(1);
+        self.items[index] = Some(data);
+        self.len += 1;
+    }
+
+    /// Removes the element at `index`, shifting the following elements left.
+    fn remove(&mut self, index: usize) -> T {
+        let data = self.items[index].take().unwrap();
+        self.items[index..self.len].rotate_left(1);
+        self.len -= 1;
+        data
+    }
+
+    /// Moves the upper half of a full node into a new node linked right after it.
+    fn split(&mut self) {
+        let mid = N / 2;
+        let mut new_node = UnrolledNode::new();
+        for (slot, item) in new_node.items.iter_mut().zip(self.items[mid..].iter_mut()) {
+            *slot = item.take();
+        }
+        new_node.len = self.len - mid;
+        new_node.next = self.next.take();
+        self.len = mid;
+        self.next = Some(Box::new(new_node));
+    }
+
+    /// Refills a node that dropped below half capacity, either by absorbing the next node
+    /// entirely or by borrowing the first element of the next node.
+    fn rebalance(&mut self) {
+        if self.len >= N / 2 {
+            return;
+        }
+
+        if let Some(next) = self.next.as_mut() {
+            if self.len + next.len <= N {
+                let mut next = self.next.take().unwrap();
+                for item in next.items[..next.len].iter_mut() {
+                    self.items[self.len] = item.take();
+                    self.len += 1;
+                }
+                self.next = next.next.take();
+            } else {
+                let data = next.remove(0);
+                self.items[self.len] = Some(data);
+                self.len += 1;
+            }
+        }
+    }
+}
+
+/// Creates an [`UnrolledLinkedVec`] from a list of elements, or from an element repeated `size`
+/// times.
+///
+/// Like `vec!`, the repeated element is evaluated once and cloned.
+///
+/// # Examples
+///
+/// ```
+/// use vec::unrolled_linked_vec;
+///
+/// let vec = unrolled_linked_vec![1, 2, 3];
+/// assert_eq!(vec.to_vec(), vec![1, 2, 3]);
+///
+/// let mut next_id = 0;
+/// let vec = unrolled_linked_vec![{ next_id += 1; next_id }; 3];
+/// assert_eq!(vec.to_vec(), vec![1, 1, 1]);
+/// assert_eq!(next_id, 1);
+/// ```
+#[macro_export]
+macro_rules! unrolled_linked_vec {
+    ($($elem:expr),* $(,)?) => {{
+        let mut vec = $crate::vec::unrolled_linked_vec::UnrolledLinkedVec::new();
+        $(
+            vec.push($elem);
+        )*
+        vec
+    }};
+    ($elem:expr; $size:expr) => {{
+        // The element and the size are evaluated once, the element being cloned for all but
+        // the last position, which takes it.
+        let elem = $elem;
+        let size: usize = $size;
+        let mut vec = $crate::vec::unrolled_linked_vec::UnrolledLinkedVec::new();
+        for _ in 1..size {
+            vec.push(elem.clone());
+        }
+        if size > 0 {
+            vec.push(elem);
+        }
+        vec
+    }};
+}
+
+/// Represents an unrolled linked vector: a singly linked list of nodes, each holding a small
+/// fixed-capacity array of up to `N` elements.
+///
+/// Compared to [`LinkedVec`](super::LinkedVec) it allocates one node per `N` elements instead
+/// of one per element, and index walks skip whole nodes, so `find`, `insert` and `remove`
+/// cost O(n / N + N). Nodes are split when an insertion overflows them and merged with
+/// their successor when a removal leaves them less than half full.
+///
+/// It does not support range indexing such as `vec[2..5]`, because a range can span several
+/// nodes and so can't be borrowed as one slice. [`range`](UnrolledLinkedVec::range) and
+/// [`range_mut`](UnrolledLinkedVec::range_mut) replace it, returning iterators over the
+/// elements at the given positions instead.
+///
+/// # Generic Parameters
+///
+/// * `T` - Type of elements stored in the unrolled linked vector.
+/// * `N` - The number of elements each node can hold (at least 2, 8 by default).
+///
+/// # Fields
+///
+/// * `size` - The number of elements in the unrolled linked vector.
+/// * `head` - Pointer to the head node of the unrolled linked vector.
+///
+/// # Examples
+///
+/// ```
+/// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+///
+/// let mut vec: UnrolledLinkedVec<i32, 4> = UnrolledLinkedVec::default();
+/// for i in 0..10 {
+///     vec.push(i);
+/// }
+/// vec.insert(5, 100);
+///
+/// assert_eq!(vec.len(), 11);
+/// assert_eq!(vec.find(5), Some(&100));
+/// assert_eq!(vec.remove(0), Some(0));
+/// assert_eq!(vec.to_vec(), vec![1, 2, 3, 4, 100, 5, 6, 7, 8, 9]);
+///
+/// // `vec.range(2..5)` instead of `&vec[2..5]`.
+/// assert_eq!(vec.range(2..5).copied().collect::<Vec<_>>(), vec![3, 4, 100]);
+/// ```
+pub struct UnrolledLinkedVec<T, const N: usize = 8> {
+    /// The number of elements in the unrolled linked vector.
+    size: usize,
+    /// Pointer to the head node of the unrolled linked vector.
+    head: Link<T, N>,
+}
+
+impl<T> UnrolledLinkedVec<T> {
+    /// Creates a new empty unrolled linked vector with the default node capacity of 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let vec: UnrolledLinkedVec<i32> = UnrolledLinkedVec::new();
+    /// assert_eq!(vec.len(), 0);
+    /// assert_eq!(vec.node_capacity(), 8);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, const N: usize> Default for UnrolledLinkedVec<T, N> {
+    fn default() -> Self {
+        const { assert!(N >= 2, "Node capacity must be at least 2") };
+        UnrolledLinkedVec {
+            size: 0,
+            head: None,
+        }
+    }
+}
+
+impl<T, const N: usize> UnrolledLinkedVec<T, N> {
+    /// Returns the number of elements each node can hold.
+    pub fn node_capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of nodes currently allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec: UnrolledLinkedVec<i32, 4> = UnrolledLinkedVec::default();
+    /// for i in 0..8 {
+    ///     vec.push(i);
+    /// }
+    /// assert!(vec.node_count() <= 4);
+    ///
+    /// while vec.len() > 1 {
+    ///     vec.remove(0);
+    /// }
+    /// assert_eq!(vec.node_count(), 1);
+    /// ```
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut node = self.head.as_deref();
+        while let Some(n) = node {
+            count += 1;
+            node = n.next.as_deref();
+        }
+        count
+    }
+
+    /// Pushes an element onto the end of the unrolled linked vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to be pushed onto the unrolled linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    pub fn push(&mut self, data: T) {
+        self.insert(self.size, data);
+    }
+
+    /// Moves all elements of another unrolled linked vector to the end of this one, leaving the other empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The unrolled linked vector whose elements will be appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let mut vec1 = unrolled_linked_vec![1, 2];
+    /// let mut vec2 = unrolled_linked_vec![3, 4];
+    /// vec1.append(&mut vec2);
+    ///
+    /// assert_eq!(vec1.to_vec(), vec![1, 2, 3, 4]);
+    /// assert_eq!(vec2.len(), 0);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        *link = other.head.take();
+        self.size += other.size;
+        other.size = 0;
+    }
+
+    /// Inserts an element at the specified index, splitting the target node if it is full.
+    ///
+    /// If the specified index is greater than the size of the vector, the element will be inserted at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index at which to insert the element.
+    /// * `data` - The element to be inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec: UnrolledLinkedVec<i32, 2> = UnrolledLinkedVec::default();
+    /// vec.push(1);
+    /// vec.push(3);
+    /// vec.insert(1, 2);
+    /// vec.insert(10, 4);
+    ///
+    /// assert_eq!(vec.to_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn insert(&mut self, mut index: usize, data: T) {
+        if self.size < index {
+            index = self.size;
+        }
+
+        let mut node = self
+            .head
+            .get_or_insert_with(|| Box::new(UnrolledNode::new()));
+        while index > node.len {
+            index -= node.len;
+            node = node.next.as_mut().unwrap();
+        }
+
+        if node.len == N {
+            node.split();
+            if index > node.len {
+                index -= node.len;
+                node = node.next.as_mut().unwrap();
+            }
+        }
+
+        node.insert(index, data);
+        self.size += 1;
+    }
+
+    /// Pops the last element of the unrolled linked vector.
+    ///
+    /// # Returns
+    ///
+    /// An option containing the popped data, or None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// assert_eq!(vec.pop(), Some(1));
+    /// assert_eq!(vec.is_empty(), true);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.remove(self.size - 1)
+        }
+    }
+
+    /// Returns a reference to the last element, or None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// assert_eq!(vec.peek(), None);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.peek(), Some(&2));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.find(self.size - 1)
+        }
+    }
+
+    /// Returns a mutable reference to the last element, or None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// if let Some(data) = vec.peek_mut() {
+    ///     *data = 2;
+    /// }
+    /// assert_eq!(vec.peek(), Some(&2));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.find_mut(self.size - 1)
+        }
+    }
+
+    /// Removes the element at the specified index, merging or refilling its node if it becomes less than half full.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be removed.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    ///
+    /// assert_eq!(vec.remove(1), Some(2));
+    /// assert_eq!(vec.remove(5), None);
+    /// assert_eq!(vec.to_vec(), vec![1, 3]);
+    /// ```
+    pub fn remove(&mut self, mut index: usize) -> Option<T> {
+        if self.size <= index {
+            return None;
+        }
+
+        let mut link = &mut self.head;
+        while index >= link.as_ref().unwrap().len {
+            index -= link.as_ref().unwrap().len;
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        let node = link.as_mut().unwrap();
+        let data = node.remove(index);
+        node.rebalance();
+
+        // Only the last node can be left empty: any other one absorbs its successor.
+        if node.len == 0 {
+            *link = node.next.take();
+        }
+        self.size -= 1;
+
+        Some(data)
+    }
+
+    /// Checks if the unrolled linked vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the number of elements in the unrolled linked vector.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Clears the unrolled linked vector, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// vec.clear();
+    /// assert_eq!(vec.is_empty(), true);
+    /// assert_eq!(vec.node_count(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        }
+        self.size = 0;
+    }
+
+    /// Finds and returns the element at the specified index, skipping whole nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be found.
+    ///
+    /// # Returns
+    ///
+    /// The element at the specified index, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    ///
+    /// assert_eq!(vec.find(1), Some(&2));
+    /// assert_eq!(vec.find(3), None);
+    /// ```
+    pub fn find(&self, mut index: usize) -> Option<&T> {
+        if self.size <= index {
+            return None;
+        }
+
+        let mut node = self.head.as_ref().unwrap();
+        while index >= node.len {
+            index -= node.len;
+            node = node.next.as_ref().unwrap();
+        }
+        Some(node.get(index))
+    }
+
+    /// Finds and returns a mutable reference to the element at the specified index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be found.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the element at the specified index, or None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec = UnrolledLinkedVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    ///
+    /// if let Some(data) = vec.find_mut(1) {
+    ///     *data = 4;
+    /// }
+    /// assert_eq!(vec.find(1), Some(&4));
+    /// ```
+    pub fn find_mut(&mut self, mut index: usize) -> Option<&mut T> {
+        if self.size <= index {
+            return None;
+        }
+
+        let mut node = self.head.as_mut().unwrap();
+        while index >= node.len {
+            index -= node.len;
+            node = node.next.as_mut().unwrap();
+        }
+        Some(node.get_mut(index))
+    }
+
+    /// Swaps the elements at the specified indices; does nothing if either is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `index1` - The index of the first element.
+    /// * `index2` - The index of the second element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let mut vec = unrolled_linked_vec![1, 2, 3];
+    /// vec.swap(0, 2);
+    /// assert_eq!(vec.to_vec(), vec![3, 2, 1]);
+    /// ```
+    pub fn swap(&mut self, index1: usize, index2: usize) {
+        if index1 >= self.size || index2 >= self.size || index1 == index2 {
+            return;
+        }
+
+        let slot1 = self.slot_mut(index1).take();
+        let data2 = std::mem::replace(self.slot_mut(index2), slot1);
+        *self.slot_mut(index1) = data2;
+    }
+
+    fn slot_mut(&mut self, mut index: usize) -> &mut Option<T> {
+        let mut node = self.head.as_mut().unwrap();
+        while index >= node.len {
+            index -= node.len;
+            node = node.next.as_mut().unwrap();
+        }
+        &mut node.items[index]
+    }
+
+    /// Returns an iterator over the elements of the unrolled linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let vec = unrolled_linked_vec![1, 2];
+    /// let mut iter = vec.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            items: [].iter(),
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Returns a mutable iterator over the elements of the unrolled linked vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let mut vec = unrolled_linked_vec![1, 2];
+    /// for data in vec.iter_mut() {
+    ///     *data *= 10;
+    /// }
+    ///
+    /// assert_eq!(vec.to_vec(), vec![10, 20]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut {
+            items: [].iter_mut(),
+            next: self.head.as_deref_mut(),
+        }
+    }
+
+    /// Returns an iterator over a range of positions of the unrolled linked vector, skipping
+    /// whole nodes to reach the start of the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The positions of the elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding references to the elements in the range, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let vec: UnrolledLinkedVec<i32, 4> = (0..10).collect();
+    /// assert_eq!(vec.range(3..6).copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    /// assert_eq!(vec.range(..2).count(), 2);
+    /// assert_eq!(vec.range(8..).copied().collect::<Vec<_>>(), vec![8, 9]);
+    /// assert_eq!(vec.range(10..).next(), None);
+    /// ```
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Take<Iter<'_, T, N>> {
+        let (mut start, end) = super::bounds(range, self.size);
+        let len = end - start;
+        let mut node = self.head.as_deref();
+        while let Some(current) = node.filter(|current| start >= current.len) {
+            start -= current.len;
+            node = current.next.as_deref();
+        }
+
+        let iter = match node {
+            Some(node) => Iter {
+                items: node.items[start..node.len].iter(),
+                next: node.next.as_deref(),
+            },
+            None => Iter {
+                items: [].iter(),
+                next: None,
+            },
+        };
+        iter.take(len)
+    }
+
+    /// Returns a mutable iterator over a range of positions of the unrolled linked vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The positions of the elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding mutable references to the elements in the range, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::vec::unrolled_linked_vec::UnrolledLinkedVec;
+    ///
+    /// let mut vec: UnrolledLinkedVec<i32, 4> = (0..6).collect();
+    /// vec.range_mut(2..=4).for_each(|data| *data *= 10);
+    /// assert_eq!(vec.to_vec(), vec![0, 1, 20, 30, 40, 5]);
+    /// ```
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Take<IterMut<'_, T, N>> {
+        let (mut start, end) = super::bounds(range, self.size);
+        let len = end - start;
+        let mut node = self.head.as_deref_mut();
+        loop {
+            match node {
+                Some(current) if start >= current.len => {
+                    start -= current.len;
+                    node = current.next.as_deref_mut();
+                }
+                _ => break,
+            }
+        }
+
+        let iter = match node {
+            Some(node) => IterMut {
+                items: node.items[start..node.len].iter_mut(),
+                next: node.next.as_deref_mut(),
+            },
+            None => IterMut {
+                items: [].iter_mut(),
+                next: None,
+            },
+        };
+        iter.take(len)
+    }
+}
+
+impl<T: PartialEq, const N: usize> UnrolledLinkedVec<T, N> {
+    /// Checks if the unrolled linked vector contains the given element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let vec = unrolled_linked_vec![1, 2];
+    /// assert!(vec.contains(&2));
+    /// assert!(!vec.contains(&3));
+    /// ```
+    pub fn contains(&self, data: &T) -> bool {
+        self.iter().any(|item| item == data)
+    }
+}
+
+impl<T: Debug, const N: usize> UnrolledLinkedVec<T, N> {
+    /// Prints the elements of the unrolled linked vector.
+    pub fn print(&self) {
+        for data in self.iter() {
+            print!("{:?} ", data);
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> UnrolledLinkedVec<T, N> {
+    /// Converts the unrolled linked vector into a standard Vec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let vec = unrolled_linked_vec![1, 2];
+    /// assert_eq!(vec.to_vec(), vec![1, 2]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for UnrolledLinkedVec<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.find(index).expect("Index out of bounds")
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for UnrolledLinkedVec<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.find_mut(index).expect("Index out of bounds")
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for UnrolledLinkedVec<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for UnrolledLinkedVec<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Two unrolled linked vectors are equal when they hold equal elements in the same order,
+/// however those elements are spread over nodes.
+impl<T: PartialEq, const N: usize> PartialEq for UnrolledLinkedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for UnrolledLinkedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = UnrolledLinkedVec::default();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, const N: usize> Extend<T> for UnrolledLinkedVec<T, N> {
+    /// Appends every element of the iterator, filling nodes front to back in a single walk.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut node = self
+            .head
+            .get_or_insert_with(|| Box::new(UnrolledNode::new()));
+        while node.next.is_some() {
+            node = node.next.as_mut().unwrap();
+        }
+
+        for data in iter {
+            if node.len == N {
+                node = node.next.insert(Box::new(UnrolledNode::new()));
+            }
+            node.insert(node.len, data);
+            self.size += 1;
+        }
+
+        // Drop the head created above if nothing was added to an empty vector.
+        if self.size == 0 {
+            self.head = None;
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a UnrolledLinkedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut UnrolledLinkedVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for UnrolledLinkedVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// Consumes the unrolled linked vector and returns an iterator over its elements, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::vec::unrolled_linked_vec;
+    ///
+    /// let vec = unrolled_linked_vec![1, 2];
+    /// let mut iter = vec.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// Iterator over the elements of an unrolled linked vector.
+pub struct Iter<'a, T, const N: usize> {
+    items: slice::Iter<'a, Option<T>>,
+    next: Option<&'a UnrolledNode<T, N>>,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return item.as_ref();
+            }
+            let node = self.next.take()?;
+            self.items = node.items[..node.len].iter();
+            self.next = node.next.as_deref();
+        }
+    }
+}
+
+/// Mutable iterator over the elements of an unrolled linked vector.
+pub struct IterMut<'a, T, const N: usize> {
+    items: slice::IterMut<'a, Option<T>>,
+    next: Option<&'a mut UnrolledNode<T, N>>,
+}
+
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return item.as_mut();
+            }
+            let node = self.next.take()?;
+            self.items = node.items[..node.len].iter_mut();
+            self.next = node.next.as_deref_mut();
+        }
+    }
+}
+
+/// Consuming iterator over the elements of an unrolled linked vector.
+pub struct IntoIter<T, const N: usize>(UnrolledLinkedVec<T, N>);
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.remove(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+/// Custom drop implementation for releasing the nodes without recursion.
+impl<T, const N: usize> Drop for UnrolledLinkedVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}